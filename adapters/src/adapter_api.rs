@@ -1,435 +1,1040 @@
-use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use serde_json::Value;
-use std::collections::HashMap;
-use std::time::Duration;
-use std::sync::Arc;
-use std::pin::Pin;
-use std::future::Future;
-
-pub type Result<T> = std::result::Result<T, String>;
-
-#[derive(Debug, Clone, serde::Serialize)]
-pub struct AdapterHealth {
-    pub status: HealthStatus,
-    pub last_success: Option<DateTime<Utc>>,
-    pub last_error: Option<String>,
-    pub uptime_percentage: f32,
-    pub requests_per_hour: u32,
-}
-
-#[derive(Debug, Clone, serde::Serialize)]
-pub enum HealthStatus {
-    Healthy,
-    Degraded,
-    Down,
-}
-
-#[derive(Debug, Clone, serde::Serialize)]
-pub struct RateLimit {
-    pub requests_per_hour: u32,
-    pub burst: u32,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub enum AdapterData {
-    Ships(Value),
-    Loadouts(Value),
-    Systems(Value),
-    Prices(Value),
-    News(Value),
-    Other(Value),
-}
-
-#[async_trait]
-pub trait DataAdapter: Send + Sync {
-    fn name(&self) -> &str;
-    fn version(&self) -> &str;
-    fn source_url(&self) -> &str;
-    fn attribution(&self) -> &str;
-    fn license(&self) -> &str;
-
-    async fn health_check(&self) -> Result<AdapterHealth>;
-    async fn fetch(&self) -> Result<AdapterData>;
-
-    fn rate_limit(&self) -> RateLimit;
-    fn cache_ttl(&self) -> Duration;
-
-    async fn initialize(&self) -> Result<()> { Ok(()) }
-    async fn shutdown(&self) -> Result<()> { Ok(()) }
-}
-
-// lightweight scheduler and health monitor with Schedule support
-
-
-#[derive(Clone)]
-pub enum Schedule {
-    Fixed(Duration),        // Every N seconds/minutes/hours
-    Cron(String),           // Cron expression (supports seconds with "with-seconds")
-    OnDemand,               // Manual trigger only
-}
-
-pub struct AdapterScheduler {
-    pub schedules: std::collections::HashMap<String, Schedule>,
-}
-impl AdapterScheduler {
-    pub fn new() -> Self { AdapterScheduler { schedules: std::collections::HashMap::new() } }
-
-    pub fn schedule(&mut self, adapter_name: String, schedule: Schedule) {
-        self.schedules.insert(adapter_name, schedule);
-    }
-}
-
-impl Default for AdapterScheduler {
-    fn default() -> Self { Self::new() }
-}
-
-pub struct HealthMonitor;
-impl HealthMonitor {
-    pub fn new() -> Self { HealthMonitor }
-    pub fn monitor(&mut self, _name: String) {}
-    pub async fn start(&self) -> Result<()> { Ok(()) }
-}
-
-impl Default for HealthMonitor {
-    fn default() -> Self { Self::new() }
-}
-
-#[cfg(test)]
-#[allow(clippy::items_after_test_module, clippy::type_complexity, clippy::await_holding_lock)]
-mod reg_tests {
-    use super::*;
-    use std::sync::{Arc, Mutex};
-    use std::pin::Pin;
-    use std::future::Future;
-    use tokio::time::Duration;
-
-    type MsgList = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
-
-    struct MockPublisher { events: MsgList }
-    impl MockPublisher { fn new() -> Self { Self { events: Arc::new(Mutex::new(Vec::new())) } }
-    fn events(&self) -> MsgList { self.events.clone() }
-    }
-
-    impl EventPublisher for MockPublisher {
-        fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
-            let subj = subject.to_string();
-            let events = self.events.clone();
-            Box::pin(async move {
-                // TODO(SOT) [TRACKED-002]: Replace lock usage with proper error handling to avoid poisoning panics in production
-                // See docs/TRACKED_TODOS.md#TRACKED-002
-                let mut guard = match events.lock() {
-                    Ok(g) => g,
-                    Err(e) => {
-                        tracing::error!("MockPublisher publish failed: mutex poisoned: {}", e);
-                        return Ok(());
-                    }
-                };
-                guard.push((subj, payload));
-                Ok(())
-            })
-        }
-    }
-
-    struct TestAdapter;
-
-    #[async_trait]
-    impl DataAdapter for TestAdapter {
-        fn name(&self) -> &str { "test" }
-        fn version(&self) -> &str { "0.1.0" }
-        fn source_url(&self) -> &str { "local" }
-        fn attribution(&self) -> &str { "test" }
-        fn license(&self) -> &str { "MIT" }
-
-        async fn health_check(&self) -> crate::Result<AdapterHealth> {
-            Ok(AdapterHealth {
-                status: HealthStatus::Healthy,
-                last_success: Some(chrono::Utc::now()),
-                last_error: None,
-                uptime_percentage: 100.0,
-                requests_per_hour: 0,
-            })
-        }
-
-        async fn fetch(&self) -> crate::Result<AdapterData> {
-            Ok(AdapterData::Other(serde_json::json!({"x":"y"})))
-        }
-
-        fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 10, burst: 2 } }
-        fn cache_ttl(&self) -> Duration { Duration::from_secs(1) }
-    }
-
-    #[tokio::test]
-    async fn registry_publishes_adapter_data() {
-        let mut reg = AdapterRegistry::new();
-        let adapter = std::sync::Arc::new(TestAdapter);
-        reg.register(adapter, Schedule::Fixed(std::time::Duration::from_secs(1)));
-
-        let pubm = MockPublisher::new();
-        let events = pubm.events();
-        let arc_pubm = std::sync::Arc::new(pubm);
-        reg.start_all(Some(arc_pubm), None).await.expect("start_all failed in test");
-
-        // give the scheduler a bit of time to run one iteration
-        tokio::time::sleep(Duration::from_secs(2)).await;
-
-        let guard = match events.lock() {
-            Ok(g) => g,
-            Err(e) => { tracing::error!("events mutex poisoned in test: {}", e); panic!("events mutex poisoned"); }
-        }; 
-        assert!(!guard.is_empty(), "No events published by adapter scheduler");
-        drop(guard);
-
-        // Also test the direct fetch_and_update method
-        let publisher2 = MockPublisher::new();
-        let events2 = publisher2.events();
-        let arc_pub2 = std::sync::Arc::new(publisher2);
-        reg.fetch_and_update("test", Some(arc_pub2)).await.expect("fetch_and_update failed in test");
-        let guard2 = match events2.lock() {
-            Ok(g) => g,
-            Err(e) => { tracing::error!("events2 mutex poisoned in test: {}", e); panic!("events2 mutex poisoned"); }
-        }; 
-        assert_eq!(guard2.len(), 1, "fetch_and_update did not publish expected event");
-    }
-}
-
-/// EventPublisher: abstraction for publishing adapter events (used by scheduler)
-pub trait EventPublisher: Send + Sync + 'static {
-    fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
-}
-
-pub struct AdapterRegistry {
-    adapters: HashMap<String, Arc<dyn DataAdapter>>,
-    scheduler: AdapterScheduler,
-    health_monitor: HealthMonitor,
-}
-
-impl AdapterRegistry {
-    pub fn new() -> Self {
-        Self {
-            adapters: HashMap::new(),
-            scheduler: AdapterScheduler::new(),
-            health_monitor: HealthMonitor::new(),
-        }
-    }
-
-    pub fn register(&mut self, adapter: Arc<dyn DataAdapter>, schedule: Schedule) {
-        let name = adapter.name().to_string();
-        self.adapters.insert(name.clone(), adapter);
-        self.scheduler.schedule(name.clone(), schedule);
-        self.health_monitor.monitor(name);
-    }
-
-    pub fn get(&self, name: &str) -> Option<Arc<dyn DataAdapter>> {
-        self.adapters.get(name).cloned()
-    }
-
-    /// Fetch data from a named adapter and publish a serialized event (if publisher provided)
-    pub async fn fetch_and_update(&self, name: &str, publisher: Option<std::sync::Arc<dyn EventPublisher>>) -> Result<()> {
-        if let Some(adapter) = self.adapters.get(name) {
-            let adapter = adapter.clone();
-            match adapter.fetch().await {
-                Ok(data) => {
-                    if let Some(p) = publisher {
-                        match serde_json::to_vec(&data) {
-                            Ok(payload) => {
-                                let subj = format!("adapters.{}.data", name);
-                                if let Err(e) = p.publish(&subj, payload).await {
-                                    tracing::error!("Failed to publish adapter {} data: {}", name, e);
-                                }
-                            }
-                            Err(e) => tracing::error!("Failed to serialize adapter {} data: {}", name, e),
-                        }
-                    }
-                    Ok(())
-                }
-                Err(e) => Err(e),
-            }
-        } else {
-            Err(format!("Adapter not found: {}", name))
-        }
-    }
-
-    /// Start all adapters and spawn scheduler tasks for those with fixed schedules.
-    pub async fn start_all(&mut self, publisher: Option<std::sync::Arc<dyn EventPublisher>>, metrics_registry: Option<std::sync::Arc<prometheus::Registry>>) -> Result<()> {
-        for (name, adapter) in &self.adapters {
-            tracing::info!("Initializing adapter: {}", name);
-            adapter.initialize().await?;
-        }
-
-        // Prepare metrics if requested
-        let metric_handles = if let Some(reg) = metrics_registry.as_ref() {
-            // create a small set of vectors (labeled by adapter name when used)
-            match (
-                prometheus::IntCounterVec::new(
-                    prometheus::Opts::new("adapter_fetch_success_total", "Adapter fetch successes"),
-                    &["adapter"],
-                ),
-                prometheus::IntCounterVec::new(
-                    prometheus::Opts::new("adapter_fetch_failure_total", "Adapter fetch failures"),
-                    &["adapter"],
-                ),
-                prometheus::IntCounterVec::new(
-                    prometheus::Opts::new("adapter_publish_total", "Adapter publishes"),
-                    &["adapter"],
-                ),
-                prometheus::HistogramVec::new(
-                    prometheus::HistogramOpts::new("adapter_fetch_seconds", "Adapter fetch latency seconds"),
-                    &["adapter"],
-                ),
-            ) {
-                (Ok(fetch_success), Ok(fetch_failure), Ok(publish_total), Ok(fetch_latency)) => {
-                    reg.register(Box::new(fetch_success.clone())).ok();
-                    reg.register(Box::new(fetch_failure.clone())).ok();
-                    reg.register(Box::new(publish_total.clone())).ok();
-                    reg.register(Box::new(fetch_latency.clone())).ok();
-
-                    Some(std::sync::Arc::new((fetch_success, fetch_failure, publish_total, fetch_latency)))
-                }
-                (e1, e2, e3, e4) => {
-                    tracing::error!("Failed to create metrics: {:?}, {:?}, {:?}, {:?}", e1.err(), e2.err(), e3.err(), e4.err());
-                    None
-                }
-            }
-        } else { None };
-
-        // Collect spawn tasks without borrowing `self` for the task lifetime
-        let mut launches: Vec<(String, Schedule, Arc<dyn DataAdapter>)> = Vec::new();
-        for (name, schedule) in &self.scheduler.schedules {
-            if let Some(adapter) = self.adapters.get(name) {
-                launches.push((name.clone(), schedule.clone(), adapter.clone()));
-            }
-        }
-
-        for (name, schedule, adapter) in launches {
-            match schedule {
-                Schedule::Fixed(interval) => {
-                    let adapter = adapter.clone();
-                    let n = name.clone();
-                    let intv = interval;
-                    let pub_opt = publisher.clone();
-                    let metric_counters = metric_handles.clone();
-                    let _task = tokio::spawn(async move {
-                        let mut ticker = tokio::time::interval(intv);
-                        loop {
-                            ticker.tick().await;
-                            // Pass the metric handles Option through; function will handle absence gracefully
-                            run_fetch_with_retries_metrics(&n, &adapter, &pub_opt, metric_counters.clone()).await;
-                        }
-                    });
-                }
-                Schedule::Cron(expr) => {
-                    let adapter = adapter.clone();
-                    let n = name.clone();
-                    let cron_expr = expr.clone();
-                    let pub_opt = publisher.clone();
-                    let metric_counters = metric_handles.clone();
-                    let _task = tokio::spawn(async move {
-                        // Use the cron crate for full cron expressions (supports seconds when specified)
-                        match std::str::FromStr::from_str(&cron_expr) {
-                            Ok(schedule_parsed) => {
-                                let mut upcoming = cron::Schedule::upcoming(&schedule_parsed, chrono::Utc);
-                                loop {
-                                    if let Some(next_dt) = upcoming.next() {
-                                        let now: chrono::DateTime<chrono::Utc> = chrono::Utc::now();
-                                        let dur = match (next_dt - now).to_std() {
-                                            Ok(d) => d,
-                                            Err(_) => std::time::Duration::from_secs(0),
-                                        };
-                                        tokio::time::sleep(dur).await;
-                                        // Pass the metric handles Option through; function will handle absence gracefully
-                                        run_fetch_with_retries_metrics(&n, &adapter, &pub_opt, metric_counters.clone()).await;
-                                    } else {
-                                        tracing::warn!("Cron schedule produced no upcoming times: {}", cron_expr);
-                                        break;
-                                    }
-                                }
-                            }
-                            Err(e) => tracing::error!("Invalid cron expression for adapter {}: {}: {}", name, cron_expr, e),
-                        }
-                    });
-                }
-                Schedule::OnDemand => {}
-            }
-        }
-
-        self.health_monitor.start().await?;
-        Ok(())
-    }
-
-    pub async fn get_health(&self) -> HashMap<String, AdapterHealth> {
-        let mut health = HashMap::new();
-        for (name, adapter) in &self.adapters {
-            match adapter.health_check().await {
-                Ok(h) => { health.insert(name.clone(), h); }
-                Err(e) => { tracing::error!("Health check failed for {}: {}", name, e); }
-            }
-        }
-        health
-    }
-}
-
-impl Default for AdapterRegistry {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-// Helper: run fetch with retries and record minimal metrics
-#[allow(dead_code)]
-async fn run_fetch_with_retries(name: &str, adapter: &Arc<dyn DataAdapter>, publisher: &Option<std::sync::Arc<dyn EventPublisher>>, metric_handles: Option<std::sync::Arc<(prometheus::IntCounterVec, prometheus::IntCounterVec, prometheus::IntCounterVec, prometheus::HistogramVec)>>) {
-    // Pass through the Option; the inner function will handle presence/absence of metrics gracefully
-    run_fetch_with_retries_metrics(name, adapter, publisher, metric_handles).await;
-}
-
-async fn run_fetch_with_retries_metrics(name: &str, adapter: &Arc<dyn DataAdapter>, publisher: &Option<std::sync::Arc<dyn EventPublisher>>, metrics_opt: Option<std::sync::Arc<(prometheus::IntCounterVec, prometheus::IntCounterVec, prometheus::IntCounterVec, prometheus::HistogramVec)>>) {
-    let max_retries: u32 = std::env::var("ADAPTER_FETCH_MAX_RETRIES").ok().and_then(|v| v.parse().ok()).unwrap_or(3);
-    let base_backoff_ms: u64 = std::env::var("ADAPTER_FETCH_BACKOFF_MS").ok().and_then(|v| v.parse().ok()).unwrap_or(500);
-
-    // Clone out the metric tuple if available; otherwise operate without metrics
-    let metrics: Option<(prometheus::IntCounterVec, prometheus::IntCounterVec, prometheus::IntCounterVec, prometheus::HistogramVec)> =
-        metrics_opt.as_ref().map(|m| m.as_ref().clone());
-
-    let mut attempt = 0u32;
-    loop {
-        attempt += 1;
-        let start = std::time::Instant::now();
-        match adapter.fetch().await {
-            Ok(data) => {
-                let elapsed = start.elapsed();
-                if let Some((fetch_success, _fetch_failure, _publish_total, fetch_latency)) = metrics.as_ref() {
-                    fetch_success.with_label_values(&[name]).inc();
-                    fetch_latency.with_label_values(&[name]).observe(elapsed.as_secs_f64());
-                }
-                if let Some(p) = &publisher {
-                    match serde_json::to_vec(&data) {
-                        Ok(payload) => {
-                            let subj = format!("adapters.{}.data", name);
-                            if let Err(e) = p.publish(&subj, payload).await {
-                                tracing::error!("Failed to publish adapter {} data: {}", name, e);
-                            } else if let Some((_fetch_success, _fetch_failure, publish_total, _fetch_latency)) = metrics.as_ref() {
-                                publish_total.with_label_values(&[name]).inc();
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to serialize adapter {} data: {}", name, e);
-                        }
-                    }
-                }
-                break; // success
-            }
-            Err(e) => {
-                if let Some((_fetch_success, fetch_failure, _publish_total, _fetch_latency)) = metrics.as_ref() {
-                    fetch_failure.with_label_values(&[name]).inc();
-                }
-                tracing::warn!("Adapter {} fetch attempt {} failed: {}", name, attempt, e);
-                if attempt >= max_retries {
-                    tracing::error!("Adapter {} exhausted retries ({}), giving up", name, max_retries);
-                    break;
-                }
-                let backoff = std::time::Duration::from_millis(base_backoff_ms * (1u64 << (attempt - 1)));
-                tokio::time::sleep(backoff).await;
-            }
-        }
-    }
-}
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+use std::sync::Arc;
+use std::pin::Pin;
+use std::future::Future;
+
+use crate::cache::AdapterCache;
+use crate::delta::ChangeTracker;
+use crate::rate_limit::{validate_schedule, TokenBucket};
+use crate::registry::{AuditEvent, AuditSink, HubPolicy, LogAuditSink};
+use crate::retry::{CircuitBreaker, CircuitState, RetryPolicy};
+
+pub type Result<T> = std::result::Result<T, String>;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AdapterHealth {
+    pub status: HealthStatus,
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    /// Share of successful fetches over the health window; `None` without recorded fetches
+    pub uptime_percentage: Option<f32>,
+    pub requests_per_hour: u32,
+    /// Age of the registry's cached data in seconds; filled in by `AdapterRegistry::get_health`
+    pub cache_age_secs: Option<u64>,
+    /// Average fetch latency over the health window; filled in by `AdapterRegistry::get_health`
+    pub avg_latency_ms: Option<u64>,
+    /// State of the registry's circuit breaker for this adapter; filled in by `AdapterRegistry::get_health`
+    pub circuit: Option<CircuitState>,
+}
+
+impl AdapterHealth {
+    /// Health of an adapter without fetch history. `AdapterRegistry::get_health` asks the adapter only until the
+    /// registry's `HealthMonitor` has recorded a fetch, so adapters do not track fetch outcomes themselves.
+    pub fn unmonitored(status: HealthStatus, last_error: Option<String>) -> Self {
+        Self {
+            status,
+            last_success: None,
+            last_error,
+            uptime_percentage: None,
+            requests_per_hour: 0,
+            cache_age_secs: None,
+            avg_latency_ms: None,
+            circuit: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Down,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RateLimit {
+    pub requests_per_hour: u32,
+    pub burst: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum AdapterData {
+    Ships(Value),
+    Loadouts(Value),
+    Systems(Value),
+    Prices(Value),
+    News(Value),
+    Other(Value),
+}
+
+#[async_trait]
+pub trait DataAdapter: Send + Sync {
+    fn name(&self) -> &str;
+    fn version(&self) -> &str;
+    fn source_url(&self) -> &str;
+    fn attribution(&self) -> &str;
+    fn license(&self) -> &str;
+    /// Id of the data hub this adapter reads from; must be listed in `registry::HUBS` to register
+    fn hub_id(&self) -> Option<&str> { None }
+
+    async fn health_check(&self) -> Result<AdapterHealth>;
+    async fn fetch(&self) -> Result<AdapterData>;
+
+    fn rate_limit(&self) -> RateLimit;
+    fn cache_ttl(&self) -> Duration;
+    /// Retries and circuit breaking applied by the registry around `fetch`
+    fn retry_policy(&self) -> RetryPolicy { RetryPolicy::default() }
+
+    async fn initialize(&self) -> Result<()> { Ok(()) }
+    async fn shutdown(&self) -> Result<()> { Ok(()) }
+}
+
+// lightweight scheduler and health monitor with Schedule support
+
+
+#[derive(Clone)]
+pub enum Schedule {
+    Fixed(Duration),        // Every N seconds/minutes/hours
+    Cron(String),           // Cron expression (supports seconds with "with-seconds")
+    OnDemand,               // Manual trigger only
+}
+
+pub struct AdapterScheduler {
+    pub schedules: std::collections::HashMap<String, Schedule>,
+}
+impl AdapterScheduler {
+    pub fn new() -> Self { AdapterScheduler { schedules: std::collections::HashMap::new() } }
+
+    pub fn schedule(&mut self, adapter_name: String, schedule: Schedule) {
+        self.schedules.insert(adapter_name, schedule);
+    }
+}
+
+/// Serializable description of a `Schedule`, used when listing adapters.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScheduleInfo {
+    Fixed { every_secs: u64 },
+    Cron { expr: String },
+    OnDemand,
+}
+
+impl From<&Schedule> for ScheduleInfo {
+    fn from(s: &Schedule) -> Self {
+        match s {
+            Schedule::Fixed(d) => ScheduleInfo::Fixed { every_secs: d.as_secs() },
+            Schedule::Cron(expr) => ScheduleInfo::Cron { expr: expr.clone() },
+            Schedule::OnDemand => ScheduleInfo::OnDemand,
+        }
+    }
+}
+
+/// A registered adapter as reported by `AdapterRegistry::list`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AdapterInfo {
+    pub name: String,
+    pub version: String,
+    pub hub_id: Option<String>,
+    pub source_url: String,
+    pub attribution: String,
+    pub license: String,
+    pub schedule: ScheduleInfo,
+    pub paused: bool,
+    /// When the cached data was fetched
+    pub last_fetched_at: Option<DateTime<Utc>>,
+    /// Content hash of the last published payload
+    pub last_hash: Option<String>,
+}
+
+impl Default for AdapterScheduler {
+    fn default() -> Self { Self::new() }
+}
+
+pub use crate::health::{HealthMonitor, HealthThresholds};
+
+#[cfg(test)]
+#[allow(clippy::items_after_test_module, clippy::type_complexity, clippy::await_holding_lock)]
+mod reg_tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::pin::Pin;
+    use std::future::Future;
+    use tokio::time::Duration;
+
+    type MsgList = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
+
+    struct MockPublisher { events: MsgList }
+    impl MockPublisher { fn new() -> Self { Self { events: Arc::new(Mutex::new(Vec::new())) } }
+    fn events(&self) -> MsgList { self.events.clone() }
+    }
+
+    impl EventPublisher for MockPublisher {
+        fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+            let subj = subject.to_string();
+            let events = self.events.clone();
+            Box::pin(async move {
+                // TODO(SOT) [TRACKED-002]: Replace lock usage with proper error handling to avoid poisoning panics in production
+                // See docs/TRACKED_TODOS.md#TRACKED-002
+                let mut guard = match events.lock() {
+                    Ok(g) => g,
+                    Err(e) => {
+                        tracing::error!("MockPublisher publish failed: mutex poisoned: {}", e);
+                        return Ok(());
+                    }
+                };
+                guard.push((subj, payload));
+                Ok(())
+            })
+        }
+    }
+
+    struct TestAdapter;
+
+    #[async_trait]
+    impl DataAdapter for TestAdapter {
+        fn name(&self) -> &str { "test" }
+        fn version(&self) -> &str { "0.1.0" }
+        fn source_url(&self) -> &str { "local" }
+        fn attribution(&self) -> &str { "test" }
+        fn license(&self) -> &str { "MIT" }
+        // any Allowed hub
+        fn hub_id(&self) -> Option<&str> { Some("fleetyards") }
+
+        async fn health_check(&self) -> crate::Result<AdapterHealth> {
+            Ok(AdapterHealth {
+                status: HealthStatus::Healthy,
+                last_success: Some(chrono::Utc::now()),
+                last_error: None,
+                uptime_percentage: Some(100.0),
+                requests_per_hour: 0,
+                cache_age_secs: None,
+                avg_latency_ms: None,
+                circuit: None,
+            })
+        }
+
+        async fn fetch(&self) -> crate::Result<AdapterData> {
+            Ok(AdapterData::Other(serde_json::json!({"x":"y"})))
+        }
+
+        fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 3600, burst: 2 } }
+        fn cache_ttl(&self) -> Duration { Duration::from_secs(1) }
+    }
+
+    #[tokio::test]
+    async fn registry_publishes_adapter_data() {
+        let mut reg = AdapterRegistry::new();
+        let adapter = std::sync::Arc::new(TestAdapter);
+        reg.register(adapter, Schedule::Fixed(std::time::Duration::from_secs(1))).expect("register");
+
+        let pubm = MockPublisher::new();
+        let events = pubm.events();
+        let arc_pubm = std::sync::Arc::new(pubm);
+        reg.start_all(Some(arc_pubm), None).await.expect("start_all failed in test");
+
+        // give the scheduler a bit of time to run one iteration
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        let guard = match events.lock() {
+            Ok(g) => g,
+            Err(e) => { tracing::error!("events mutex poisoned in test: {}", e); panic!("events mutex poisoned"); }
+        }; 
+        assert!(!guard.is_empty(), "No events published by adapter scheduler");
+        drop(guard);
+
+        // Also test the direct fetch_and_update method
+        let publisher2 = MockPublisher::new();
+        let events2 = publisher2.events();
+        let arc_pub2 = std::sync::Arc::new(publisher2);
+        reg.fetch_and_update("test", Some(arc_pub2)).await.expect("fetch_and_update failed in test");
+        let guard2 = match events2.lock() {
+            Ok(g) => g,
+            Err(e) => { tracing::error!("events2 mutex poisoned in test: {}", e); panic!("events2 mutex poisoned"); }
+        }; 
+        // the scheduler already published this content; an unchanged on-demand fetch publishes nothing
+        assert!(guard2.is_empty(), "fetch_and_update republished unchanged data");
+    }
+
+    struct CountingAdapter { calls: std::sync::atomic::AtomicU32, ttl: Duration }
+
+    #[async_trait]
+    impl DataAdapter for CountingAdapter {
+        fn name(&self) -> &str { "counting" }
+        fn version(&self) -> &str { "0.1.0" }
+        fn source_url(&self) -> &str { "local" }
+        fn attribution(&self) -> &str { "test" }
+        fn license(&self) -> &str { "MIT" }
+        fn hub_id(&self) -> Option<&str> { Some("fleetyards") }
+
+        async fn health_check(&self) -> crate::Result<AdapterHealth> {
+            Ok(AdapterHealth { status: HealthStatus::Healthy, last_success: None, last_error: None, uptime_percentage: Some(100.0), requests_per_hour: 0, cache_age_secs: None, avg_latency_ms: None, circuit: None })
+        }
+
+        async fn fetch(&self) -> crate::Result<AdapterData> {
+            let n = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            Ok(AdapterData::Other(serde_json::json!({ "call": n })))
+        }
+
+        fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 10, burst: 2 } }
+        fn cache_ttl(&self) -> Duration { self.ttl }
+    }
+
+    #[tokio::test]
+    async fn registry_serves_cache_within_ttl() {
+        let adapter = Arc::new(CountingAdapter { calls: Default::default(), ttl: Duration::from_secs(60) });
+        let mut reg = AdapterRegistry::new();
+        reg.register(adapter.clone(), Schedule::OnDemand).expect("register");
+
+        let pubm = Arc::new(MockPublisher::new());
+        let events = pubm.events();
+        reg.fetch_and_update("counting", Some(pubm.clone())).await.expect("first");
+        reg.fetch_and_update("counting", Some(pubm.clone())).await.expect("cached");
+        assert_eq!(adapter.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        // cached, unchanged data is not published again
+        assert_eq!(events.lock().map(|e| e.len()).unwrap_or_default(), 1);
+
+        reg.force_refresh("counting", None).await.expect("forced");
+        assert_eq!(adapter.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        match reg.fetch_cached("counting", false).await.expect("cached") {
+            AdapterData::Other(v) => assert_eq!(v["call"], 2),
+            _ => unreachable!("unexpected variant"),
+        }
+
+        let health = reg.get_health().await;
+        assert!(health["counting"].cache_age_secs.is_some_and(|a| a < 60));
+        // cache hits are not fetches
+        assert_eq!(health["counting"].requests_per_hour, 2);
+        assert!(matches!(health["counting"].status, HealthStatus::Healthy));
+    }
+
+    #[tokio::test]
+    async fn changed_content_publishes_delta() {
+        let adapter = Arc::new(CountingAdapter { calls: Default::default(), ttl: Duration::from_secs(60) });
+        let mut reg = AdapterRegistry::new();
+        reg.register(adapter.clone(), Schedule::OnDemand).expect("register");
+        let pubm = Arc::new(MockPublisher::new());
+        let events = pubm.events();
+
+        reg.fetch_and_update("counting", Some(pubm.clone())).await.expect("baseline");
+        reg.fetch_and_update("counting", Some(pubm.clone())).await.expect("unchanged");
+        reg.force_refresh("counting", Some(pubm.clone())).await.expect("changed");
+
+        let events = events.lock().expect("events");
+        let subjects: Vec<&str> = events.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(subjects, ["adapters.counting.data", "adapters.counting.delta"]);
+        let delta: crate::delta::DataDelta = serde_json::from_slice(&events[1].1).expect("delta json");
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.changed[0].path, "call");
+        assert_eq!(delta.changed[0].after, 2);
+    }
+
+    #[tokio::test]
+    async fn list_pause_and_trigger() {
+        let adapter = Arc::new(CountingAdapter { calls: Default::default(), ttl: Duration::ZERO });
+        let mut reg = AdapterRegistry::new();
+        reg.register(adapter.clone(), Schedule::Fixed(Duration::from_secs(3600))).expect("register");
+        reg.pause("counting").expect("pause before start");
+        let pubm = Arc::new(MockPublisher::new());
+        let events = pubm.events();
+        reg.start_all(Some(pubm), None).await.expect("start");
+
+        // the immediate first tick is skipped while paused
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(adapter.calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+        let info = reg.list().into_iter().next().expect("listed");
+        assert_eq!(info.name, "counting");
+        assert!(info.paused && info.last_fetched_at.is_none());
+        assert!(matches!(info.schedule, ScheduleInfo::Fixed { every_secs: 3600 }));
+
+        // on-demand trigger still runs and publishes through the start_all publisher
+        reg.trigger("counting").await.expect("trigger");
+        assert_eq!(adapter.calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(events.lock().map(|e| e.len()).unwrap_or_default(), 1);
+        let info = reg.info("counting").expect("info");
+        assert!(info.last_fetched_at.is_some() && info.last_hash.is_some());
+
+        reg.resume("counting").expect("resume");
+        assert!(!reg.is_paused("counting"));
+        assert!(reg.pause("missing").is_err());
+    }
+
+    #[tokio::test]
+    async fn zero_ttl_disables_cache() {
+        let adapter = Arc::new(CountingAdapter { calls: Default::default(), ttl: Duration::ZERO });
+        let mut reg = AdapterRegistry::new();
+        reg.register(adapter.clone(), Schedule::OnDemand).expect("register");
+        reg.fetch_and_update("counting", None).await.expect("first");
+        reg.fetch_and_update("counting", None).await.expect("second");
+        assert_eq!(adapter.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn registry_throttles_past_rate_limit() {
+        let adapter = Arc::new(CountingAdapter { calls: Default::default(), ttl: Duration::ZERO });
+        let mut reg = AdapterRegistry::new();
+        // 10/h cannot sustain a fetch every second
+        assert!(reg.register(adapter.clone(), Schedule::Fixed(Duration::from_secs(1))).is_err());
+        assert!(reg.get("counting").is_none());
+
+        reg.register(adapter.clone(), Schedule::OnDemand).expect("register");
+        let metrics = Arc::new(prometheus::Registry::new());
+        reg.start_all(None, Some(metrics.clone())).await.expect("start");
+
+        // burst of 2, then throttled without calling the adapter
+        reg.force_refresh("counting", None).await.expect("first");
+        reg.force_refresh("counting", None).await.expect("second");
+        let err = reg.force_refresh("counting", None).await.expect_err("throttled");
+        assert!(err.contains("rate limited"));
+        assert_eq!(adapter.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        let throttled = metrics.gather().into_iter().find(|f| f.name() == "adapter_throttled_total").expect("throttle metric");
+        assert_eq!(throttled.get_metric()[0].get_counter().value(), 1.0);
+    }
+
+    struct FlakyAdapter { calls: std::sync::atomic::AtomicU32, error: Mutex<Option<&'static str>> }
+
+    #[async_trait]
+    impl DataAdapter for FlakyAdapter {
+        fn name(&self) -> &str { "flaky" }
+        fn version(&self) -> &str { "0.1.0" }
+        fn source_url(&self) -> &str { "local" }
+        fn attribution(&self) -> &str { "test" }
+        fn license(&self) -> &str { "MIT" }
+        fn hub_id(&self) -> Option<&str> { Some("fleetyards") }
+
+        async fn health_check(&self) -> crate::Result<AdapterHealth> {
+            Ok(AdapterHealth { status: HealthStatus::Healthy, last_success: None, last_error: None, uptime_percentage: Some(100.0), requests_per_hour: 0, cache_age_secs: None, avg_latency_ms: None, circuit: None })
+        }
+
+        async fn fetch(&self) -> crate::Result<AdapterData> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            match *self.error.lock().unwrap_or_else(|e| e.into_inner()) {
+                Some(e) => Err(e.to_string()),
+                None => Ok(AdapterData::Other(serde_json::json!({}))),
+            }
+        }
+
+        fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 3600, burst: 20 } }
+        fn cache_ttl(&self) -> Duration { Duration::ZERO }
+        fn retry_policy(&self) -> crate::retry::RetryPolicy {
+            crate::retry::RetryPolicy {
+                max_attempts: 2,
+                base_backoff: Duration::from_millis(1),
+                jitter: 0.0,
+                breaker: crate::retry::BreakerConfig { failure_threshold: 3, open_for: Duration::from_millis(100) },
+                ..Default::default()
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_and_circuit_breaker_follow_policy() {
+        let adapter = Arc::new(FlakyAdapter { calls: Default::default(), error: Mutex::new(Some("flaky returned 503 Service Unavailable")) });
+        let mut reg = AdapterRegistry::new();
+        reg.register(adapter.clone(), Schedule::OnDemand).expect("register");
+        let metrics = Arc::new(prometheus::Registry::new());
+        reg.start_all(None, Some(metrics.clone())).await.expect("start");
+        let calls = || adapter.calls.load(std::sync::atomic::Ordering::SeqCst);
+
+        // transient error: retried up to max_attempts
+        assert!(reg.force_refresh("flaky", None).await.is_err());
+        assert_eq!(calls(), 2);
+        // third failure opens the circuit and stops retrying
+        assert!(reg.force_refresh("flaky", None).await.is_err());
+        assert_eq!(calls(), 3);
+        let err = reg.force_refresh("flaky", None).await.expect_err("open");
+        assert!(err.contains("circuit open"));
+        assert_eq!(calls(), 3);
+        let health = reg.get_health().await;
+        assert_eq!(health["flaky"].circuit, Some(crate::retry::CircuitState::Open));
+        assert!(matches!(health["flaky"].status, HealthStatus::Down));
+
+        // half-open trial succeeds and closes the circuit
+        *adapter.error.lock().expect("lock") = None;
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        reg.force_refresh("flaky", None).await.expect("half-open trial");
+        assert_eq!(reg.get_health().await["flaky"].circuit, Some(crate::retry::CircuitState::Closed));
+
+        // client errors are not retried
+        *adapter.error.lock().expect("lock") = Some("flaky returned 404 Not Found");
+        let before = calls();
+        assert!(reg.force_refresh("flaky", None).await.is_err());
+        assert_eq!(calls(), before + 1);
+
+        let transitions = metrics.gather().into_iter().find(|f| f.name() == "adapter_circuit_transitions_total").expect("transition metric");
+        assert_eq!(transitions.get_metric().len(), 3);
+    }
+
+    struct HubAdapter(Option<&'static str>);
+
+    #[async_trait]
+    impl DataAdapter for HubAdapter {
+        fn name(&self) -> &str { "hub-test" }
+        fn version(&self) -> &str { "0.1.0" }
+        fn source_url(&self) -> &str { "local" }
+        fn attribution(&self) -> &str { "test" }
+        fn license(&self) -> &str { "MIT" }
+        fn hub_id(&self) -> Option<&str> { self.0 }
+
+        async fn health_check(&self) -> crate::Result<AdapterHealth> { Err("unused".into()) }
+        async fn fetch(&self) -> crate::Result<AdapterData> { Err("unused".into()) }
+        fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 10, burst: 2 } }
+        fn cache_ttl(&self) -> Duration { Duration::ZERO }
+    }
+
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<crate::registry::AuditEvent>>);
+    impl crate::registry::AuditSink for RecordingSink {
+        fn emit(&self, event: crate::registry::AuditEvent) {
+            self.0.lock().expect("audit lock").push(event);
+        }
+    }
+
+    #[test]
+    fn register_enforces_hub_policy() {
+        let sink = Arc::new(RecordingSink::default());
+        let mut reg = AdapterRegistry::new().with_audit_sink(sink.clone());
+        for hub in [Some("player_tracking"), None, Some("not_a_hub"), Some("spectrum_dev")] {
+            assert!(reg.register(Arc::new(HubAdapter(hub)), Schedule::OnDemand).is_err(), "{:?}", hub);
+        }
+        assert!(reg.get("hub-test").is_none());
+        let events = sink.0.lock().expect("audit lock");
+        assert_eq!(events.len(), 4);
+        match &events[0] {
+            crate::registry::AuditEvent::ToSViolation { adapter, hub_id, category, .. } => {
+                assert_eq!(adapter, "hub-test");
+                assert_eq!(hub_id.as_deref(), Some("player_tracking"));
+                assert_eq!(category, &Some(crate::registry::HubCategory::Forbidden));
+            }
+        }
+        drop(events);
+
+        let mut opted = AdapterRegistry::new().with_hub_policy(crate::registry::HubPolicy::new().opt_in("spectrum_dev"));
+        opted.register(Arc::new(HubAdapter(Some("spectrum_dev"))), Schedule::OnDemand).expect("opted-in optional hub");
+        reg.register(Arc::new(HubAdapter(Some("uex"))), Schedule::OnDemand).expect("allowed hub");
+
+        let mut exempting = AdapterRegistry::new().with_hub_policy(crate::registry::HubPolicy::new().exempt("hub-test"));
+        exempting.register(Arc::new(HubAdapter(None)), Schedule::OnDemand).expect("exempt adapter without a hub");
+    }
+}
+
+/// EventPublisher: abstraction for publishing adapter events (used by scheduler)
+pub trait EventPublisher: Send + Sync + 'static {
+    fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+}
+
+pub struct AdapterRegistry {
+    adapters: HashMap<String, Arc<dyn DataAdapter>>,
+    scheduler: AdapterScheduler,
+    health_monitor: Arc<HealthMonitor>,
+    cache: Arc<AdapterCache>,
+    controls: HashMap<String, Arc<AdapterControl>>,
+    metrics: Option<Arc<AdapterMetrics>>,
+    hub_policy: HubPolicy,
+    audit: Arc<dyn AuditSink>,
+    changes: Arc<ChangeTracker>,
+    publisher: Option<Arc<dyn EventPublisher>>,
+}
+
+/// Per-adapter fetch guards shared by scheduled and on-demand fetches.
+struct AdapterControl {
+    policy: RetryPolicy,
+    bucket: TokenBucket,
+    breaker: CircuitBreaker,
+    /// Scheduled ticks are skipped while set; on-demand fetches still run
+    paused: std::sync::atomic::AtomicBool,
+}
+
+/// Prometheus handles shared by the scheduler tasks and on-demand fetches (labeled by adapter name).
+#[derive(Clone)]
+struct AdapterMetrics {
+    fetch_success: prometheus::IntCounterVec,
+    fetch_failure: prometheus::IntCounterVec,
+    publish_total: prometheus::IntCounterVec,
+    fetch_latency: prometheus::HistogramVec,
+    throttled: prometheus::IntCounterVec,
+    circuit_state: prometheus::IntGaugeVec,
+    circuit_transitions: prometheus::IntCounterVec,
+}
+
+impl AdapterMetrics {
+    fn new(reg: &prometheus::Registry) -> Option<Self> {
+        let counter = |name: &str, help: &str| prometheus::IntCounterVec::new(prometheus::Opts::new(name, help), &["adapter"]);
+        let build = || -> prometheus::Result<Self> {
+            Ok(Self {
+                fetch_success: counter("adapter_fetch_success_total", "Adapter fetch successes")?,
+                fetch_failure: counter("adapter_fetch_failure_total", "Adapter fetch failures")?,
+                publish_total: counter("adapter_publish_total", "Adapter publishes")?,
+                fetch_latency: prometheus::HistogramVec::new(
+                    prometheus::HistogramOpts::new("adapter_fetch_seconds", "Adapter fetch latency seconds"),
+                    &["adapter"],
+                )?,
+                throttled: counter("adapter_throttled_total", "Adapter fetches rejected by the rate limiter")?,
+                circuit_state: prometheus::IntGaugeVec::new(
+                    prometheus::Opts::new("adapter_circuit_state", "Adapter circuit breaker state (0 closed, 1 half-open, 2 open)"),
+                    &["adapter"],
+                )?,
+                circuit_transitions: prometheus::IntCounterVec::new(
+                    prometheus::Opts::new("adapter_circuit_transitions_total", "Adapter circuit breaker state transitions"),
+                    &["adapter", "state"],
+                )?,
+            })
+        };
+        match build() {
+            Ok(m) => {
+                reg.register(Box::new(m.fetch_success.clone())).ok();
+                reg.register(Box::new(m.fetch_failure.clone())).ok();
+                reg.register(Box::new(m.publish_total.clone())).ok();
+                reg.register(Box::new(m.fetch_latency.clone())).ok();
+                reg.register(Box::new(m.throttled.clone())).ok();
+                reg.register(Box::new(m.circuit_state.clone())).ok();
+                reg.register(Box::new(m.circuit_transitions.clone())).ok();
+                Some(m)
+            }
+            Err(e) => {
+                tracing::error!("Failed to create metrics: {}", e);
+                None
+            }
+        }
+    }
+
+    fn set_circuit(&self, name: &str, state: CircuitState) {
+        let value = match state {
+            CircuitState::Closed => 0,
+            CircuitState::HalfOpen => 1,
+            CircuitState::Open => 2,
+        };
+        self.circuit_state.with_label_values(&[name]).set(value);
+    }
+}
+
+impl AdapterRegistry {
+    pub fn new() -> Self {
+        Self {
+            adapters: HashMap::new(),
+            scheduler: AdapterScheduler::new(),
+            health_monitor: Arc::new(HealthMonitor::new()),
+            cache: Arc::new(AdapterCache::new()),
+            controls: HashMap::new(),
+            metrics: None,
+            hub_policy: HubPolicy::new(),
+            audit: Arc::new(LogAuditSink),
+            changes: Arc::new(ChangeTracker::new()),
+            publisher: None,
+        }
+    }
+
+    /// Use `cache` (e.g. `AdapterCache::with_dir` for on-disk persistence) instead of the in-memory default.
+    pub fn with_cache(mut self, cache: AdapterCache) -> Self {
+        self.cache = Arc::new(cache);
+        self
+    }
+
+    pub fn cache(&self) -> &AdapterCache {
+        &self.cache
+    }
+
+    /// Derive adapter health from `thresholds` instead of the defaults.
+    pub fn with_health_thresholds(mut self, thresholds: HealthThresholds) -> Self {
+        self.health_monitor = Arc::new(HealthMonitor::with_thresholds(thresholds));
+        self
+    }
+
+    pub fn health_monitor(&self) -> &HealthMonitor {
+        &self.health_monitor
+    }
+
+    /// Check registrations against `policy` (e.g. to opt in to Optional hubs) instead of the default.
+    pub fn with_hub_policy(mut self, policy: HubPolicy) -> Self {
+        self.hub_policy = policy;
+        self
+    }
+
+    /// Publish a full snapshot on `adapters.{name}.data` every `interval` in addition to deltas.
+    pub fn with_snapshot_interval(mut self, interval: Duration) -> Self {
+        self.changes = Arc::new(ChangeTracker::with_snapshot_interval(interval));
+        self
+    }
+
+    /// Use `changes` (e.g. `ChangeTracker::new().with_dir(..)` so deltas survive a restart) instead of the in-memory default.
+    pub fn with_changes(mut self, changes: ChangeTracker) -> Self {
+        self.changes = Arc::new(changes);
+        self
+    }
+
+    pub fn changes(&self) -> &ChangeTracker {
+        &self.changes
+    }
+
+    /// Send audit events (rejected registrations) to `sink` instead of the log.
+    pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit = sink;
+        self
+    }
+
+    /// Register `adapter` with its own token bucket and circuit breaker. Adapters whose hub the hub policy
+    /// refuses (and that it does not exempt) are rejected and reported as `ToSViolation`; so are schedules that
+    /// would exceed `rate_limit`.
+    pub fn register(&mut self, adapter: Arc<dyn DataAdapter>, schedule: Schedule) -> Result<()> {
+        let name = adapter.name().to_string();
+        if let Err(reason) = self.hub_policy.check_adapter(&name, adapter.hub_id()) {
+            let hub_id = adapter.hub_id().map(str::to_string);
+            let category = hub_id.as_deref().and_then(crate::registry::hub_info).map(|h| h.category.clone());
+            self.audit.emit(AuditEvent::ToSViolation { adapter: name.clone(), hub_id, category, reason: reason.clone(), at: chrono::Utc::now() });
+            return Err(format!("Adapter {} rejected by hub policy: {}", name, reason));
+        }
+        let limit = adapter.rate_limit();
+        validate_schedule(&limit, &schedule, adapter.cache_ttl())
+            .map_err(|e| format!("Adapter {} schedule rejected: {}", name, e))?;
+        let policy = adapter.retry_policy();
+        let control = AdapterControl {
+            bucket: TokenBucket::new(&limit),
+            breaker: CircuitBreaker::new(policy.breaker.clone()),
+            policy,
+            paused: Default::default(),
+        };
+        self.controls.insert(name.clone(), Arc::new(control));
+        self.adapters.insert(name.clone(), adapter);
+        self.scheduler.schedule(name.clone(), schedule);
+        self.health_monitor.monitor(name);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn DataAdapter>> {
+        self.adapters.get(name).cloned()
+    }
+
+    /// Registered adapter `name` with its attribution, schedule and last data.
+    pub fn info(&self, name: &str) -> Option<AdapterInfo> {
+        let adapter = self.adapters.get(name)?;
+        Some(AdapterInfo {
+            name: name.to_string(),
+            version: adapter.version().to_string(),
+            hub_id: adapter.hub_id().map(str::to_string),
+            source_url: adapter.source_url().to_string(),
+            attribution: adapter.attribution().to_string(),
+            license: adapter.license().to_string(),
+            schedule: self.scheduler.schedules.get(name).map(ScheduleInfo::from).unwrap_or(ScheduleInfo::OnDemand),
+            paused: self.is_paused(name),
+            last_fetched_at: self.cache.entry(name).map(|e| e.fetched_at),
+            last_hash: self.changes.last_hash(name),
+        })
+    }
+
+    /// All registered adapters, sorted by name.
+    pub fn list(&self) -> Vec<AdapterInfo> {
+        let mut names: Vec<&String> = self.adapters.keys().collect();
+        names.sort();
+        names.into_iter().filter_map(|n| self.info(n)).collect()
+    }
+
+    /// Stop the scheduler from fetching `name` until `resume`; on-demand fetches are unaffected.
+    pub fn pause(&self, name: &str) -> Result<()> {
+        self.set_paused(name, true)
+    }
+
+    pub fn resume(&self, name: &str) -> Result<()> {
+        self.set_paused(name, false)
+    }
+
+    pub fn is_paused(&self, name: &str) -> bool {
+        self.controls.get(name).is_some_and(|c| c.paused.load(std::sync::atomic::Ordering::SeqCst))
+    }
+
+    fn set_paused(&self, name: &str, paused: bool) -> Result<()> {
+        let control = self.controls.get(name).ok_or_else(|| format!("Adapter not found: {}", name))?;
+        if matches!(self.scheduler.schedules.get(name), None | Some(Schedule::OnDemand)) {
+            return Err(format!("Adapter {} has no schedule to pause or resume", name));
+        }
+        control.paused.store(paused, std::sync::atomic::Ordering::SeqCst);
+        tracing::info!("Adapter {} {}", name, if paused { "paused" } else { "resumed" });
+        Ok(())
+    }
+
+    /// Run `fetch_and_update` for `name` with the publisher given to `start_all`.
+    pub async fn trigger(&self, name: &str) -> Result<()> {
+        self.fetch_and_update(name, self.publisher.clone()).await
+    }
+
+    /// Data of a named adapter, served from the cache while younger than the adapter's `cache_ttl`
+    /// unless `force` is set; fresh data is stored in the cache.
+    pub async fn fetch_cached(&self, name: &str, force: bool) -> Result<AdapterData> {
+        let adapter = self.adapters.get(name).cloned().ok_or_else(|| format!("Adapter not found: {}", name))?;
+        if !force {
+            if let Some(data) = self.cache.get_fresh(name, adapter.cache_ttl()) {
+                tracing::debug!("Serving cached data for adapter {}", name);
+                return Ok(data);
+            }
+        }
+        let control = self.controls.get(name).ok_or_else(|| format!("Adapter not registered: {}", name))?;
+        let data = guarded_fetch(name, &adapter, control, &self.health_monitor, self.metrics.as_deref()).await?;
+        self.cache.put(name, data.clone());
+        Ok(data)
+    }
+
+    /// Fetch data from a named adapter (cached within its TTL) and publish it (if publisher provided) like a scheduled
+    /// fetch: a delta when the content changed and the full data only when a snapshot is due
+    pub async fn fetch_and_update(&self, name: &str, publisher: Option<std::sync::Arc<dyn EventPublisher>>) -> Result<()> {
+        self.update(name, publisher, false).await
+    }
+
+    /// Like `fetch_and_update`, but always calls the adapter and replaces the cached data.
+    pub async fn force_refresh(&self, name: &str, publisher: Option<std::sync::Arc<dyn EventPublisher>>) -> Result<()> {
+        self.update(name, publisher, true).await
+    }
+
+    async fn update(&self, name: &str, publisher: Option<std::sync::Arc<dyn EventPublisher>>, force: bool) -> Result<()> {
+        let data = self.fetch_cached(name, force).await?;
+        if let Some(p) = publisher {
+            publish_changes(name, &data, p.as_ref(), &self.changes, self.metrics.as_deref()).await;
+        }
+        Ok(())
+    }
+
+    /// Start all adapters and spawn scheduler tasks for those with fixed schedules.
+    pub async fn start_all(&mut self, publisher: Option<std::sync::Arc<dyn EventPublisher>>, metrics_registry: Option<std::sync::Arc<prometheus::Registry>>) -> Result<()> {
+        for (name, adapter) in &self.adapters {
+            tracing::info!("Initializing adapter: {}", name);
+            adapter.initialize().await?;
+        }
+
+        self.publisher = publisher.clone();
+
+        // Prepare metrics if requested
+        let metric_handles = metrics_registry.as_ref().and_then(|reg| AdapterMetrics::new(reg)).map(Arc::new);
+        self.metrics = metric_handles.clone();
+        if let Some(m) = metric_handles.as_ref() {
+            for (name, control) in &self.controls {
+                m.set_circuit(name, control.breaker.state());
+            }
+        }
+
+        // Collect spawn tasks without borrowing `self` for the task lifetime
+        type Launch = (String, Schedule, Arc<dyn DataAdapter>, Arc<AdapterControl>);
+        let mut launches: Vec<Launch> = Vec::new();
+        for (name, schedule) in &self.scheduler.schedules {
+            if let (Some(adapter), Some(control)) = (self.adapters.get(name), self.controls.get(name)) {
+                launches.push((name.clone(), schedule.clone(), adapter.clone(), control.clone()));
+            }
+        }
+
+        for (name, schedule, adapter, control) in launches {
+            match schedule {
+                Schedule::Fixed(interval) => {
+                    let adapter = adapter.clone();
+                    let n = name.clone();
+                    let intv = interval;
+                    let pub_opt = publisher.clone();
+                    let metric_counters = metric_handles.clone();
+                    let cache = self.cache.clone();
+                    let health = self.health_monitor.clone();
+                    let changes = self.changes.clone();
+                    let _task = tokio::spawn(async move {
+                        let mut ticker = tokio::time::interval(intv);
+                        loop {
+                            ticker.tick().await;
+                            // Pass the metric handles Option through; function will handle absence gracefully
+                            run_scheduled_fetch(&n, &adapter, &pub_opt, metric_counters.clone(), &cache, &control, &health, &changes).await;
+                        }
+                    });
+                }
+                Schedule::Cron(expr) => {
+                    let adapter = adapter.clone();
+                    let n = name.clone();
+                    let cron_expr = expr.clone();
+                    let pub_opt = publisher.clone();
+                    let metric_counters = metric_handles.clone();
+                    let cache = self.cache.clone();
+                    let health = self.health_monitor.clone();
+                    let changes = self.changes.clone();
+                    let _task = tokio::spawn(async move {
+                        // Use the cron crate for full cron expressions (supports seconds when specified)
+                        match std::str::FromStr::from_str(&cron_expr) {
+                            Ok(schedule_parsed) => {
+                                let mut upcoming = cron::Schedule::upcoming(&schedule_parsed, chrono::Utc);
+                                loop {
+                                    if let Some(next_dt) = upcoming.next() {
+                                        let now: chrono::DateTime<chrono::Utc> = chrono::Utc::now();
+                                        let dur = match (next_dt - now).to_std() {
+                                            Ok(d) => d,
+                                            Err(_) => std::time::Duration::from_secs(0),
+                                        };
+                                        tokio::time::sleep(dur).await;
+                                        // Pass the metric handles Option through; function will handle absence gracefully
+                                        run_scheduled_fetch(&n, &adapter, &pub_opt, metric_counters.clone(), &cache, &control, &health, &changes).await;
+                                    } else {
+                                        tracing::warn!("Cron schedule produced no upcoming times: {}", cron_expr);
+                                        break;
+                                    }
+                                }
+                            }
+                            Err(e) => tracing::error!("Invalid cron expression for adapter {}: {}: {}", name, cron_expr, e),
+                        }
+                    });
+                }
+                Schedule::OnDemand => {}
+            }
+        }
+
+        self.health_monitor.start().await?;
+        Ok(())
+    }
+
+    /// Health of every adapter, computed from recorded fetches; adapters that have not fetched yet fall back
+    /// to their own `health_check`.
+    pub async fn get_health(&self) -> HashMap<String, AdapterHealth> {
+        let mut health = HashMap::new();
+        for (name, adapter) in &self.adapters {
+            let mut h = match self.health_monitor.health(name) {
+                Some(h) => h,
+                None => match adapter.health_check().await {
+                    Ok(h) => h,
+                    Err(e) => {
+                        tracing::error!("Health check failed for {}: {}", name, e);
+                        AdapterHealth::unmonitored(HealthStatus::Down, Some(e))
+                    }
+                },
+            };
+            h.cache_age_secs = self.cache.age(name).map(|a| a.as_secs());
+            if let Some(control) = self.controls.get(name) {
+                let circuit = control.breaker.state();
+                match circuit {
+                    CircuitState::Open => h.status = HealthStatus::Down,
+                    CircuitState::HalfOpen if !matches!(h.status, HealthStatus::Down) => h.status = HealthStatus::Degraded,
+                    _ => {}
+                }
+                h.circuit = Some(circuit);
+            }
+            health.insert(name.clone(), h);
+        }
+        health
+    }
+}
+
+impl Default for AdapterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Helper: run a scheduled fetch with retries, then cache and publish the result
+#[allow(clippy::too_many_arguments)]
+async fn run_scheduled_fetch(name: &str, adapter: &Arc<dyn DataAdapter>, publisher: &Option<std::sync::Arc<dyn EventPublisher>>, metrics_opt: Option<Arc<AdapterMetrics>>, cache: &AdapterCache, control: &AdapterControl, health: &HealthMonitor, changes: &ChangeTracker) {
+    if control.paused.load(std::sync::atomic::Ordering::SeqCst) {
+        tracing::debug!("Adapter {} paused, skipping scheduled fetch", name);
+        return;
+    }
+    // Data cached within the TTL was already published; skip this tick
+    if cache.get_fresh(name, adapter.cache_ttl()).is_some() {
+        tracing::debug!("Adapter {} cache still fresh, skipping scheduled fetch", name);
+        return;
+    }
+    let metrics = metrics_opt.as_deref();
+
+    let data = match guarded_fetch(name, adapter, control, health, metrics).await {
+        Ok(data) => data,
+        Err(e) => {
+            tracing::error!("Adapter {} scheduled fetch failed: {}", name, e);
+            return;
+        }
+    };
+    cache.put(name, data.clone());
+    if let Some(p) = &publisher {
+        publish_changes(name, &data, p.as_ref(), changes, metrics).await;
+    }
+}
+
+/// Publish `adapters.{name}.delta` if `data` differs from the adapter's last published payload, and the full data on
+/// `adapters.{name}.data` for the first payload and whenever a snapshot is due. `data` becomes the tracker's baseline
+/// only once something was published, so changes lost to a failed publish go out with the next fetch.
+async fn publish_changes(name: &str, data: &AdapterData, publisher: &dyn EventPublisher, changes: &ChangeTracker, metrics: Option<&AdapterMetrics>) {
+    let observation = changes.observe(name, data);
+    let mut sent = false;
+    if let Some(delta) = observation.delta {
+        sent = publish_json(name, &format!("adapters.{}.delta", name), &delta, publisher, metrics).await;
+    }
+    let snapshot = observation.snapshot_due && publish_json(name, &format!("adapters.{}.data", name), data, publisher, metrics).await;
+    if sent || snapshot {
+        changes.published(name, data, snapshot);
+    }
+}
+
+async fn publish_json<T: serde::Serialize>(name: &str, subject: &str, value: &T, publisher: &dyn EventPublisher, metrics: Option<&AdapterMetrics>) -> bool {
+    let payload = match serde_json::to_vec(value) {
+        Ok(payload) => payload,
+        Err(e) => {
+            tracing::error!("Failed to serialize adapter {} payload for {}: {}", name, subject, e);
+            return false;
+        }
+    };
+    if let Err(e) = publisher.publish(subject, payload).await {
+        tracing::error!("Failed to publish adapter {} payload on {}: {}", name, subject, e);
+        return false;
+    }
+    if let Some(m) = metrics {
+        m.publish_total.with_label_values(&[name]).inc();
+    }
+    true
+}
+
+/// Call `adapter.fetch` under its rate limit, circuit breaker and retry policy, recording every attempt in
+/// health and metrics.
+async fn guarded_fetch(name: &str, adapter: &Arc<dyn DataAdapter>, control: &AdapterControl, health: &HealthMonitor, metrics: Option<&AdapterMetrics>) -> Result<AdapterData> {
+    let policy = &control.policy;
+    let transition = |state: Option<CircuitState>| {
+        if let Some(state) = state {
+            tracing::warn!("Adapter {} circuit {}", name, state.as_str());
+            if let Some(m) = metrics {
+                m.set_circuit(name, state);
+                m.circuit_transitions.with_label_values(&[name, state.as_str()]).inc();
+            }
+        }
+    };
+
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+        let (allowed, changed) = control.breaker.allow();
+        transition(changed);
+        if !allowed {
+            return Err(format!("Adapter {} circuit open", name));
+        }
+        // Scheduled ticks, retries and on-demand fetches share the adapter's bucket
+        if !control.bucket.try_acquire() {
+            control.breaker.abandon();
+            if let Some(m) = metrics {
+                m.throttled.with_label_values(&[name]).inc();
+            }
+            return Err(format!("Adapter {} rate limited", name));
+        }
+        let start = std::time::Instant::now();
+        match adapter.fetch().await {
+            Ok(data) => {
+                let elapsed = start.elapsed();
+                health.record_success(name, elapsed);
+                transition(control.breaker.on_success());
+                if let Some(m) = metrics {
+                    m.fetch_success.with_label_values(&[name]).inc();
+                    m.fetch_latency.with_label_values(&[name]).observe(elapsed.as_secs_f64());
+                }
+                return Ok(data);
+            }
+            Err(e) => {
+                health.record_failure(name, start.elapsed(), &e);
+                transition(control.breaker.on_failure());
+                if let Some(m) = metrics {
+                    m.fetch_failure.with_label_values(&[name]).inc();
+                }
+                tracing::warn!("Adapter {} fetch attempt {} failed: {}", name, attempt, e);
+                if attempt >= policy.max_attempts.max(1) || !(policy.retryable)(&e) {
+                    return Err(e);
+                }
+                tokio::time::sleep(policy.backoff(attempt)).await;
+            }
+        }
+    }
+}
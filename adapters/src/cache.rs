@@ -0,0 +1,137 @@
+//! Per-adapter response cache used by `AdapterRegistry` to honor `DataAdapter::cache_ttl`.
+//!
+//! Entries live in memory and, when a directory is configured, are mirrored to `<dir>/<adapter>.json` so a
+//! restart within the TTL does not hit the source again.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::adapter_api::AdapterData;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub data: AdapterData,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CacheEntry {
+    pub fn age(&self) -> Duration {
+        (Utc::now() - self.fetched_at).to_std().unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+pub struct AdapterCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+    dir: Option<PathBuf>,
+}
+
+impl AdapterCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Persist entries as JSON files under `dir` (created on first write).
+    pub fn with_dir(dir: impl Into<PathBuf>) -> Self {
+        Self { entries: Mutex::new(HashMap::new()), dir: Some(dir.into()) }
+    }
+
+    pub(crate) fn file(dir: &Path, name: &str) -> PathBuf {
+        let safe: String = name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect();
+        dir.join(format!("{}.json", safe))
+    }
+
+    fn load(&self, name: &str) -> Option<CacheEntry> {
+        let raw = std::fs::read(Self::file(self.dir.as_ref()?, name)).ok()?;
+        match serde_json::from_slice(&raw) {
+            Ok(e) => Some(e),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable cache file for adapter {}: {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Entry for `name`, falling back to the disk copy.
+    pub fn entry(&self, name: &str) -> Option<CacheEntry> {
+        let mut entries = self.entries.lock().ok()?;
+        if let Some(e) = entries.get(name) {
+            return Some(e.clone());
+        }
+        let e = self.load(name)?;
+        entries.insert(name.to_string(), e.clone());
+        Some(e)
+    }
+
+    /// Cached data for `name` if younger than `ttl`; a zero TTL disables caching.
+    pub fn get_fresh(&self, name: &str, ttl: Duration) -> Option<AdapterData> {
+        if ttl.is_zero() {
+            return None;
+        }
+        self.entry(name).filter(|e| e.age() < ttl).map(|e| e.data)
+    }
+
+    pub fn age(&self, name: &str) -> Option<Duration> {
+        self.entry(name).map(|e| e.age())
+    }
+
+    pub fn put(&self, name: &str, data: AdapterData) {
+        let entry = CacheEntry { data, fetched_at: Utc::now() };
+        if let Some(dir) = &self.dir {
+            if let Err(e) = Self::write(dir, name, &entry) {
+                tracing::warn!("Failed to persist cache for adapter {}: {}", name, e);
+            }
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(name.to_string(), entry);
+        }
+    }
+
+    fn write(dir: &Path, name: &str, entry: &CacheEntry) -> std::io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let path = Self::file(dir, name);
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec(entry)?)?;
+        std::fs::rename(tmp, path)
+    }
+
+    pub fn invalidate(&self, name: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(name);
+        }
+        if let Some(dir) = &self.dir {
+            let _ = std::fs::remove_file(Self::file(dir, name));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn entries_expire_and_survive_restart_on_disk() {
+        let dir = std::env::temp_dir().join(format!("sc-manager-cache-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let cache = AdapterCache::with_dir(&dir);
+        assert!(cache.get_fresh("uex", Duration::from_secs(60)).is_none());
+        cache.put("uex", AdapterData::Prices(json!({"n": 1})));
+        assert!(cache.get_fresh("uex", Duration::from_secs(60)).is_some());
+        assert!(cache.get_fresh("uex", Duration::ZERO).is_none());
+
+        let restarted = AdapterCache::with_dir(&dir);
+        assert!(matches!(restarted.get_fresh("uex", Duration::from_secs(60)), Some(AdapterData::Prices(_))));
+        assert!(restarted.age("uex").is_some_and(|a| a < Duration::from_secs(60)));
+
+        restarted.invalidate("uex");
+        assert!(AdapterCache::with_dir(&dir).entry("uex").is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,25 @@
+//! RSS/Atom parsing for the Comm-Link feed.
+
+use super::{NewsItem, NewsSource};
+
+/// Parse an RSS 2.0 or Atom document.
+pub fn parse_feed(bytes: &[u8]) -> crate::Result<Vec<NewsItem>> {
+    let feed = feed_rs::parser::parse(bytes).map_err(|e| format!("rsi_comms feed: {}", e))?;
+    Ok(feed
+        .entries
+        .into_iter()
+        .filter_map(|e| {
+            let link = e.links.first().map(|l| l.href.clone())?;
+            let id = if e.id.is_empty() { link.clone() } else { e.id };
+            Some(NewsItem {
+                id,
+                source: NewsSource::CommLink,
+                title: e.title.map(|t| t.content.trim().to_string()).unwrap_or_default(),
+                category: e.categories.first().map(|c| c.label.clone().unwrap_or_else(|| c.term.clone())),
+                published: e.published.or(e.updated),
+                link,
+                summary: e.summary.map(|s| s.content.trim().to_string()).filter(|s| !s.is_empty()),
+            })
+        })
+        .collect())
+}
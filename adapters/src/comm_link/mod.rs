@@ -0,0 +1,140 @@
+//! RSI Comm-Link & patch notes adapter (outbound)
+//!
+//! Parses the Comm-Link RSS/Atom feed (`feed`) and the patch-notes listing page (`patch_notes`) into `NewsItem`s and
+//! publishes the current listing as `AdapterData::News`. New items reach subscribers as `added` entries of the
+//! registry's `adapters.rsi_comms.delta` events, matched by item id.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::adapter_api::{AdapterData, AdapterHealth, DataAdapter, RateLimit};
+use crate::health;
+
+pub mod feed;
+pub mod patch_notes;
+pub use feed::parse_feed;
+pub use patch_notes::parse_patch_notes;
+
+pub const DEFAULT_FEED_URL: &str = "https://robertsspaceindustries.com/comm-link/rss";
+pub const DEFAULT_PATCH_NOTES_URL: &str = "https://robertsspaceindustries.com/patch-notes";
+
+/// Schema version of the `NewsBatch` payload; bump on breaking changes.
+pub const NEWS_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsSource {
+    CommLink,
+    PatchNotes,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsItem {
+    /// Feed guid, or the link when the source has none
+    pub id: String,
+    pub source: NewsSource,
+    pub title: String,
+    pub category: Option<String>,
+    pub published: Option<DateTime<Utc>>,
+    pub link: String,
+    pub summary: Option<String>,
+}
+
+/// Payload of `AdapterData::News`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsBatch {
+    pub schema_version: u32,
+    pub fetched_at: DateTime<Utc>,
+    /// Items currently listed, newest first
+    pub items: Vec<NewsItem>,
+}
+
+impl NewsBatch {
+    /// Decode a batch from `AdapterData::News`.
+    pub fn from_adapter_data(data: &AdapterData) -> crate::Result<Self> {
+        let AdapterData::News(v) = data else {
+            return Err("expected AdapterData::News".to_string());
+        };
+        serde_json::from_value(v.clone()).map_err(|e| e.to_string())
+    }
+}
+
+/// Comm-Link + patch notes adapter (hub id `rsi_comms`).
+pub struct CommLinkAdapter {
+    feed_url: String,
+    patch_notes_url: Option<String>,
+    timeout: Duration,
+}
+
+impl CommLinkAdapter {
+    pub fn new() -> Self {
+        Self {
+            feed_url: DEFAULT_FEED_URL.to_string(),
+            patch_notes_url: Some(DEFAULT_PATCH_NOTES_URL.to_string()),
+            timeout: Duration::from_secs(15),
+        }
+    }
+
+    pub fn with_feed_url(mut self, url: impl Into<String>) -> Self {
+        self.feed_url = url.into();
+        self
+    }
+
+    /// Patch notes listing to scrape; `None` disables patch notes.
+    pub fn with_patch_notes_url(mut self, url: Option<String>) -> Self {
+        self.patch_notes_url = url;
+        self
+    }
+
+    async fn get(&self, client: &reqwest::Client, url: &str) -> crate::Result<String> {
+        let resp = client.get(url).send().await.map_err(|e| format!("rsi_comms request failed: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("rsi_comms {} returned {}", url, resp.status()));
+        }
+        resp.text().await.map_err(|e| e.to_string())
+    }
+
+    /// All items currently listed (feed + patch notes), newest first.
+    pub async fn fetch_items(&self) -> crate::Result<Vec<NewsItem>> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        let mut items = parse_feed(self.get(&client, &self.feed_url).await?.as_bytes())?;
+        if let Some(url) = &self.patch_notes_url {
+            items.extend(parse_patch_notes(&self.get(&client, url).await?, url));
+        }
+        items.sort_by_key(|i| std::cmp::Reverse(i.published));
+        Ok(items)
+    }
+}
+
+impl Default for CommLinkAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataAdapter for CommLinkAdapter {
+    fn name(&self) -> &str { "rsi_comms" }
+    fn version(&self) -> &str { "0.1.0" }
+    fn source_url(&self) -> &str { &self.feed_url }
+    fn attribution(&self) -> &str { "Comm-Link and patch notes © Cloud Imperium Games / Roberts Space Industries" }
+    fn license(&self) -> &str { "RSI Terms of Service (links and summaries only)" }
+    fn hub_id(&self) -> Option<&str> { Some("rsi_comms") }
+
+    async fn health_check(&self) -> crate::Result<AdapterHealth> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        Ok(health::probe("rsi_comms", client.get(&self.feed_url)).await)
+    }
+
+    async fn fetch(&self) -> crate::Result<AdapterData> {
+        let items = self.fetch_items().await?;
+        let batch = NewsBatch { schema_version: NEWS_SCHEMA_VERSION, fetched_at: Utc::now(), items };
+        serde_json::to_value(batch).map(AdapterData::News).map_err(|e| e.to_string())
+    }
+
+    fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 12, burst: 2 } }
+    fn cache_ttl(&self) -> Duration { Duration::from_secs(15 * 60) }
+}
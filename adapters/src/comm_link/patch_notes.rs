@@ -0,0 +1,54 @@
+//! HTML parsing for the patch-notes listing.
+//!
+//! Each note is an `a.patch-note` card holding `.title`, `time[datetime]`, `.category` and `.summary`.
+
+use chrono::{DateTime, Utc};
+use scraper::{Html, Selector};
+
+use super::{NewsItem, NewsSource};
+
+fn sel(s: &str) -> Selector {
+    Selector::parse(s).unwrap_or_else(|e| panic!("invalid selector {:?}: {:?}", s, e))
+}
+
+/// Resolve `href` against the listing URL's origin.
+fn absolute(href: &str, page_url: &str) -> String {
+    if href.contains("://") {
+        return href.to_string();
+    }
+    let origin = page_url.splitn(4, '/').take(3).collect::<Vec<_>>().join("/");
+    format!("{}/{}", origin, href.trim_start_matches('/'))
+}
+
+/// Parse the patch-notes listing page fetched from `page_url`.
+pub fn parse_patch_notes(html: &str, page_url: &str) -> Vec<NewsItem> {
+    let doc = Html::parse_document(html);
+    let (card, title, time, category, summary) =
+        (sel("a.patch-note"), sel(".title"), sel("time"), sel(".category"), sel(".summary"));
+    let text = |el: scraper::ElementRef, s: &Selector| {
+        el.select(s)
+            .next()
+            .map(|e| e.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|t| !t.is_empty())
+    };
+
+    doc.select(&card)
+        .filter_map(|a| {
+            let link = absolute(a.value().attr("href")?, page_url);
+            Some(NewsItem {
+                id: link.clone(),
+                source: NewsSource::PatchNotes,
+                title: text(a, &title)?,
+                category: text(a, &category).or_else(|| Some("Patch Notes".to_string())),
+                published: a
+                    .select(&time)
+                    .next()
+                    .and_then(|t| t.value().attr("datetime"))
+                    .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+                    .map(|d| d.with_timezone(&Utc)),
+                link,
+                summary: text(a, &summary),
+            })
+        })
+        .collect()
+}
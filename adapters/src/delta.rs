@@ -0,0 +1,383 @@
+//! Change detection for adapter payloads.
+//!
+//! `ChangeTracker` keeps the last published payload of every adapter together with its content hash. The
+//! registry publishes a `DataDelta` on `adapters.{name}.delta` only when the hash changes, and full payloads on
+//! `adapters.{name}.data` for the first observation and, optionally, at a lower snapshot cadence. A payload is
+//! recorded only once it was published, so a failed publish is reported again on the next fetch. With a directory
+//! configured the last published payload is mirrored to `<dir>/<adapter>_changes.json`, so the first fetch after a
+//! restart is diffed against it rather than treated as a first observation.
+//!
+//! Diffs are structural: list elements are matched by an identity field (`id`, `slug`, `handle`,
+//! `commodity`+`terminal` or `code`) and reported as added, removed or changed items; other values are
+//! compared field by field. Volatile fields such as `fetched_at` are ignored.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::adapter_api::AdapterData;
+use crate::cache::AdapterCache;
+
+/// Top-level fields that change on every fetch without the content changing
+const VOLATILE_FIELDS: &[&str] = &["fetched_at"];
+
+/// Identity fields tried, in order, to match list elements across payloads
+const KEY_FIELDS: &[&[&str]] = &[&["id"], &["slug"], &["handle"], &["commodity", "terminal"], &["code"]];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeltaItem {
+    pub path: String,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangedItem {
+    pub path: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataDelta {
+    pub adapter: String,
+    pub hash: String,
+    pub previous_hash: String,
+    pub added: Vec<DeltaItem>,
+    pub removed: Vec<DeltaItem>,
+    pub changed: Vec<ChangedItem>,
+    pub at: DateTime<Utc>,
+}
+
+impl DataDelta {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// `data` as JSON with volatile fields removed and object keys sorted.
+fn normalize(data: &AdapterData) -> Value {
+    let mut v = canonical(serde_json::to_value(data).unwrap_or(Value::Null));
+    if let Some(Value::Object(inner)) = v.as_object_mut().and_then(|o| o.values_mut().next()) {
+        for f in VOLATILE_FIELDS {
+            inner.remove(*f);
+        }
+    }
+    v
+}
+
+fn canonical(v: Value) -> Value {
+    match v {
+        Value::Object(map) => {
+            let sorted: BTreeMap<String, Value> = map.into_iter().map(|(k, v)| (k, canonical(v))).collect();
+            Value::Object(sorted.into_iter().collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonical).collect()),
+        other => other,
+    }
+}
+
+fn hash_value(v: &Value) -> String {
+    let bytes = serde_json::to_vec(v).unwrap_or_default();
+    Sha256::digest(&bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// SHA-256 (hex) of the normalized payload; equal for payloads that differ only in volatile fields.
+pub fn content_hash(data: &AdapterData) -> String {
+    hash_value(&normalize(data))
+}
+
+fn item_key(v: &Value) -> Option<String> {
+    let obj = v.as_object()?;
+    KEY_FIELDS.iter().find_map(|fields| {
+        let parts: Option<Vec<String>> = fields
+            .iter()
+            .map(|f| match obj.get(*f)? {
+                Value::String(s) => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+            .collect();
+        parts.map(|p| p.join("@"))
+    })
+}
+
+/// Elements of `items` keyed by identity, or by content hash when identities are missing or repeat.
+fn keyed(items: &[Value]) -> Vec<(String, &Value)> {
+    let by_id: Vec<Option<String>> = items.iter().map(item_key).collect();
+    let mut seen = std::collections::HashSet::new();
+    let unique = by_id.iter().all(|k| k.as_ref().is_some_and(|k| seen.insert(k.clone())));
+    items
+        .iter()
+        .zip(by_id)
+        .map(|(v, k)| match k {
+            Some(k) if unique => (k, v),
+            _ => (format!("#{}", &hash_value(v)[..12]), v),
+        })
+        .collect()
+}
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() { field.to_string() } else { format!("{}.{}", path, field) }
+}
+
+fn diff_into(path: &str, before: &Value, after: &Value, out: &mut (Vec<DeltaItem>, Vec<DeltaItem>, Vec<ChangedItem>)) {
+    if before == after {
+        return;
+    }
+    match (before, after) {
+        (Value::Object(b), Value::Object(a)) => {
+            for (k, bv) in b {
+                match a.get(k) {
+                    Some(av) => diff_into(&join(path, k), bv, av, out),
+                    None => out.1.push(DeltaItem { path: join(path, k), value: bv.clone() }),
+                }
+            }
+            for (k, av) in a.iter().filter(|(k, _)| !b.contains_key(*k)) {
+                out.0.push(DeltaItem { path: join(path, k), value: av.clone() });
+            }
+        }
+        (Value::Array(b), Value::Array(a)) => {
+            let before_keyed = keyed(b);
+            let before_items: HashMap<&String, &Value> = before_keyed.iter().map(|(k, v)| (k, *v)).collect();
+            let after_items = keyed(a);
+            for (k, av) in &after_items {
+                let item_path = format!("{}[{}]", path, k);
+                match before_items.get(k) {
+                    Some(bv) if *bv != *av => out.2.push(ChangedItem { path: item_path, before: (*bv).clone(), after: (*av).clone() }),
+                    Some(_) => {}
+                    None => out.0.push(DeltaItem { path: item_path, value: (*av).clone() }),
+                }
+            }
+            let after_keys: std::collections::HashSet<&String> = after_items.iter().map(|(k, _)| k).collect();
+            for (k, bv) in before_keyed.iter().filter(|(k, _)| !after_keys.contains(k)) {
+                out.1.push(DeltaItem { path: format!("{}[{}]", path, k), value: (*bv).clone() });
+            }
+        }
+        _ => out.2.push(ChangedItem { path: path.to_string(), before: before.clone(), after: after.clone() }),
+    }
+}
+
+/// Structural diff between two payloads as (added, removed, changed).
+pub fn diff(before: &AdapterData, after: &AdapterData) -> (Vec<DeltaItem>, Vec<DeltaItem>, Vec<ChangedItem>) {
+    let mut out = (Vec::new(), Vec::new(), Vec::new());
+    let (b, a) = (normalize(before), normalize(after));
+    match (b.as_object().and_then(|o| o.iter().next()), a.as_object().and_then(|o| o.iter().next())) {
+        // same variant: diff the wrapped values
+        (Some((bk, bv)), Some((ak, av))) if bk == ak => diff_into("", bv, av, &mut out),
+        _ => diff_into("", &b, &a, &mut out),
+    }
+    out
+}
+
+/// What the registry should publish for a new payload.
+#[derive(Debug, Default)]
+pub struct Observation {
+    /// Diff against the previous payload, if the content changed
+    pub delta: Option<DataDelta>,
+    /// Whether a full snapshot is due (first payload or snapshot interval elapsed)
+    pub snapshot_due: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Tracked {
+    hash: String,
+    data: AdapterData,
+    snapshot_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Default)]
+pub struct ChangeTracker {
+    snapshot_every: Option<Duration>,
+    last: Mutex<HashMap<String, Tracked>>,
+    dir: Option<PathBuf>,
+}
+
+impl ChangeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also report a full snapshot as due every `interval`, even without changes.
+    pub fn with_snapshot_interval(interval: Duration) -> Self {
+        Self { snapshot_every: Some(interval), ..Self::default() }
+    }
+
+    /// Persist the last published payload of every adapter as JSON files under `dir` (created on first write).
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    fn file(dir: &Path, name: &str) -> PathBuf {
+        AdapterCache::file(dir, &format!("{}_changes", name))
+    }
+
+    /// Last published payload of `name`, falling back to the disk copy.
+    fn tracked(&self, last: &mut HashMap<String, Tracked>, name: &str) -> Option<Tracked> {
+        if let Some(t) = last.get(name) {
+            return Some(t.clone());
+        }
+        let raw = std::fs::read(Self::file(self.dir.as_ref()?, name)).ok()?;
+        match serde_json::from_slice::<Tracked>(&raw) {
+            Ok(t) => {
+                last.insert(name.to_string(), t.clone());
+                Some(t)
+            }
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable change state for adapter {}: {}", name, e);
+                None
+            }
+        }
+    }
+
+    fn write(dir: &Path, name: &str, tracked: &Tracked) -> std::io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let path = Self::file(dir, name);
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec(tracked)?)?;
+        std::fs::rename(tmp, path)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Tracked>> {
+        match self.last.lock() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    /// Report what changed in `data` since the last published payload of `name`; nothing is recorded until
+    /// `published` is called.
+    pub fn observe(&self, name: &str, data: &AdapterData) -> Observation {
+        let mut last = self.lock();
+        let Some(prev) = self.tracked(&mut last, name) else {
+            return Observation { delta: None, snapshot_due: true };
+        };
+
+        let hash = content_hash(data);
+        let delta = (prev.hash != hash).then(|| {
+            let (added, removed, changed) = diff(&prev.data, data);
+            DataDelta { adapter: name.to_string(), hash, previous_hash: prev.hash.clone(), added, removed, changed, at: Utc::now() }
+        });
+        let snapshot_due = prev.snapshot_at.is_none()
+            || self.snapshot_every.is_some_and(|every| {
+                prev.snapshot_at.is_some_and(|at| (Utc::now() - at).to_std().unwrap_or_default() >= every)
+            });
+        Observation { delta, snapshot_due }
+    }
+
+    /// Record `data` as the last published payload of `name`; `snapshot` when the full payload was published.
+    pub fn published(&self, name: &str, data: &AdapterData, snapshot: bool) {
+        let hash = content_hash(data);
+        let mut last = self.lock();
+        let snapshot_at = self.tracked(&mut last, name).and_then(|t| t.snapshot_at);
+        let snapshot_at = if snapshot { Some(Utc::now()) } else { snapshot_at };
+        let tracked = Tracked { hash, data: data.clone(), snapshot_at };
+        if let Some(dir) = &self.dir {
+            if let Err(e) = Self::write(dir, name, &tracked) {
+                tracing::warn!("Failed to persist change state for adapter {}: {}", name, e);
+            }
+        }
+        last.insert(name.to_string(), tracked);
+    }
+
+    pub fn last_hash(&self, name: &str) -> Option<String> {
+        let mut last = self.lock();
+        self.tracked(&mut last, name).map(|t| t.hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn prices(rows: Value, fetched_at: &str) -> AdapterData {
+        AdapterData::Prices(json!({ "schema_version": 1, "fetched_at": fetched_at, "prices": rows }))
+    }
+
+    #[test]
+    fn hash_ignores_volatile_fields_and_key_order() {
+        let a = prices(json!([{"commodity": "Agricium", "terminal": "TDD", "sell_price": 25.0}]), "2026-01-01T00:00:00Z");
+        let b = prices(json!([{"terminal": "TDD", "sell_price": 25.0, "commodity": "Agricium"}]), "2026-01-02T00:00:00Z");
+        assert_eq!(content_hash(&a), content_hash(&b));
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_changed_items() {
+        let before = prices(
+            json!([
+                {"commodity": "Agricium", "terminal": "TDD", "sell_price": 25.0},
+                {"commodity": "Laranite", "terminal": "TDD", "sell_price": 28.0}
+            ]),
+            "t1",
+        );
+        let after = prices(
+            json!([
+                {"commodity": "Agricium", "terminal": "TDD", "sell_price": 26.5},
+                {"commodity": "Quantanium", "terminal": "Area18", "sell_price": 88.0}
+            ]),
+            "t2",
+        );
+        let (added, removed, changed) = diff(&before, &after);
+        assert_eq!(added.iter().map(|i| i.path.as_str()).collect::<Vec<_>>(), ["prices[Quantanium@Area18]"]);
+        assert_eq!(removed.iter().map(|i| i.path.as_str()).collect::<Vec<_>>(), ["prices[Laranite@TDD]"]);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].path, "prices[Agricium@TDD]");
+        assert_eq!(changed[0].after["sell_price"], 26.5);
+    }
+
+    #[test]
+    fn tracker_emits_delta_only_on_change() {
+        let tracker = ChangeTracker::new();
+        let v1 = AdapterData::Ships(json!({"org": "test", "ships": [{"id": "v1", "name": "Old Faithful"}]}));
+        let v2 = AdapterData::Ships(json!({"org": "test", "ships": [{"id": "v1", "name": "Old Faithful"}, {"id": "v2", "name": null}]}));
+
+        let first = tracker.observe("fleetyards", &v1);
+        assert!(first.delta.is_none() && first.snapshot_due);
+        // an unpublished snapshot stays due
+        assert!(tracker.observe("fleetyards", &v1).snapshot_due);
+        tracker.published("fleetyards", &v1, true);
+
+        let same = tracker.observe("fleetyards", &v1);
+        assert!(same.delta.is_none() && !same.snapshot_due);
+
+        let delta = tracker.observe("fleetyards", &v2).delta.expect("delta");
+        assert_eq!(delta.added.len(), 1);
+        assert_eq!(delta.added[0].path, "ships[v2]");
+        // until the delta is published it is reported again
+        assert!(tracker.observe("fleetyards", &v2).delta.is_some());
+        tracker.published("fleetyards", &v2, false);
+        assert_eq!(Some(delta.hash), tracker.last_hash("fleetyards"));
+        assert!(tracker.observe("fleetyards", &v2).delta.is_none());
+
+        let periodic = ChangeTracker::with_snapshot_interval(Duration::ZERO);
+        periodic.observe("fleetyards", &v1);
+        periodic.published("fleetyards", &v1, true);
+        assert!(periodic.observe("fleetyards", &v1).snapshot_due);
+    }
+
+    #[test]
+    fn persisted_tracker_reports_changes_after_restart() {
+        let dir = std::env::temp_dir().join(format!("sc-manager-changes-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let v1 = AdapterData::News(json!({"items": [{"id": "a", "title": "Patch 4.0"}]}));
+        let v2 = AdapterData::News(json!({"items": [{"id": "a", "title": "Patch 4.0"}, {"id": "b", "title": "Patch 4.1"}]}));
+
+        let tracker = ChangeTracker::new().with_dir(&dir);
+        tracker.published("comm_link", &v1, true);
+
+        let restarted = ChangeTracker::new().with_dir(&dir);
+        let observed = restarted.observe("comm_link", &v2);
+        assert!(!observed.snapshot_due);
+        let delta = observed.delta.expect("delta against the persisted payload");
+        assert_eq!(delta.added.iter().map(|i| i.path.as_str()).collect::<Vec<_>>(), ["items[b]"]);
+        assert!(ChangeTracker::new().observe("comm_link", &v2).delta.is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
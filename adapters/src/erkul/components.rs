@@ -0,0 +1,198 @@
+//! Typed Erkul component and loadout model.
+//!
+//! Covers weapons, shields, power plants, coolers and quantum drives, plus the derived loadout numbers
+//! (total DPS, shield HP, power budget).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use sc_manager_core::domain::Equipment;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentKind {
+    Weapon,
+    Shield,
+    PowerPlant,
+    Cooler,
+    QuantumDrive,
+}
+
+/// Component grade; Erkul reports grades as 1-4, shown in game as A-D.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Grade {
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Grade::A),
+            2 => Some(Grade::B),
+            3 => Some(Grade::C),
+            4 => Some(Grade::D),
+            _ => None,
+        }
+    }
+}
+
+/// Kind-specific stats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ComponentStats {
+    Weapon { dps: f64, alpha_damage: f64, fire_rate: f64, range: f64, power_draw: f64 },
+    Shield { hp: f64, regen: f64, power_draw: f64 },
+    PowerPlant { power_output: f64 },
+    Cooler { cooling_rate: f64, power_draw: f64 },
+    QuantumDrive { speed: f64, spool_time: f64, fuel_rate: f64, power_draw: f64 },
+}
+
+impl ComponentStats {
+    pub fn kind(&self) -> ComponentKind {
+        match self {
+            ComponentStats::Weapon { .. } => ComponentKind::Weapon,
+            ComponentStats::Shield { .. } => ComponentKind::Shield,
+            ComponentStats::PowerPlant { .. } => ComponentKind::PowerPlant,
+            ComponentStats::Cooler { .. } => ComponentKind::Cooler,
+            ComponentStats::QuantumDrive { .. } => ComponentKind::QuantumDrive,
+        }
+    }
+
+    /// Power consumed by the component (power plants consume none).
+    pub fn power_draw(&self) -> f64 {
+        match self {
+            ComponentStats::Weapon { power_draw, .. }
+            | ComponentStats::Shield { power_draw, .. }
+            | ComponentStats::Cooler { power_draw, .. }
+            | ComponentStats::QuantumDrive { power_draw, .. } => *power_draw,
+            ComponentStats::PowerPlant { .. } => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Component {
+    /// Erkul `localName`, stable across patches
+    pub id: String,
+    pub name: String,
+    pub manufacturer: Option<String>,
+    pub size: u8,
+    pub grade: Option<Grade>,
+    pub class: Option<String>,
+    pub stats: ComponentStats,
+}
+
+impl Component {
+    pub fn kind(&self) -> ComponentKind {
+        self.stats.kind()
+    }
+
+    /// Core value object for this component (Erkul data is read-only).
+    pub fn to_equipment(&self) -> Equipment {
+        Equipment::new(self.id.clone(), self.name.clone(), true)
+    }
+}
+
+/// Components indexed by id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComponentCatalog {
+    components: HashMap<String, Component>,
+}
+
+impl ComponentCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, c: Component) {
+        self.components.insert(c.id.to_ascii_lowercase(), c);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Component> {
+        self.components.get(&id.to_ascii_lowercase())
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// All components, sorted by id.
+    pub fn sorted(&self) -> Vec<&Component> {
+        let mut all: Vec<&Component> = self.components.values().collect();
+        all.sort_by(|a, b| a.id.cmp(&b.id));
+        all
+    }
+
+    pub fn of_kind(&self, kind: ComponentKind) -> impl Iterator<Item = &Component> {
+        self.components.values().filter(move |c| c.kind() == kind)
+    }
+}
+
+/// Component fitted to a hardpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoadoutSlot {
+    pub hardpoint: String,
+    pub component: Component,
+}
+
+/// Imported ship loadout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Loadout {
+    pub code: String,
+    pub ship: String,
+    pub name: Option<String>,
+    pub slots: Vec<LoadoutSlot>,
+    /// Items in the share that are missing from the catalog (e.g. not yet covered kinds)
+    pub unresolved: Vec<String>,
+}
+
+/// Numbers derived from a loadout.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LoadoutStats {
+    pub total_dps: f64,
+    pub total_alpha: f64,
+    pub shield_hp: f64,
+    pub shield_regen: f64,
+    pub power_output: f64,
+    pub power_draw: f64,
+    /// Output minus draw; negative means the loadout cannot run everything at once
+    pub power_budget: f64,
+    pub cooling_rate: f64,
+}
+
+impl Loadout {
+    pub fn stats(&self) -> LoadoutStats {
+        let mut s = LoadoutStats::default();
+        for slot in &self.slots {
+            let st = &slot.component.stats;
+            s.power_draw += st.power_draw();
+            match st {
+                ComponentStats::Weapon { dps, alpha_damage, .. } => {
+                    s.total_dps += dps;
+                    s.total_alpha += alpha_damage;
+                }
+                ComponentStats::Shield { hp, regen, .. } => {
+                    s.shield_hp += hp;
+                    s.shield_regen += regen;
+                }
+                ComponentStats::PowerPlant { power_output } => s.power_output += power_output,
+                ComponentStats::Cooler { cooling_rate, .. } => s.cooling_rate += cooling_rate,
+                ComponentStats::QuantumDrive { .. } => {}
+            }
+        }
+        s.power_budget = s.power_output - s.power_draw;
+        s
+    }
+
+    pub fn components_of(&self, kind: ComponentKind) -> impl Iterator<Item = &Component> {
+        self.slots.iter().map(|s| &s.component).filter(move |c| c.kind() == kind)
+    }
+}
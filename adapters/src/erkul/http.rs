@@ -0,0 +1,310 @@
+//! HTTP client for Erkul (<https://server.erkul.games>).
+//!
+//! Builds a `ComponentCatalog` from the per-kind item endpoints and imports loadouts from share links/codes.
+//! As a `DataAdapter` it publishes the catalog as `AdapterData::Loadouts`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+
+use sc_manager_core::domain::Equipment;
+
+use super::components::{Component, ComponentCatalog, ComponentKind, ComponentStats, Grade, Loadout, LoadoutSlot};
+use super::ErkulClient;
+use crate::adapter_api::{AdapterData, AdapterHealth, DataAdapter, RateLimit};
+use crate::health;
+
+pub const DEFAULT_BASE_URL: &str = "https://server.erkul.games";
+
+/// Item endpoint per component kind.
+fn endpoint(kind: ComponentKind) -> &'static str {
+    match kind {
+        ComponentKind::Weapon => "/live/weapons",
+        ComponentKind::Shield => "/live/shields",
+        ComponentKind::PowerPlant => "/live/power-plants",
+        ComponentKind::Cooler => "/live/coolers",
+        ComponentKind::QuantumDrive => "/live/qdrives",
+    }
+}
+
+const KINDS: [ComponentKind; 5] = [
+    ComponentKind::Weapon,
+    ComponentKind::Shield,
+    ComponentKind::PowerPlant,
+    ComponentKind::Cooler,
+    ComponentKind::QuantumDrive,
+];
+
+/// Extract the loadout code from an Erkul share link (`https://www.erkul.games/loadout/<code>`) or a bare code.
+pub fn parse_share_code(input: &str) -> crate::Result<String> {
+    let s = input.trim();
+    let s = s.split(['?', '#']).next().unwrap_or_default().trim_end_matches('/');
+    let code = match s.find("/loadout/") {
+        Some(idx) => &s[idx + "/loadout/".len()..],
+        None if s.contains("://") => return Err(format!("not an erkul loadout link: {}", s)),
+        None => s,
+    };
+    if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("invalid erkul loadout code: {:?}", code));
+    }
+    Ok(code.to_string())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawItem {
+    local_name: String,
+    data: RawData,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct RawData {
+    name: String,
+    size: u8,
+    grade: u8,
+    class: Option<String>,
+    manufacturer_data: Option<RawManufacturer>,
+    power_draw: f64,
+    // weapons
+    dps: f64,
+    alpha_damage: f64,
+    fire_rate: f64,
+    range: f64,
+    // shields
+    max_shield_health: f64,
+    max_shield_regen: f64,
+    // power plants
+    power_output: f64,
+    // coolers
+    cooling_rate: f64,
+    // quantum drives
+    drive_speed: f64,
+    spool_up_time: f64,
+    quantum_fuel_requirement: f64,
+}
+
+#[derive(Default, Deserialize)]
+struct RawManufacturer {
+    data: RawManufacturerData,
+}
+
+#[derive(Default, Deserialize)]
+struct RawManufacturerData {
+    name: Option<String>,
+}
+
+impl RawItem {
+    fn into_component(self, kind: ComponentKind) -> Component {
+        let d = self.data;
+        let stats = match kind {
+            ComponentKind::Weapon => ComponentStats::Weapon {
+                dps: d.dps,
+                alpha_damage: d.alpha_damage,
+                fire_rate: d.fire_rate,
+                range: d.range,
+                power_draw: d.power_draw,
+            },
+            ComponentKind::Shield => ComponentStats::Shield { hp: d.max_shield_health, regen: d.max_shield_regen, power_draw: d.power_draw },
+            ComponentKind::PowerPlant => ComponentStats::PowerPlant { power_output: d.power_output },
+            ComponentKind::Cooler => ComponentStats::Cooler { cooling_rate: d.cooling_rate, power_draw: d.power_draw },
+            ComponentKind::QuantumDrive => ComponentStats::QuantumDrive {
+                speed: d.drive_speed,
+                spool_time: d.spool_up_time,
+                fuel_rate: d.quantum_fuel_requirement,
+                power_draw: d.power_draw,
+            },
+        };
+        Component {
+            id: self.local_name,
+            name: d.name,
+            manufacturer: d.manufacturer_data.and_then(|m| m.data.name),
+            size: d.size,
+            grade: Grade::from_number(d.grade),
+            class: d.class,
+            stats,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawLoadout {
+    ship: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    loadout: Vec<RawSlot>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSlot {
+    item_port_name: String,
+    local_name: String,
+}
+
+/// Parse one item endpoint response into components of `kind`.
+pub fn parse_components(kind: ComponentKind, json: &str) -> crate::Result<Vec<Component>> {
+    let raw: Vec<RawItem> = serde_json::from_str(json).map_err(|e| format!("erkul {:?} items: {}", kind, e))?;
+    Ok(raw.into_iter().map(|r| r.into_component(kind)).collect())
+}
+
+/// Parse a loadout response and resolve its items against `catalog`.
+pub fn parse_loadout(code: &str, json: &str, catalog: &ComponentCatalog) -> crate::Result<Loadout> {
+    let raw: RawLoadout = serde_json::from_str(json).map_err(|e| format!("erkul loadout: {}", e))?;
+    let mut slots = vec![];
+    let mut unresolved = vec![];
+    for s in raw.loadout {
+        match catalog.get(&s.local_name) {
+            Some(c) => slots.push(LoadoutSlot { hardpoint: s.item_port_name, component: c.clone() }),
+            None => unresolved.push(s.local_name),
+        }
+    }
+    Ok(Loadout { code: code.to_string(), ship: raw.ship, name: raw.name, slots, unresolved })
+}
+
+/// Erkul API client. Implements `DataAdapter` (publishing the component catalog) and the blocking
+/// `ErkulClient` trait.
+pub struct HttpErkulClient {
+    base_url: String,
+    timeout: Duration,
+}
+
+impl HttpErkulClient {
+    pub fn new() -> Self {
+        Self { base_url: DEFAULT_BASE_URL.to_string(), timeout: Duration::from_secs(15) }
+    }
+
+    /// Point the client at another API root (e.g. a mock server).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn client(&self) -> crate::Result<reqwest::Client> {
+        reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())
+    }
+
+    async fn get(&self, path: &str) -> crate::Result<String> {
+        let resp = self
+            .client()?
+            .get(format!("{}{}", self.base_url, path))
+            .send()
+            .await
+            .map_err(|e| format!("erkul request failed: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("erkul {} returned {}", path, resp.status()));
+        }
+        resp.text().await.map_err(|e| e.to_string())
+    }
+
+    fn get_blocking(&self, path: &str) -> crate::Result<String> {
+        let client = reqwest::blocking::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        let resp = client
+            .get(format!("{}{}", self.base_url, path))
+            .send()
+            .map_err(|e| format!("erkul request failed: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("erkul {} returned {}", path, resp.status()));
+        }
+        resp.text().map_err(|e| e.to_string())
+    }
+
+    /// All weapons, shields, power plants, coolers and quantum drives.
+    pub async fn fetch_catalog(&self) -> crate::Result<ComponentCatalog> {
+        let mut catalog = ComponentCatalog::new();
+        for kind in KINDS {
+            for c in parse_components(kind, &self.get(endpoint(kind)).await?)? {
+                catalog.insert(c);
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// Blocking variant of `fetch_catalog`; must not be called from within an async runtime.
+    pub fn fetch_catalog_blocking(&self) -> crate::Result<ComponentCatalog> {
+        let mut catalog = ComponentCatalog::new();
+        for kind in KINDS {
+            for c in parse_components(kind, &self.get_blocking(endpoint(kind))?)? {
+                catalog.insert(c);
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// Import a loadout from a share link or code, resolving items against `catalog`.
+    pub async fn import_loadout(&self, link_or_code: &str, catalog: &ComponentCatalog) -> crate::Result<Loadout> {
+        let code = parse_share_code(link_or_code)?;
+        let body = self.get(&format!("/loadouts/{}", code)).await?;
+        parse_loadout(&code, &body, catalog)
+    }
+
+    /// Blocking variant of `import_loadout`; must not be called from within an async runtime.
+    pub fn import_loadout_blocking(&self, link_or_code: &str, catalog: &ComponentCatalog) -> crate::Result<Loadout> {
+        let code = parse_share_code(link_or_code)?;
+        let body = self.get_blocking(&format!("/loadouts/{}", code))?;
+        parse_loadout(&code, &body, catalog)
+    }
+}
+
+impl ErkulClient for HttpErkulClient {
+    /// Blocking fetch of the whole catalog; must not be called from within an async runtime. Erkul has no
+    /// per-org or per-ship equipment, so asking for either is an error.
+    fn fetch_equipment(&self, org_id: Option<&str>, ship_id: Option<&str>) -> Result<Vec<Equipment>, String> {
+        if org_id.is_some() || ship_id.is_some() {
+            return Err("erkul only provides the component catalog, not org or ship equipment".to_string());
+        }
+        Ok(self.fetch_catalog_blocking()?.sorted().into_iter().map(Component::to_equipment).collect())
+    }
+}
+
+#[async_trait]
+impl DataAdapter for HttpErkulClient {
+    fn name(&self) -> &str { "erkul" }
+    fn version(&self) -> &str { "0.1.0" }
+    fn source_url(&self) -> &str { &self.base_url }
+    fn attribution(&self) -> &str { "Component data provided by Erkul (erkul.games)" }
+    fn license(&self) -> &str { "Erkul terms of use" }
+    fn hub_id(&self) -> Option<&str> { Some("erkul") }
+
+    async fn health_check(&self) -> crate::Result<AdapterHealth> {
+        Ok(health::probe("erkul", self.client()?.get(format!("{}{}", self.base_url, endpoint(ComponentKind::PowerPlant)))).await)
+    }
+
+    async fn fetch(&self) -> crate::Result<AdapterData> {
+        let catalog = self.fetch_catalog().await?;
+        Ok(AdapterData::Loadouts(json!({ "components": catalog.sorted() })))
+    }
+
+    // each fetch requests all five component kinds; the catalog only changes with game patches
+    fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 4, burst: 2 } }
+    fn cache_ttl(&self) -> Duration { Duration::from_secs(6 * 60 * 60) }
+}
+
+impl Default for HttpErkulClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn share_codes_from_links_and_bare_codes() {
+        assert_eq!(parse_share_code("https://www.erkul.games/loadout/AbC123xy").unwrap(), "AbC123xy");
+        assert_eq!(parse_share_code(" https://www.erkul.games/loadout/AbC123xy/?ref=x ").unwrap(), "AbC123xy");
+        assert_eq!(parse_share_code("AbC123xy").unwrap(), "AbC123xy");
+        assert!(parse_share_code("https://example.com/other").is_err());
+        assert!(parse_share_code("bad code!").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+//! HTTP client for the FleetYards public API (<https://api.fleetyards.net/v1>).
+//!
+//! Fetches an organization's fleet (`/fleets/{slug}/vehicles`) and the ship-model
+//! catalog (`/models`), following `page`/`perPage` pagination.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use sc_manager_core::domain::Ship;
+
+use super::FleetYardClient;
+use crate::adapter_api::{AdapterData, AdapterHealth, DataAdapter, RateLimit};
+use crate::health;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.fleetyards.net/v1";
+
+const PER_PAGE: usize = 200;
+const MAX_PAGES: usize = 50;
+
+/// Ship model from the FleetYards catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShipModel {
+    pub slug: String,
+    pub name: String,
+    pub manufacturer: Option<String>,
+    pub classification: Option<String>,
+    pub size: Option<String>,
+    /// Cargo capacity in SCU
+    pub cargo: Option<f64>,
+    pub min_crew: Option<u32>,
+    pub max_crew: Option<u32>,
+    pub scm_speed: Option<f64>,
+    /// Pledge price in USD
+    pub pledge_price: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FyManufacturer {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FyModel {
+    slug: String,
+    name: String,
+    manufacturer: Option<FyManufacturer>,
+    classification: Option<String>,
+    size: Option<String>,
+    cargo: Option<f64>,
+    min_crew: Option<u32>,
+    max_crew: Option<u32>,
+    scm_speed: Option<f64>,
+    pledge_price: Option<f64>,
+}
+
+impl From<FyModel> for ShipModel {
+    fn from(m: FyModel) -> Self {
+        Self {
+            slug: m.slug,
+            name: m.name,
+            manufacturer: m.manufacturer.map(|x| x.name),
+            classification: m.classification,
+            size: m.size,
+            cargo: m.cargo,
+            min_crew: m.min_crew,
+            max_crew: m.max_crew,
+            scm_speed: m.scm_speed,
+            pledge_price: m.pledge_price,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FyVehicle {
+    id: String,
+    name: Option<String>,
+    model: FyModel,
+}
+
+fn vehicle_to_ship(v: FyVehicle, org_slug: &str) -> Ship {
+    let mut ship = Ship::new(v.id, v.model.name);
+    ship.owner_org = Some(org_slug.to_string());
+    ship.name = v.name.filter(|n| !n.is_empty());
+    ship
+}
+
+/// `/fleets/{slug}/vehicles` with the slug percent-encoded as a single path segment.
+fn fleet_path(org_slug: &str) -> String {
+    let mut url = reqwest::Url::parse("http://localhost/fleets").expect("static url");
+    url.path_segments_mut().expect("http url has a path").push(org_slug).push("vehicles");
+    url.path().to_string()
+}
+
+/// FleetYards API client. Implements the blocking `FleetYardClient` trait and, for the
+/// configured organization, `DataAdapter` (publishing `AdapterData::Ships`).
+pub struct HttpFleetYardClient {
+    base_url: String,
+    org_slug: String,
+    timeout: Duration,
+}
+
+impl HttpFleetYardClient {
+    pub fn new(org_slug: impl Into<String>) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            org_slug: org_slug.into(),
+            timeout: Duration::from_secs(15),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn page_url(&self, path: &str, page: usize) -> String {
+        format!("{}{}?page={}&perPage={}", self.base_url, path, page, PER_PAGE)
+    }
+
+    async fn get_all<T: serde::de::DeserializeOwned>(&self, path: &str) -> crate::Result<Vec<T>> {
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let mut out = vec![];
+        for page in 1..=MAX_PAGES {
+            let resp = client
+                .get(self.page_url(path, page))
+                .send()
+                .await
+                .map_err(|e| format!("fleetyards request failed: {}", e))?;
+            if !resp.status().is_success() {
+                return Err(format!("fleetyards {} returned {}", path, resp.status()));
+            }
+            let items: Vec<T> = resp.json().await.map_err(|e| format!("fleetyards {}: invalid json: {}", path, e))?;
+            let done = items.len() < PER_PAGE;
+            out.extend(items);
+            if done {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    fn get_all_blocking<T: serde::de::DeserializeOwned>(&self, path: &str) -> crate::Result<Vec<T>> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let mut out = vec![];
+        for page in 1..=MAX_PAGES {
+            let resp = client
+                .get(self.page_url(path, page))
+                .send()
+                .map_err(|e| format!("fleetyards request failed: {}", e))?;
+            if !resp.status().is_success() {
+                return Err(format!("fleetyards {} returned {}", path, resp.status()));
+            }
+            let items: Vec<T> = resp.json().map_err(|e| format!("fleetyards {}: invalid json: {}", path, e))?;
+            let done = items.len() < PER_PAGE;
+            out.extend(items);
+            if done {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Ships of `org_slug`'s public fleet.
+    pub async fn fetch_fleet(&self, org_slug: &str) -> crate::Result<Vec<Ship>> {
+        let vehicles: Vec<FyVehicle> = self.get_all(&fleet_path(org_slug)).await?;
+        Ok(vehicles.into_iter().map(|v| vehicle_to_ship(v, org_slug)).collect())
+    }
+
+    /// The ship-model catalog.
+    pub async fn fetch_models(&self) -> crate::Result<Vec<ShipModel>> {
+        let models: Vec<FyModel> = self.get_all("/models").await?;
+        Ok(models.into_iter().map(ShipModel::from).collect())
+    }
+
+    /// Blocking variant of `fetch_models`; must not be called from within an async runtime.
+    pub fn fetch_models_blocking(&self) -> crate::Result<Vec<ShipModel>> {
+        let models: Vec<FyModel> = self.get_all_blocking("/models")?;
+        Ok(models.into_iter().map(ShipModel::from).collect())
+    }
+}
+
+impl FleetYardClient for HttpFleetYardClient {
+    /// Blocking fetch; must not be called from within an async runtime.
+    fn fetch_ships_for_org(&self, org_id: &str) -> Result<Vec<Ship>, String> {
+        let vehicles: Vec<FyVehicle> = self.get_all_blocking(&fleet_path(org_id))?;
+        Ok(vehicles.into_iter().map(|v| vehicle_to_ship(v, org_id)).collect())
+    }
+}
+
+#[async_trait]
+impl DataAdapter for HttpFleetYardClient {
+    fn name(&self) -> &str { "fleetyards" }
+    fn version(&self) -> &str { "0.1.0" }
+    fn source_url(&self) -> &str { &self.base_url }
+    fn attribution(&self) -> &str { "Data provided by FleetYards.net" }
+    fn license(&self) -> &str { "FleetYards API terms of use" }
+    fn hub_id(&self) -> Option<&str> { Some("fleetyards") }
+
+    async fn health_check(&self) -> crate::Result<AdapterHealth> {
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(health::probe("fleetyards", client.get(format!("{}/models?page=1&perPage=1", self.base_url))).await)
+    }
+
+    async fn fetch(&self) -> crate::Result<AdapterData> {
+        let ships = self.fetch_fleet(&self.org_slug).await?;
+        let models = self.fetch_models().await?;
+        Ok(AdapterData::Ships(json!({
+            "org": self.org_slug,
+            "ships": ships,
+            "models": models,
+        })))
+    }
+
+    fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 120, burst: 10 } }
+    fn cache_ttl(&self) -> Duration { Duration::from_secs(60 * 60) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter_api::HealthStatus;
+    use httpmock::{Method::GET, MockServer};
+
+    fn model(slug: &str, name: &str) -> serde_json::Value {
+        json!({
+            "slug": slug,
+            "name": name,
+            "manufacturer": {"name": "Drake Interplanetary", "slug": "drake"},
+            "classification": "combat",
+            "size": "small",
+            "cargo": 46.0,
+            "minCrew": 1,
+            "maxCrew": 2,
+            "scmSpeed": 200.0,
+            "pledgePrice": 110.0
+        })
+    }
+
+    #[test]
+    fn fetch_ships_for_org_maps_vehicles() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/fleets/testorg/vehicles").query_param("page", "1");
+            then.status(200).json_body(json!([
+                {"id": "v1", "name": "Old Faithful", "model": model("cutlass-black", "Cutlass Black")},
+                {"id": "v2", "name": null, "model": model("buccaneer", "Buccaneer")}
+            ]));
+        });
+
+        let c = HttpFleetYardClient::new("testorg").with_base_url(server.base_url());
+        let ships = c.fetch_ships_for_org("testorg").expect("fetch");
+        assert_eq!(ships.len(), 2);
+        assert_eq!(ships[0].id, "v1");
+        assert_eq!(ships[0].model, "Cutlass Black");
+        assert_eq!(ships[0].owner_org.as_deref(), Some("testorg"));
+        assert_eq!(ships[0].name.as_deref(), Some("Old Faithful"));
+        assert_eq!(ships[1].name, None);
+    }
+
+    #[test]
+    fn org_slug_is_encoded_in_the_path() {
+        let server = MockServer::start();
+        let m = server.mock(|when, then| {
+            when.method(GET).path("/fleets/a%2Fb%20c/vehicles");
+            then.status(200).json_body(json!([]));
+        });
+        let c = HttpFleetYardClient::new("a/b c").with_base_url(server.base_url());
+        assert!(c.fetch_ships_for_org("a/b c").expect("fetch").is_empty());
+        m.assert();
+    }
+
+    #[test]
+    fn http_errors_are_reported() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/fleets/missing/vehicles");
+            then.status(404);
+        });
+        let c = HttpFleetYardClient::new("missing").with_base_url(server.base_url());
+        let err = c.fetch_ships_for_org("missing").unwrap_err();
+        assert!(err.contains("404"));
+    }
+
+    #[tokio::test]
+    async fn adapter_fetch_publishes_ships_and_models() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/fleets/testorg/vehicles");
+            then.status(200).json_body(json!([{"id": "v1", "model": model("cutlass-black", "Cutlass Black")}]));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/models");
+            then.status(200).json_body(json!([model("cutlass-black", "Cutlass Black")]));
+        });
+
+        let c = HttpFleetYardClient::new("testorg").with_base_url(server.base_url());
+        let data = c.fetch().await.expect("fetch");
+        match data {
+            AdapterData::Ships(v) => {
+                assert_eq!(v["ships"][0]["model"], "Cutlass Black");
+                assert_eq!(v["models"][0]["manufacturer"], "Drake Interplanetary");
+                assert_eq!(v["models"][0]["max_crew"], 2);
+            }
+            _ => unreachable!("unexpected variant"),
+        }
+
+        let health = c.health_check().await.expect("health");
+        assert!(matches!(health.status, HealthStatus::Healthy));
+        // fetch history lives in the registry's HealthMonitor
+        assert!(health.last_success.is_none() && health.uptime_percentage.is_none());
+    }
+
+    #[tokio::test]
+    async fn health_check_reports_down_when_unreachable() {
+        let c = HttpFleetYardClient::new("testorg")
+            .with_base_url("http://127.0.0.1:9")
+            .with_timeout(Duration::from_millis(500));
+        let health = c.health_check().await.expect("health");
+        assert!(matches!(health.status, HealthStatus::Down));
+        assert!(health.last_error.is_some());
+    }
+}
@@ -1,122 +1,159 @@
-//! Game.log parser adapter (inbound)
-
-use sc_manager_core::domain::game_event::GameEventType;
-use sc_manager_core::events::EventEnvelope;
-
-pub trait GameLogParser {
-    fn parse_line(&self, line: &str) -> Option<EventEnvelope>;
-}
-
-pub struct SimpleGameLogParser;
-
-impl SimpleGameLogParser {
-    fn parse_tokens(line: &str) -> Option<(GameEventType, i64, Option<String>)> {
-        // Robust regex-based parser
-        // Expected general format: "EVENT 1610000000 [key=value ...] [free text]"
-        let re =
-            regex::Regex::new(r"^(?P<ev>[A-Za-z_-]+)\s+(?P<ts>\d+)(?:\s+(?P<rest>.*))?$").expect("valid line regex");
-        let caps = re.captures(line.trim())?;
-        let ev = caps.name("ev")?.as_str().to_lowercase();
-        let ts = caps.name("ts")?.as_str().parse::<i64>().ok()?;
-        let rest = caps.name("rest").map(|m| m.as_str().to_string());
-
-        // normalize event type
-        let event_type = match ev.as_str() {
-            "sessionstart" | "session_start" | "session-start" => GameEventType::SessionStart,
-            "sessionend" | "session_end" | "session-end" => GameEventType::SessionEnd,
-            "kill" => GameEventType::Kill,
-            "death" => GameEventType::Death,
-            _ => return None,
-        };
-
-        // parse rest into key=value tokens when present
-        let details = rest
-            .and_then(|r| {
-                // split by whitespace, keep quoted values together
-                let token_re =
-                    regex::Regex::new(r#"(?P<k>[^\s=]+)=(?P<v>"[^"]+"|[^\s]+)|(?P<raw>[^\s]+)"#)
-                        .expect("valid token regex");
-                let mut pairs = vec![];
-                for cap in token_re.captures_iter(&r) {
-                    if let Some(k) = cap.name("k") {
-                        let v = cap
-                            .name("v")
-                            .map(|m| m.as_str().trim_matches('"').to_string())
-                            .unwrap_or_default();
-                        pairs.push(format!("{}={}", k.as_str(), v));
-                    } else if let Some(raw) = cap.name("raw") {
-                        pairs.push(raw.as_str().to_string());
-                    }
-                }
-                if pairs.is_empty() {
-                    None
-                } else {
-                    Some(pairs.join(" "))
-                }
-            });
-
-        Some((event_type, ts, details))
-    }
-}
-
-impl GameLogParser for SimpleGameLogParser {
-    fn parse_line(&self, line: &str) -> Option<EventEnvelope> {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            return None;
-        }
-        if let Some((event_type, ts, details)) = Self::parse_tokens(trimmed) {
-            // id can include event and timestamp
-            let id = format!("evt-{}-{}", event_type as i32, ts);
-            return Some(EventEnvelope::GameEvent {
-                id,
-                event_type,
-                timestamp: ts,
-                details,
-            });
-        }
-        None
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use sc_manager_core::domain::game_event::GameEventType;
-
-    #[test]
-    fn parse_session_start() {
-        let p = SimpleGameLogParser;
-        let line = "SessionStart 1610000000";
-        let out = p.parse_line(line).expect("should parse");
-        match out {
-            EventEnvelope::GameEvent {
-                event_type,
-                timestamp,
-                ..
-            } => {
-                assert_eq!(event_type, GameEventType::SessionStart);
-                assert_eq!(timestamp, 1610000000);
-            }
-            _ => unreachable!("unexpected envelope type"),
-        }
-    }
-
-    #[test]
-    fn parse_kill_with_details() {
-        let p = SimpleGameLogParser;
-        let line = "Kill 1610000100 killer=alice victim=bob";
-        let out = p.parse_line(line).expect("should parse kill");
-        match out {
-            EventEnvelope::GameEvent {
-                event_type,
-                timestamp,
-                ..
-            } => {
-                assert_eq!(event_type, GameEventType::Kill);
-                assert_eq!(timestamp, 1610000100);
-            }
-            _ => unreachable!("unexpected envelope type"),
-        }
-    }
-}
+//! Game.log parser adapter (inbound)
+
+use sc_manager_core::domain::game_event::GameEventType;
+use sc_manager_core::events::EventEnvelope;
+use sha2::{Digest, Sha256};
+
+pub trait GameLogParser {
+    fn parse_line(&self, line: &str) -> Option<EventEnvelope>;
+
+    /// Parses a line read from `source` (e.g. a log file path or client id); the source
+    /// is part of the event id so identical lines from different clients stay distinct.
+    fn parse_line_from(&self, _source: &str, line: &str) -> Option<EventEnvelope> {
+        self.parse_line(line)
+    }
+}
+
+/// Content-addressed event id: hash of the source and the normalized line.
+/// Re-reading the same line from the same source yields the same id.
+pub fn event_id(source: &str, normalized_line: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(source.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(normalized_line.as_bytes());
+    let digest = hasher.finalize();
+    let hex: String = digest.iter().take(12).map(|b| format!("{:02x}", b)).collect();
+    format!("evt-{}", hex)
+}
+
+pub struct SimpleGameLogParser;
+
+impl SimpleGameLogParser {
+    fn parse_tokens(line: &str) -> Option<(GameEventType, i64, Option<String>)> {
+        // Robust regex-based parser
+        // Expected general format: "EVENT 1610000000 [key=value ...] [free text]"
+        let re =
+            regex::Regex::new(r"^(?P<ev>[A-Za-z_-]+)\s+(?P<ts>\d+)(?:\s+(?P<rest>.*))?$").expect("valid line regex");
+        let caps = re.captures(line.trim())?;
+        let ev = caps.name("ev")?.as_str().to_lowercase();
+        let ts = caps.name("ts")?.as_str().parse::<i64>().ok()?;
+        let rest = caps.name("rest").map(|m| m.as_str().to_string());
+
+        // normalize event type
+        let event_type = match ev.as_str() {
+            "sessionstart" | "session_start" | "session-start" => GameEventType::SessionStart,
+            "sessionend" | "session_end" | "session-end" => GameEventType::SessionEnd,
+            "kill" => GameEventType::Kill,
+            "death" => GameEventType::Death,
+            _ => return None,
+        };
+
+        // parse rest into key=value tokens when present
+        let details = rest
+            .and_then(|r| {
+                // split by whitespace, keep quoted values together
+                let token_re =
+                    regex::Regex::new(r#"(?P<k>[^\s=]+)=(?P<v>"[^"]+"|[^\s]+)|(?P<raw>[^\s]+)"#)
+                        .expect("valid token regex");
+                let mut pairs = vec![];
+                for cap in token_re.captures_iter(&r) {
+                    if let Some(k) = cap.name("k") {
+                        let v = cap
+                            .name("v")
+                            .map(|m| m.as_str().trim_matches('"').to_string())
+                            .unwrap_or_default();
+                        pairs.push(format!("{}={}", k.as_str(), v));
+                    } else if let Some(raw) = cap.name("raw") {
+                        pairs.push(raw.as_str().to_string());
+                    }
+                }
+                if pairs.is_empty() {
+                    None
+                } else {
+                    Some(pairs.join(" "))
+                }
+            });
+
+        Some((event_type, ts, details))
+    }
+}
+
+impl GameLogParser for SimpleGameLogParser {
+    fn parse_line(&self, line: &str) -> Option<EventEnvelope> {
+        self.parse_line_from("", line)
+    }
+
+    fn parse_line_from(&self, source: &str, line: &str) -> Option<EventEnvelope> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+        if let Some((event_type, ts, details)) = Self::parse_tokens(trimmed) {
+            // normalized form ignores spacing, quoting and event name spelling
+            let normalized = format!("{:?} {} {}", event_type, ts, details.as_deref().unwrap_or_default());
+            let id = event_id(source, &normalized);
+            return Some(EventEnvelope::GameEvent {
+                id,
+                event_type,
+                timestamp: ts,
+                details,
+            });
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sc_manager_core::domain::game_event::GameEventType;
+
+    #[test]
+    fn parse_session_start() {
+        let p = SimpleGameLogParser;
+        let line = "SessionStart 1610000000";
+        let out = p.parse_line(line).expect("should parse");
+        match out {
+            EventEnvelope::GameEvent {
+                event_type,
+                timestamp,
+                ..
+            } => {
+                assert_eq!(event_type, GameEventType::SessionStart);
+                assert_eq!(timestamp, 1610000000);
+            }
+            _ => unreachable!("unexpected envelope type"),
+        }
+    }
+
+    #[test]
+    fn ids_are_content_addressed() {
+        let p = SimpleGameLogParser;
+        let id = |source: &str, line: &str| match p.parse_line_from(source, line) {
+            Some(EventEnvelope::GameEvent { id, .. }) => id,
+            _ => unreachable!("unexpected envelope type"),
+        };
+        let a = id("client-1", "Kill 1610000100 killer=alice victim=bob");
+        assert_eq!(a, id("client-1", "kill   1610000100 killer=\"alice\" victim=bob "));
+        assert_ne!(a, id("client-1", "Kill 1610000100 killer=alice victim=carol"));
+        assert_ne!(a, id("client-2", "Kill 1610000100 killer=alice victim=bob"));
+    }
+
+    #[test]
+    fn parse_kill_with_details() {
+        let p = SimpleGameLogParser;
+        let line = "Kill 1610000100 killer=alice victim=bob";
+        let out = p.parse_line(line).expect("should parse kill");
+        match out {
+            EventEnvelope::GameEvent {
+                event_type,
+                timestamp,
+                ..
+            } => {
+                assert_eq!(event_type, GameEventType::Kill);
+                assert_eq!(timestamp, 1610000100);
+            }
+            _ => unreachable!("unexpected envelope type"),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Per-adapter fetch history used by `AdapterRegistry::get_health`.
+//!
+//! The registry records the outcome and latency of every scheduled or on-demand fetch. Uptime and average
+//! latency are computed over a rolling window, requests per hour over the last hour, and `HealthStatus` is
+//! derived from `HealthThresholds`.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::adapter_api::{AdapterHealth, HealthStatus, Result};
+
+#[derive(Debug, Clone)]
+pub struct HealthThresholds {
+    /// Window for uptime and average latency
+    pub window: Duration,
+    /// Uptime (percent) below which an adapter is Degraded
+    pub degraded_below: f32,
+    /// Uptime (percent) below which an adapter is Down
+    pub down_below: f32,
+    /// Consecutive failed fetches after which an adapter is Down regardless of uptime
+    pub down_after_failures: u32,
+    /// Average latency above which an otherwise healthy adapter is Degraded
+    pub slow_latency: Duration,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(24 * 60 * 60),
+            degraded_below: 95.0,
+            down_below: 50.0,
+            down_after_failures: 3,
+            slow_latency: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Outcome {
+    at: DateTime<Utc>,
+    ok: bool,
+    latency: Duration,
+}
+
+#[derive(Debug, Default)]
+struct AdapterStats {
+    outcomes: VecDeque<Outcome>,
+    consecutive_failures: u32,
+    last_success: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+}
+
+#[derive(Default)]
+pub struct HealthMonitor {
+    thresholds: HealthThresholds,
+    stats: Mutex<HashMap<String, AdapterStats>>,
+}
+
+impl HealthMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_thresholds(thresholds: HealthThresholds) -> Self {
+        Self { thresholds, stats: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn thresholds(&self) -> &HealthThresholds {
+        &self.thresholds
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, AdapterStats>> {
+        match self.stats.lock() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    /// Start tracking `name`; adapters without recorded fetches have no monitor health.
+    pub fn monitor(&self, name: String) {
+        self.lock().entry(name).or_default();
+    }
+
+    pub async fn start(&self) -> Result<()> {
+        tracing::info!("Health monitor tracking {} adapters", self.lock().len());
+        Ok(())
+    }
+
+    pub fn record_success(&self, name: &str, latency: Duration) {
+        self.record(name, latency, None);
+    }
+
+    pub fn record_failure(&self, name: &str, latency: Duration, error: &str) {
+        self.record(name, latency, Some(error));
+    }
+
+    fn record(&self, name: &str, latency: Duration, error: Option<&str>) {
+        let now = Utc::now();
+        let window = chrono::Duration::from_std(self.thresholds.window.max(Duration::from_secs(3600)))
+            .unwrap_or_else(|_| chrono::Duration::days(1));
+        let mut stats = self.lock();
+        let s = stats.entry(name.to_string()).or_default();
+        s.outcomes.push_back(Outcome { at: now, ok: error.is_none(), latency });
+        while s.outcomes.front().is_some_and(|o| now - o.at > window) {
+            s.outcomes.pop_front();
+        }
+        match error {
+            None => {
+                s.consecutive_failures = 0;
+                s.last_success = Some(now);
+            }
+            Some(e) => {
+                s.consecutive_failures += 1;
+                s.last_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Health computed from recorded fetches, or `None` before the first fetch of `name`.
+    pub fn health(&self, name: &str) -> Option<AdapterHealth> {
+        let now = Utc::now();
+        let window = chrono::Duration::from_std(self.thresholds.window).unwrap_or_else(|_| chrono::Duration::days(1));
+        let stats = self.lock();
+        let s = stats.get(name)?;
+        let recent: Vec<&Outcome> = s.outcomes.iter().filter(|o| now - o.at <= window).collect();
+        if recent.is_empty() && s.last_success.is_none() && s.last_error.is_none() {
+            return None;
+        }
+
+        let uptime = if recent.is_empty() {
+            100.0
+        } else {
+            recent.iter().filter(|o| o.ok).count() as f32 * 100.0 / recent.len() as f32
+        };
+        let avg_latency = (!recent.is_empty())
+            .then(|| recent.iter().map(|o| o.latency).sum::<Duration>() / recent.len() as u32);
+        let requests_per_hour = s.outcomes.iter().filter(|o| now - o.at <= chrono::Duration::hours(1)).count() as u32;
+
+        let t = &self.thresholds;
+        let status = if s.consecutive_failures >= t.down_after_failures || uptime < t.down_below {
+            HealthStatus::Down
+        } else if s.consecutive_failures > 0 || uptime < t.degraded_below || avg_latency.is_some_and(|l| l > t.slow_latency) {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+
+        Some(AdapterHealth {
+            status,
+            last_success: s.last_success,
+            last_error: s.last_error.clone(),
+            uptime_percentage: Some(uptime),
+            requests_per_hour,
+            cache_age_secs: None,
+            avg_latency_ms: avg_latency.map(|l| l.as_millis() as u64),
+            circuit: None,
+        })
+    }
+}
+
+/// Unmonitored health from a reachability probe of an HTTP source: Healthy on a 2xx response, Degraded on any
+/// other status and Down when the request fails.
+pub async fn probe(source: &str, request: reqwest::RequestBuilder) -> AdapterHealth {
+    match request.send().await {
+        Ok(resp) if resp.status().is_success() => AdapterHealth::unmonitored(HealthStatus::Healthy, None),
+        Ok(resp) => AdapterHealth::unmonitored(HealthStatus::Degraded, Some(format!("{} returned {}", source, resp.status()))),
+        Err(e) => AdapterHealth::unmonitored(HealthStatus::Down, Some(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unmonitored_or_idle_adapter_has_no_health() {
+        let m = HealthMonitor::new();
+        m.monitor("uex".into());
+        assert!(m.health("uex").is_none());
+        assert!(m.health("fleetyards").is_none());
+    }
+
+    #[test]
+    fn status_follows_thresholds() {
+        let m = HealthMonitor::new();
+        for _ in 0..19 {
+            m.record_success("uex", Duration::from_millis(200));
+        }
+        let h = m.health("uex").expect("health");
+        assert!(matches!(h.status, HealthStatus::Healthy));
+        assert_eq!(h.requests_per_hour, 19);
+        assert_eq!(h.avg_latency_ms, Some(200));
+        assert!(h.last_success.is_some() && h.last_error.is_none());
+
+        // one failure: 95% uptime but the latest fetch failed
+        m.record_failure("uex", Duration::from_millis(200), "HTTP 503");
+        let h = m.health("uex").expect("health");
+        assert!(matches!(h.status, HealthStatus::Degraded));
+        assert_eq!(h.uptime_percentage, Some(95.0));
+        assert_eq!(h.last_error.as_deref(), Some("HTTP 503"));
+
+        m.record_failure("uex", Duration::from_millis(200), "HTTP 503");
+        m.record_failure("uex", Duration::from_millis(200), "HTTP 503");
+        assert!(matches!(m.health("uex").expect("health").status, HealthStatus::Down));
+
+        m.record_success("uex", Duration::from_millis(200));
+        // recovered, but uptime 87% stays below the degraded threshold
+        assert!(matches!(m.health("uex").expect("health").status, HealthStatus::Degraded));
+    }
+
+    #[test]
+    fn slow_adapter_is_degraded() {
+        let m = HealthMonitor::with_thresholds(HealthThresholds { slow_latency: Duration::from_millis(100), ..Default::default() });
+        m.record_success("erkul", Duration::from_millis(500));
+        assert!(matches!(m.health("erkul").expect("health").status, HealthStatus::Degraded));
+    }
+}
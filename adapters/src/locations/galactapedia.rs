@@ -0,0 +1,152 @@
+//! Galactapedia location articles.
+//!
+//! Reads `GET /galactapedia/articles?type=location&page=N`; each article carries a `template` (StarSystem,
+//! Planet, Moon, SpaceStation, Outpost/LandingZone) and `properties.system`/`properties.parent` names.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{canonical_id, Location, LocationKind, LocationSnapshot};
+use crate::adapter_api::{AdapterData, AdapterHealth, DataAdapter, RateLimit};
+use crate::health;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.star-citizen.wiki/api/v2";
+
+const MAX_PAGES: usize = 50;
+
+#[derive(Deserialize)]
+struct ArticlePage {
+    #[serde(default)]
+    data: Vec<Article>,
+    #[serde(default)]
+    meta: Option<PageMeta>,
+}
+
+#[derive(Deserialize)]
+struct PageMeta {
+    last_page: usize,
+}
+
+#[derive(Deserialize)]
+struct Article {
+    title: String,
+    template: String,
+    #[serde(default)]
+    excerpt: Option<String>,
+    #[serde(default)]
+    properties: ArticleProperties,
+}
+
+#[derive(Default, Deserialize)]
+struct ArticleProperties {
+    system: Option<String>,
+    parent: Option<String>,
+    designation: Option<String>,
+}
+
+fn kind(template: &str) -> Option<LocationKind> {
+    match template {
+        "StarSystem" => Some(LocationKind::System),
+        "Planet" => Some(LocationKind::Planet),
+        "Moon" => Some(LocationKind::Moon),
+        "SpaceStation" => Some(LocationKind::Station),
+        "Outpost" | "LandingZone" => Some(LocationKind::Outpost),
+        _ => None,
+    }
+}
+
+impl Article {
+    fn into_location(self) -> Option<Location> {
+        let kind = kind(&self.template)?;
+        let system = match kind {
+            LocationKind::System => self.title.clone(),
+            _ => self.properties.system?,
+        };
+        let parent_id = match kind {
+            LocationKind::System => None,
+            _ => Some(canonical_id(&system, self.properties.parent.as_deref())),
+        };
+        Some(Location {
+            id: canonical_id(&system, (kind != LocationKind::System).then_some(self.title.as_str())),
+            system_id: canonical_id(&system, None),
+            name: self.title,
+            kind,
+            parent_id,
+            aliases: self.properties.designation.into_iter().collect(),
+            summary: self.excerpt.map(|s| s.trim().to_string()).filter(|s| !s.is_empty()),
+        })
+    }
+}
+
+/// Galactapedia adapter (hub id `galactapedia`).
+pub struct GalactapediaAdapter {
+    base_url: String,
+    timeout: Duration,
+}
+
+impl GalactapediaAdapter {
+    pub fn new() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(15),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// All location articles, normalized.
+    pub async fn fetch_locations(&self) -> crate::Result<Vec<Location>> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        let mut out = vec![];
+        for page in 1..=MAX_PAGES {
+            let resp = client
+                .get(format!("{}/galactapedia/articles?type=location&page={}", self.base_url, page))
+                .send()
+                .await
+                .map_err(|e| format!("galactapedia request failed: {}", e))?;
+            if !resp.status().is_success() {
+                return Err(format!("galactapedia returned {}", resp.status()));
+            }
+            let body: ArticlePage = resp.json().await.map_err(|e| format!("galactapedia: invalid json: {}", e))?;
+            let last = body.meta.map(|m| m.last_page).unwrap_or(page);
+            out.extend(body.data.into_iter().filter_map(Article::into_location));
+            if page >= last {
+                break;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Default for GalactapediaAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataAdapter for GalactapediaAdapter {
+    fn name(&self) -> &str { "galactapedia" }
+    fn version(&self) -> &str { "0.1.0" }
+    fn source_url(&self) -> &str { &self.base_url }
+    fn attribution(&self) -> &str { "Galactapedia © Cloud Imperium Games, via the Star Citizen Wiki API" }
+    fn license(&self) -> &str { "CC BY-SA 4.0 (Star Citizen Wiki)" }
+    fn hub_id(&self) -> Option<&str> { Some("galactapedia") }
+
+    async fn health_check(&self) -> crate::Result<AdapterHealth> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        Ok(health::probe("galactapedia", client.get(format!("{}/galactapedia/articles?type=location&page=1", self.base_url))).await)
+    }
+
+    async fn fetch(&self) -> crate::Result<AdapterData> {
+        LocationSnapshot::new("galactapedia", self.fetch_locations().await?).into_adapter_data()
+    }
+
+    fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 60, burst: 10 } }
+    fn cache_ttl(&self) -> Duration { Duration::from_secs(24 * 60 * 60) }
+}
@@ -0,0 +1,211 @@
+//! Canonical location reference data (outbound)
+//!
+//! Galactapedia (`galactapedia`) and the RSI VerseGuide starmap (`verseguide`) are normalized into `Location`s
+//! and published as `AdapterData::Systems` carrying a `LocationSnapshot`. Ids are derived from system and name
+//! (`stanton/hurston`), so both sources agree and consumers can reference locations instead of free text.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::adapter_api::AdapterData;
+
+pub mod galactapedia;
+pub mod verseguide;
+pub use galactapedia::GalactapediaAdapter;
+pub use verseguide::VerseGuideAdapter;
+
+/// Schema version of the `LocationSnapshot` payload; bump on breaking changes.
+pub const LOCATION_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocationKind {
+    System,
+    Planet,
+    Moon,
+    Station,
+    Outpost,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    /// Canonical id, see `canonical_id`
+    pub id: String,
+    pub name: String,
+    pub kind: LocationKind,
+    /// Canonical id of the containing system
+    pub system_id: String,
+    /// Canonical id of the body this orbits/sits on, if known
+    pub parent_id: Option<String>,
+    /// Designations and alternative names (e.g. "Stanton III")
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub summary: Option<String>,
+}
+
+fn slug(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.ends_with('-') && !out.is_empty() {
+            out.push('-');
+        }
+    }
+    out.trim_end_matches('-').to_string()
+}
+
+/// `stanton` for systems, `stanton/hurston` for everything inside a system.
+pub fn canonical_id(system: &str, name: Option<&str>) -> String {
+    match name {
+        Some(n) if slug(n) != slug(system) => format!("{}/{}", slug(system), slug(n)),
+        _ => slug(system),
+    }
+}
+
+/// `id`, or if it is already in `taken` the id qualified by its parent (`stanton/crusader/crusader`), then numbered
+/// (`stanton/crusader/crusader-2`). The returned id is added to `taken`.
+pub fn unique_id(id: String, parent_id: Option<&str>, taken: &mut HashSet<String>) -> String {
+    let mut candidate = id.clone();
+    if taken.contains(&candidate) {
+        if let Some(parent) = parent_id {
+            let leaf = id.rsplit('/').next().unwrap_or(&id);
+            candidate = format!("{}/{}", parent, leaf);
+        }
+        let base = candidate.clone();
+        let mut n = 2;
+        while taken.contains(&candidate) {
+            candidate = format!("{}-{}", base, n);
+            n += 1;
+        }
+    }
+    taken.insert(candidate.clone());
+    candidate
+}
+
+/// Payload of `AdapterData::Systems`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocationSnapshot {
+    pub schema_version: u32,
+    pub source: String,
+    pub fetched_at: DateTime<Utc>,
+    pub locations: Vec<Location>,
+}
+
+impl LocationSnapshot {
+    pub fn new(source: impl Into<String>, locations: Vec<Location>) -> Self {
+        Self { schema_version: LOCATION_SCHEMA_VERSION, source: source.into(), fetched_at: Utc::now(), locations }
+    }
+
+    pub fn into_adapter_data(self) -> crate::Result<AdapterData> {
+        serde_json::to_value(self).map(AdapterData::Systems).map_err(|e| e.to_string())
+    }
+
+    /// Decode a snapshot from `AdapterData::Systems`; other variants and unknown schema versions are rejected.
+    pub fn from_adapter_data(data: &AdapterData) -> crate::Result<Self> {
+        let AdapterData::Systems(v) = data else {
+            return Err("expected AdapterData::Systems".to_string());
+        };
+        let snap: LocationSnapshot = serde_json::from_value(v.clone()).map_err(|e| e.to_string())?;
+        if snap.schema_version != LOCATION_SCHEMA_VERSION {
+            return Err(format!("unsupported location schema version {}", snap.schema_version));
+        }
+        Ok(snap)
+    }
+}
+
+/// Lookup of canonical locations by id, name or alias (case-insensitive).
+#[derive(Debug, Clone, Default)]
+pub struct LocationIndex {
+    by_id: HashMap<String, Location>,
+    by_name: HashMap<String, Vec<String>>,
+}
+
+impl LocationIndex {
+    /// Index the given snapshots; later snapshots fill in fields missing from earlier ones.
+    pub fn from_snapshots<'a>(snapshots: impl IntoIterator<Item = &'a LocationSnapshot>) -> Self {
+        let mut idx = Self::default();
+        for snap in snapshots {
+            for loc in &snap.locations {
+                idx.insert(loc.clone());
+            }
+        }
+        idx
+    }
+
+    fn insert(&mut self, loc: Location) {
+        let names: Vec<String> = std::iter::once(loc.name.clone()).chain(loc.aliases.iter().cloned()).collect();
+        match self.by_id.get_mut(&loc.id) {
+            Some(existing) => {
+                existing.parent_id = existing.parent_id.take().or(loc.parent_id);
+                existing.summary = existing.summary.take().or(loc.summary);
+                for a in loc.aliases {
+                    if !existing.aliases.contains(&a) {
+                        existing.aliases.push(a);
+                    }
+                }
+            }
+            None => {
+                self.by_id.insert(loc.id.clone(), loc.clone());
+            }
+        }
+        for n in names {
+            let ids = self.by_name.entry(n.to_lowercase()).or_default();
+            if !ids.contains(&loc.id) {
+                ids.push(loc.id.clone());
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Location> {
+        self.by_id.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+
+    /// Resolve free text (id, name or alias) to a location; ambiguous names resolve to None.
+    pub fn resolve(&self, text: &str) -> Option<&Location> {
+        if let Some(l) = self.by_id.get(&text.to_lowercase()) {
+            return Some(l);
+        }
+        match self.by_name.get(&text.trim().to_lowercase())?.as_slice() {
+            [id] => self.by_id.get(id),
+            _ => None,
+        }
+    }
+
+    pub fn children<'a>(&'a self, parent_id: &'a str) -> impl Iterator<Item = &'a Location> + 'a {
+        self.by_id.values().filter(move |l| l.parent_id.as_deref() == Some(parent_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_ids_are_slugs() {
+        assert_eq!(canonical_id("Stanton", None), "stanton");
+        assert_eq!(canonical_id("Stanton", Some("Stanton")), "stanton");
+        assert_eq!(canonical_id("Stanton", Some("Port Olisar")), "stanton/port-olisar");
+        assert_eq!(canonical_id("Stanton", Some("ARC-L1 Wide Forest Station")), "stanton/arc-l1-wide-forest-station");
+    }
+
+    #[test]
+    fn colliding_ids_fall_back_to_the_parent() {
+        let mut taken = HashSet::new();
+        assert_eq!(unique_id("stanton".into(), None, &mut taken), "stanton");
+        assert_eq!(unique_id("stanton/crusader".into(), Some("stanton"), &mut taken), "stanton/crusader");
+        assert_eq!(unique_id("stanton/crusader".into(), Some("stanton/crusader"), &mut taken), "stanton/crusader/crusader");
+        assert_eq!(unique_id("stanton/crusader".into(), Some("stanton/crusader"), &mut taken), "stanton/crusader/crusader-2");
+        assert_eq!(unique_id("stanton/crusader".into(), None, &mut taken), "stanton/crusader-2");
+    }
+}
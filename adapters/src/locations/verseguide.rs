@@ -0,0 +1,233 @@
+//! RSI VerseGuide (starmap) locations.
+//!
+//! `POST /api/starmap/bootup` lists systems; `POST /api/starmap/star-systems/{code}` lists a system's celestial
+//! objects with `type` (PLANET, SATELLITE, MANMADE, LZ, POI, ...) and `parent_id`.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use super::{canonical_id, unique_id, Location, LocationKind, LocationSnapshot};
+use crate::adapter_api::{AdapterData, AdapterHealth, DataAdapter, RateLimit};
+use crate::health;
+
+pub const DEFAULT_BASE_URL: &str = "https://robertsspaceindustries.com";
+/// RSI starmap request budget, shared by a fetch's bootup and per-system requests.
+pub const REQUESTS_PER_HOUR: u32 = 30;
+
+#[derive(Deserialize)]
+struct Envelope<T> {
+    success: u8,
+    #[serde(default)]
+    msg: Option<String>,
+    data: Option<T>,
+}
+
+#[derive(Deserialize)]
+struct Bootup {
+    systems: ResultSet<StarSystem>,
+}
+
+#[derive(Deserialize)]
+struct ResultSet<T> {
+    resultset: Vec<T>,
+}
+
+#[derive(Deserialize)]
+struct StarSystem {
+    code: String,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    celestial_objects: Vec<CelestialObject>,
+}
+
+#[derive(Deserialize)]
+struct CelestialObject {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    designation: Option<String>,
+    #[serde(default)]
+    parent_id: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+fn kind(t: &str) -> Option<LocationKind> {
+    match t {
+        "PLANET" => Some(LocationKind::Planet),
+        "SATELLITE" => Some(LocationKind::Moon),
+        "MANMADE" => Some(LocationKind::Station),
+        "LZ" | "POI" => Some(LocationKind::Outpost),
+        _ => None,
+    }
+}
+
+fn non_empty(s: Option<String>) -> Option<String> {
+    s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Canonical id of object `id`, resolving its parent first so colliding names can be qualified by the parent's id.
+fn resolve_id(
+    id: &str,
+    system: &str,
+    system_id: &str,
+    objects: &HashMap<&str, (String, Option<&str>)>,
+    ids: &mut HashMap<String, String>,
+    taken: &mut HashSet<String>,
+    visiting: &mut HashSet<String>,
+) -> Option<String> {
+    if let Some(resolved) = ids.get(id) {
+        return Some(resolved.clone());
+    }
+    let (name, parent) = objects.get(id)?;
+    visiting.insert(id.to_string());
+    let parent_id = parent
+        .filter(|p| !visiting.contains(*p))
+        .and_then(|p| resolve_id(p, system, system_id, objects, ids, taken, visiting))
+        .unwrap_or_else(|| system_id.to_string());
+    let resolved = unique_id(canonical_id(system, Some(name)), Some(&parent_id), taken);
+    ids.insert(id.to_string(), resolved.clone());
+    Some(resolved)
+}
+
+/// Normalize one system and its celestial objects. Objects whose parent is skipped (e.g. a star) hang off the system.
+/// Ids are unique within `taken`: an object named like its system or a sibling is qualified by its parent's id.
+fn normalize(system: StarSystem, taken: &mut HashSet<String>) -> Vec<Location> {
+    let system_id = unique_id(canonical_id(&system.name, None), None, taken);
+    let mut out = vec![Location {
+        id: system_id.clone(),
+        name: system.name.clone(),
+        kind: LocationKind::System,
+        system_id: system_id.clone(),
+        parent_id: None,
+        aliases: vec![system.code.clone()],
+        summary: non_empty(system.description),
+    }];
+
+    let objects: HashMap<&str, (String, Option<&str>)> = system
+        .celestial_objects
+        .iter()
+        .filter(|o| kind(&o.kind).is_some())
+        .filter_map(|o| {
+            let name = non_empty(o.name.clone()).or_else(|| non_empty(o.designation.clone()))?;
+            Some((o.id.as_str(), (name, o.parent_id.as_deref())))
+        })
+        .collect();
+    let mut ids = HashMap::new();
+    for o in &system.celestial_objects {
+        resolve_id(&o.id, &system.name, &system_id, &objects, &mut ids, taken, &mut HashSet::new());
+    }
+
+    for o in system.celestial_objects {
+        let (Some(k), Some(id)) = (kind(&o.kind), ids.get(&o.id)) else { continue };
+        let name = non_empty(o.name).or_else(|| o.designation.clone()).unwrap_or_default();
+        out.push(Location {
+            id: id.clone(),
+            kind: k,
+            system_id: system_id.clone(),
+            parent_id: Some(o.parent_id.and_then(|p| ids.get(&p).cloned()).unwrap_or_else(|| system_id.clone())),
+            aliases: non_empty(o.designation).filter(|d| *d != name).into_iter().collect(),
+            summary: non_empty(o.description),
+            name,
+        });
+    }
+    out
+}
+
+/// VerseGuide adapter (hub id `rsi_verseguide`).
+pub struct VerseGuideAdapter {
+    base_url: String,
+    timeout: Duration,
+    request_interval: Duration,
+}
+
+impl VerseGuideAdapter {
+    pub fn new() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(15),
+            request_interval: Duration::from_secs(3600 / REQUESTS_PER_HOUR as u64),
+        }
+    }
+
+    /// Pause between the per-system requests of one fetch (default: spread over `REQUESTS_PER_HOUR`).
+    pub fn with_request_interval(mut self, interval: Duration) -> Self {
+        self.request_interval = interval;
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn post<T: DeserializeOwned>(&self, client: &reqwest::Client, path: &str) -> crate::Result<T> {
+        let resp = client
+            .post(format!("{}{}", self.base_url, path))
+            .send()
+            .await
+            .map_err(|e| format!("verseguide request failed: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("verseguide {} returned {}", path, resp.status()));
+        }
+        let body: Envelope<T> = resp.json().await.map_err(|e| format!("verseguide {}: invalid json: {}", path, e))?;
+        match (body.success, body.data) {
+            (1, Some(data)) => Ok(data),
+            _ => Err(format!("verseguide {} error: {}", path, body.msg.unwrap_or_default())),
+        }
+    }
+
+    /// All systems with their planets, moons, stations and outposts. The bootup request is the fetch's rate-limit
+    /// token; each per-system request waits `request_interval` so a crawl stays within `REQUESTS_PER_HOUR`.
+    pub async fn fetch_locations(&self) -> crate::Result<Vec<Location>> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        let bootup: Bootup = self.post(&client, "/api/starmap/bootup").await?;
+        let mut out = vec![];
+        let mut taken = HashSet::new();
+        for s in bootup.systems.resultset {
+            tokio::time::sleep(self.request_interval).await;
+            let detail: ResultSet<StarSystem> = self.post(&client, &format!("/api/starmap/star-systems/{}", s.code)).await?;
+            for system in detail.resultset {
+                out.extend(normalize(system, &mut taken));
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Default for VerseGuideAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataAdapter for VerseGuideAdapter {
+    fn name(&self) -> &str { "rsi_verseguide" }
+    fn version(&self) -> &str { "0.1.0" }
+    fn source_url(&self) -> &str { &self.base_url }
+    fn attribution(&self) -> &str { "Starmap data © Cloud Imperium Games / Roberts Space Industries" }
+    fn license(&self) -> &str { "RSI Terms of Service" }
+    fn hub_id(&self) -> Option<&str> { Some("rsi_verseguide") }
+
+    async fn health_check(&self) -> crate::Result<AdapterHealth> {
+        let client = reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        Ok(health::probe("verseguide", client.get(&self.base_url)).await)
+    }
+
+    async fn fetch(&self) -> crate::Result<AdapterData> {
+        LocationSnapshot::new("rsi_verseguide", self.fetch_locations().await?).into_adapter_data()
+    }
+
+    fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: REQUESTS_PER_HOUR, burst: 10 } }
+    fn cache_ttl(&self) -> Duration { Duration::from_secs(24 * 60 * 60) }
+}
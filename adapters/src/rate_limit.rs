@@ -0,0 +1,163 @@
+//! Token buckets used by `AdapterRegistry` to enforce `DataAdapter::rate_limit`.
+//!
+//! Every registered adapter gets one bucket holding up to `burst` tokens and refilling at
+//! `requests_per_hour / 3600` tokens per second. Scheduled and on-demand fetches draw from the same
+//! bucket, so manual refreshes cannot push an adapter past its declared limit. A `requests_per_hour`
+//! of zero means the adapter declares no limit.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::adapter_api::{RateLimit, Result, Schedule};
+
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<BucketState>,
+}
+
+impl TokenBucket {
+    /// A full bucket for `limit`.
+    pub fn new(limit: &RateLimit) -> Self {
+        let capacity = f64::from(limit.burst.max(1));
+        Self {
+            capacity,
+            refill_per_sec: f64::from(limit.requests_per_hour) / 3600.0,
+            state: Mutex::new(BucketState { tokens: capacity, refilled_at: Instant::now() }),
+        }
+    }
+
+    fn unlimited(&self) -> bool {
+        self.refill_per_sec == 0.0
+    }
+
+    fn refill(&self, state: &mut BucketState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        state.refilled_at = now;
+    }
+
+    /// Take one token; `false` means the call must be throttled.
+    pub fn try_acquire(&self) -> bool {
+        if self.unlimited() {
+            return true;
+        }
+        let mut state = match self.state.lock() {
+            Ok(s) => s,
+            Err(e) => e.into_inner(),
+        };
+        self.refill(&mut state);
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whole tokens currently available.
+    pub fn available(&self) -> u32 {
+        if self.unlimited() {
+            return u32::MAX;
+        }
+        let mut state = match self.state.lock() {
+            Ok(s) => s,
+            Err(e) => e.into_inner(),
+        };
+        self.refill(&mut state);
+        state.tokens.floor() as u32
+    }
+}
+
+/// Reject schedules that would fetch more often than `limit` allows in steady state.
+///
+/// Ticks that fall within `cache_ttl` of the previous fetch are served from the registry cache and do not
+/// count. Cron schedules are checked by counting their fetches over the next hour.
+pub fn validate_schedule(limit: &RateLimit, schedule: &Schedule, cache_ttl: Duration) -> Result<()> {
+    match schedule {
+        Schedule::Fixed(interval) if interval.is_zero() => Err("fixed schedule interval must be non-zero".into()),
+        _ if limit.requests_per_hour == 0 => Ok(()),
+        Schedule::Fixed(interval) => {
+            let per_hour = 3600.0 / (*interval).max(cache_ttl).as_secs_f64();
+            if per_hour > f64::from(limit.requests_per_hour) {
+                return Err(format!(
+                    "schedule every {:?} fetches {:.0} times per hour, above the limit of {}",
+                    interval, per_hour, limit.requests_per_hour
+                ));
+            }
+            Ok(())
+        }
+        Schedule::Cron(expr) => {
+            let parsed: cron::Schedule = std::str::FromStr::from_str(expr)
+                .map_err(|e| format!("invalid cron expression {}: {}", expr, e))?;
+            let now = chrono::Utc::now();
+            let horizon = now + chrono::Duration::hours(1);
+            let ttl = chrono::Duration::from_std(cache_ttl).unwrap_or_else(|_| chrono::Duration::zero());
+            let mut last: Option<chrono::DateTime<chrono::Utc>> = None;
+            let mut fetches = 0u32;
+            for at in parsed.after(&now).take_while(|at| *at <= horizon) {
+                if last.is_some_and(|l| at - l < ttl) {
+                    continue;
+                }
+                last = Some(at);
+                fetches += 1;
+                if fetches > limit.requests_per_hour {
+                    return Err(format!(
+                        "cron schedule {} fetches more than {} times per hour",
+                        expr, limit.requests_per_hour
+                    ));
+                }
+            }
+            Ok(())
+        }
+        Schedule::OnDemand => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(requests_per_hour: u32, burst: u32) -> RateLimit {
+        RateLimit { requests_per_hour, burst }
+    }
+
+    #[test]
+    fn bucket_allows_burst_then_throttles() {
+        let bucket = TokenBucket::new(&limit(10, 3));
+        assert_eq!(bucket.available(), 3);
+        assert!((0..3).all(|_| bucket.try_acquire()));
+        assert!(!bucket.try_acquire());
+
+        // 3600/h refills one token per second
+        let fast = TokenBucket::new(&limit(3600, 1));
+        assert!(fast.try_acquire());
+        assert!(!fast.try_acquire());
+        std::thread::sleep(Duration::from_millis(1100));
+        assert!(fast.try_acquire());
+
+        let unlimited = TokenBucket::new(&limit(0, 0));
+        assert!((0..100).all(|_| unlimited.try_acquire()));
+    }
+
+    #[test]
+    fn schedules_are_checked_against_limit() {
+        let ttl = Duration::ZERO;
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Fixed(Duration::from_secs(60)), ttl).is_ok());
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Fixed(Duration::from_secs(30)), ttl).is_err());
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Fixed(Duration::ZERO), ttl).is_err());
+        // the cache absorbs ticks inside its TTL
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Fixed(Duration::from_secs(1)), Duration::from_secs(60)).is_ok());
+        assert!(validate_schedule(&limit(60, 5), &Schedule::OnDemand, ttl).is_ok());
+
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Cron("0 * * * * *".into()), ttl).is_ok());
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Cron("*/30 * * * * *".into()), ttl).is_err());
+        assert!(validate_schedule(&limit(60, 5), &Schedule::Cron("not cron".into()), ttl).is_err());
+    }
+}
@@ -1,51 +1,166 @@
-//! Adapter registry for Data Hub Policy (V3.5)
-//!
-//! This module declares the canonical lists of Allowed / Optional / Forbidden data hubs
-
-pub enum TrustLevel {
-    Official,
-    CommunityVerified,
-    CommunityUnverified,
-    Fallback,
-}
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum HubCategory {
-    Allowed,
-    Optional,
-    Forbidden,
-}
-
-pub struct HubInfo {
-    pub id: &'static str,
-    pub name: &'static str,
-    pub category: HubCategory,
-}
-
-// Canonical listing (update when adding adapters)
-pub const HUBS: &[HubInfo] = &[
-    HubInfo { id: "rsi_verseguide", name: "RSI VerseGuide", category: HubCategory::Allowed },
-    HubInfo { id: "galactapedia", name: "Galactapedia", category: HubCategory::Allowed },
-    HubInfo { id: "fleetyards", name: "FleetYards", category: HubCategory::Allowed },
-    HubInfo { id: "erkul", name: "Erkul", category: HubCategory::Allowed },
-    HubInfo { id: "uex", name: "UEX/SC Trade Tools", category: HubCategory::Allowed },
-    HubInfo { id: "rsi_comms", name: "RSI CommLinks & Patch Notes", category: HubCategory::Allowed },
-
-    // Optional / plugin
-    HubInfo { id: "rsi_issue_council", name: "RSI Issue Council", category: HubCategory::Optional },
-    HubInfo { id: "spectrum_dev", name: "Spectrum Dev Statements", category: HubCategory::Optional },
-
-    // Forbidden (examples)
-    HubInfo { id: "leaks", name: "Leaks / Datamining", category: HubCategory::Forbidden },
-    HubInfo { id: "player_tracking", name: "Player Tracking Services", category: HubCategory::Forbidden },
-];
-
-/// Verify that a given adapter id is allowed or optional; returns Err if forbidden or unknown.
-pub fn verify_adapter_allowed(adapter_id: &str) -> Result<HubCategory, String> {
-    for h in HUBS.iter() {
-        if h.id == adapter_id {
-            return Ok(h.category.clone());
-        }
-    }
-    Err(format!("Unknown adapter id: {} — must be registered in adapters::registry", adapter_id))
-}
+//! Adapter registry for Data Hub Policy (V3.5)
+//!
+//! This module declares the canonical lists of Allowed / Optional / Forbidden data hubs.
+//! `AdapterRegistry::register` checks every adapter's `hub_id` against them through `HubPolicy`
+//! and reports rejected registrations to an `AuditSink` as `ToSViolation` events.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+pub enum TrustLevel {
+    Official,
+    CommunityVerified,
+    CommunityUnverified,
+    Fallback,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub enum HubCategory {
+    Allowed,
+    Optional,
+    Forbidden,
+}
+
+#[derive(Debug)]
+pub struct HubInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub category: HubCategory,
+}
+
+// Canonical listing (update when adding adapters)
+pub const HUBS: &[HubInfo] = &[
+    HubInfo { id: "rsi_verseguide", name: "RSI VerseGuide", category: HubCategory::Allowed },
+    HubInfo { id: "galactapedia", name: "Galactapedia", category: HubCategory::Allowed },
+    HubInfo { id: "fleetyards", name: "FleetYards", category: HubCategory::Allowed },
+    HubInfo { id: "erkul", name: "Erkul", category: HubCategory::Allowed },
+    HubInfo { id: "uex", name: "UEX/SC Trade Tools", category: HubCategory::Allowed },
+    HubInfo { id: "rsi_comms", name: "RSI CommLinks & Patch Notes", category: HubCategory::Allowed },
+
+    // Optional / plugin
+    HubInfo { id: "rsi_issue_council", name: "RSI Issue Council", category: HubCategory::Optional },
+    HubInfo { id: "spectrum_dev", name: "Spectrum Dev Statements", category: HubCategory::Optional },
+
+    // Forbidden (examples)
+    HubInfo { id: "leaks", name: "Leaks / Datamining", category: HubCategory::Forbidden },
+    HubInfo { id: "player_tracking", name: "Player Tracking Services", category: HubCategory::Forbidden },
+];
+
+/// Verify that a given adapter id is allowed or optional; returns Err if forbidden or unknown.
+pub fn verify_adapter_allowed(adapter_id: &str) -> Result<HubCategory, String> {
+    hub_info(adapter_id)
+        .map(|h| h.category.clone())
+        .ok_or_else(|| format!("Unknown adapter id: {} — must be registered in adapters::registry", adapter_id))
+}
+
+/// Look up the `HUBS` entry for `hub_id`.
+pub fn hub_info(hub_id: &str) -> Option<&'static HubInfo> {
+    HUBS.iter().find(|h| h.id == hub_id)
+}
+
+/// Registration policy for data hubs: Allowed hubs always pass, Optional hubs only when opted in,
+/// Forbidden and unknown hubs never. Adapters that read no external hub (e.g. the org's own Discord bot
+/// or a local LLM) declare no hub id and register only when exempted by name.
+#[derive(Debug, Clone, Default)]
+pub struct HubPolicy {
+    opted_in: HashSet<String>,
+    exempt: HashSet<String>,
+}
+
+impl HubPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opt in to an Optional hub.
+    pub fn opt_in(mut self, hub_id: &str) -> Self {
+        self.opted_in.insert(hub_id.to_string());
+        self
+    }
+
+    /// Exempt adapter `name` from the hub check, provided it declares no hub id.
+    pub fn exempt(mut self, name: &str) -> Self {
+        self.exempt.insert(name.to_string());
+        self
+    }
+
+    /// Policy with the Optional hubs listed (comma separated) in `ADAPTER_OPTIONAL_HUBS` and the adapters
+    /// listed in `ADAPTER_HUB_EXEMPT`.
+    pub fn from_env() -> Self {
+        let list = |var| std::env::var(var).unwrap_or_default();
+        let ids = |s: &str| s.split(',').map(str::trim).filter(|id| !id.is_empty()).map(str::to_string).collect::<Vec<_>>();
+        let policy = ids(&list("ADAPTER_OPTIONAL_HUBS")).iter().fold(Self::new(), |p, id| p.opt_in(id));
+        ids(&list("ADAPTER_HUB_EXEMPT")).iter().fold(policy, |p, name| p.exempt(name))
+    }
+
+    /// Whether adapter `name` reading `hub_id` may be registered: exempt adapters without a hub pass, everything
+    /// else goes through `check`.
+    pub fn check_adapter(&self, name: &str, hub_id: Option<&str>) -> Result<(), String> {
+        if hub_id.is_none() && self.exempt.contains(name) {
+            return Ok(());
+        }
+        self.check(hub_id).map(|_| ())
+    }
+
+    /// The hub an adapter may be registered for, or the reason it may not.
+    pub fn check(&self, hub_id: Option<&str>) -> Result<&'static HubInfo, String> {
+        let id = hub_id.ok_or_else(|| "adapter declares no hub id".to_string())?;
+        let hub = hub_info(id).ok_or_else(|| format!("hub {} is not listed in adapters::registry::HUBS", id))?;
+        match hub.category {
+            HubCategory::Allowed => Ok(hub),
+            HubCategory::Optional if self.opted_in.contains(id) => Ok(hub),
+            HubCategory::Optional => Err(format!("optional hub {} requires opt-in", id)),
+            HubCategory::Forbidden => Err(format!("hub {} ({}) is forbidden", id, hub.name)),
+        }
+    }
+}
+
+/// Audit events raised by the adapter registry.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "event_type")]
+pub enum AuditEvent {
+    /// An adapter was refused registration under the data hub policy
+    ToSViolation {
+        adapter: String,
+        hub_id: Option<String>,
+        category: Option<HubCategory>,
+        reason: String,
+        at: DateTime<Utc>,
+    },
+}
+
+pub trait AuditSink: Send + Sync {
+    fn emit(&self, event: AuditEvent);
+}
+
+/// Default sink: audit events go to the log.
+pub struct LogAuditSink;
+
+impl AuditSink for LogAuditSink {
+    fn emit(&self, event: AuditEvent) {
+        tracing::warn!(target: "audit", "{}", serde_json::to_string(&event).unwrap_or_else(|_| format!("{:?}", event)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_enforces_categories() {
+        let policy = HubPolicy::new();
+        assert_eq!(policy.check(Some("fleetyards")).map(|h| h.id), Ok("fleetyards"));
+        assert!(policy.check(None).is_err());
+        assert!(policy.check(Some("unknown_hub")).is_err());
+        assert!(policy.check(Some("player_tracking")).unwrap_err().contains("forbidden"));
+        assert!(policy.check(Some("spectrum_dev")).unwrap_err().contains("opt-in"));
+        assert!(policy.clone().opt_in("spectrum_dev").check(Some("spectrum_dev")).is_ok());
+
+        let exempting = policy.exempt("inmemory-discord");
+        assert!(exempting.check_adapter("inmemory-discord", None).is_ok());
+        assert!(exempting.check_adapter("llm", None).is_err());
+        // an exemption does not cover a declared hub
+        assert!(exempting.check_adapter("inmemory-discord", Some("player_tracking")).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! Per-adapter retry policy and circuit breaker applied by `AdapterRegistry` around every fetch.
+//!
+//! Adapters declare their policy through `DataAdapter::retry_policy`. Failed fetches are retried with
+//! exponential backoff and jitter while the error is retryable. Consecutive failures open the adapter's
+//! circuit, which rejects fetches until `open_for` elapses; the next fetch then runs half-open and either
+//! closes the circuit or opens it again.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+#[derive(Debug, Clone)]
+pub struct BreakerConfig {
+    /// Consecutive failed attempts that open the circuit
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects fetches before going half-open
+    pub open_for: Duration,
+}
+
+impl Default for BreakerConfig {
+    fn default() -> Self {
+        Self { failure_threshold: 5, open_for: Duration::from_secs(5 * 60) }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Attempts per fetch, including the first one
+    pub max_attempts: u32,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Random spread applied to each backoff, as a fraction of it (0.0 disables jitter)
+    pub jitter: f64,
+    /// Whether an error returned by `fetch` is worth retrying
+    pub retryable: fn(&str) -> bool,
+    pub breaker: BreakerConfig,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: 0.2,
+            retryable: is_transient,
+            breaker: BreakerConfig::default(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retrying after failed attempt number `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.base_backoff.saturating_mul(1u32 << attempt.saturating_sub(1).min(16));
+        let capped = exp.min(self.max_backoff);
+        if self.jitter <= 0.0 {
+            return capped;
+        }
+        let spread = self.jitter.min(1.0);
+        capped.mul_f64(rand::thread_rng().gen_range(1.0 - spread..=1.0 + spread))
+    }
+}
+
+/// Default retry filter: client errors (HTTP 4xx other than 429) and malformed payloads are not retried.
+pub fn is_transient(error: &str) -> bool {
+    let e = error.to_ascii_lowercase();
+    if e.contains("invalid json") || e.contains("unsupported") {
+        return false;
+    }
+    match e.find("returned 4") {
+        Some(i) => e[i..].starts_with("returned 429"),
+        None => true,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum CircuitState {
+    Closed,
+    HalfOpen,
+    Open,
+}
+
+impl CircuitState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::HalfOpen => "half_open",
+            CircuitState::Open => "open",
+        }
+    }
+}
+
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// Start of the half-open trial call in flight, if any
+    probe_started: Option<Instant>,
+}
+
+pub struct CircuitBreaker {
+    config: BreakerConfig,
+    inner: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: BreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(BreakerState { state: CircuitState::Closed, consecutive_failures: 0, opened_at: None, probe_started: None }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        match self.inner.lock() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        self.lock().state
+    }
+
+    /// Whether a fetch may run now, plus the state transition this caused (open -> half-open), if any. While
+    /// half-open a single trial call is admitted; another one only if that trial was abandoned or outlived `open_for`.
+    pub fn allow(&self) -> (bool, Option<CircuitState>) {
+        let mut s = self.lock();
+        match s.state {
+            CircuitState::Closed => (true, None),
+            CircuitState::HalfOpen => {
+                if s.probe_started.is_some_and(|at| at.elapsed() < self.config.open_for) {
+                    return (false, None);
+                }
+                s.probe_started = Some(Instant::now());
+                (true, None)
+            }
+            CircuitState::Open => {
+                if s.opened_at.is_some_and(|at| at.elapsed() >= self.config.open_for) {
+                    s.state = CircuitState::HalfOpen;
+                    s.probe_started = Some(Instant::now());
+                    (true, Some(CircuitState::HalfOpen))
+                } else {
+                    (false, None)
+                }
+            }
+        }
+    }
+
+    /// Give up an admitted call without an outcome (e.g. it was rate limited), freeing the half-open trial slot.
+    pub fn abandon(&self) {
+        self.lock().probe_started = None;
+    }
+
+    /// Record a successful attempt; returns the new state if it changed.
+    pub fn on_success(&self) -> Option<CircuitState> {
+        let mut s = self.lock();
+        s.consecutive_failures = 0;
+        s.opened_at = None;
+        s.probe_started = None;
+        (s.state != CircuitState::Closed).then(|| {
+            s.state = CircuitState::Closed;
+            CircuitState::Closed
+        })
+    }
+
+    /// Record a failed attempt; returns the new state if it changed.
+    pub fn on_failure(&self) -> Option<CircuitState> {
+        let mut s = self.lock();
+        s.consecutive_failures += 1;
+        s.probe_started = None;
+        let trip = s.state == CircuitState::HalfOpen || s.consecutive_failures >= self.config.failure_threshold.max(1);
+        if trip {
+            s.opened_at = Some(Instant::now());
+        }
+        (trip && s.state != CircuitState::Open).then(|| {
+            s.state = CircuitState::Open;
+            CircuitState::Open
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_with_jitter_and_cap() {
+        let policy = RetryPolicy { jitter: 0.0, ..Default::default() };
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(20), Duration::from_secs(30));
+
+        let jittered = RetryPolicy::default();
+        for _ in 0..20 {
+            let b = jittered.backoff(2);
+            assert!(b >= Duration::from_millis(800) && b <= Duration::from_millis(1200), "{:?}", b);
+        }
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        assert!(is_transient("uex request failed: connection refused"));
+        assert!(is_transient("fleetyards /models returned 503 Service Unavailable"));
+        assert!(is_transient("uex returned 429 Too Many Requests"));
+        assert!(!is_transient("fleetyards /fleets/x/vehicles returned 404 Not Found"));
+        assert!(!is_transient("galactapedia: invalid json: expected value"));
+    }
+
+    #[test]
+    fn breaker_opens_half_opens_and_closes() {
+        let breaker = CircuitBreaker::new(BreakerConfig { failure_threshold: 2, open_for: Duration::from_millis(50) });
+        assert_eq!(breaker.on_failure(), None);
+        assert_eq!(breaker.on_failure(), Some(CircuitState::Open));
+        assert_eq!(breaker.allow(), (false, None));
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(breaker.allow(), (true, Some(CircuitState::HalfOpen)));
+        // a failed trial reopens immediately
+        assert_eq!(breaker.on_failure(), Some(CircuitState::Open));
+        assert!(!breaker.allow().0);
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(breaker.allow().0);
+        // only one trial call at a time; an abandoned trial frees the slot
+        assert_eq!(breaker.allow(), (false, None));
+        breaker.abandon();
+        assert!(breaker.allow().0);
+        assert_eq!(breaker.on_success(), Some(CircuitState::Closed));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
@@ -0,0 +1,219 @@
+//! HTTP RSI client reading public citizen profile pages
+//! (`https://robertsspaceindustries.com/citizens/<handle>`).
+//!
+//! Profiles (and "not found" answers) are cached per handle for a TTL; client errors are not cached.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use super::roster::{parse_roster_html, RosterPage, RsiOrgMember};
+use super::RsiClient;
+
+pub const DEFAULT_BASE_URL: &str = "https://robertsspaceindustries.com";
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+const ROSTER_PAGE_SIZE: usize = 32;
+const ROSTER_MAX_PAGES: usize = 100;
+
+/// Main organization shown on a citizen profile (absent when none or redacted).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RsiOrgMembership {
+    pub sid: String,
+    pub name: String,
+    pub rank: Option<String>,
+}
+
+/// Public citizen profile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RsiProfile {
+    pub handle: String,
+    pub display_name: String,
+    pub enlisted: Option<NaiveDate>,
+    pub main_org: Option<RsiOrgMembership>,
+}
+
+fn sel(s: &str) -> Selector {
+    // selectors are static literals below
+    Selector::parse(s).unwrap_or_else(|e| panic!("invalid selector {:?}: {:?}", s, e))
+}
+
+fn text(el: ElementRef) -> String {
+    el.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `label → value` of the `p.entry` rows under `root`.
+fn entries(root: ElementRef) -> HashMap<String, String> {
+    let entry = sel("p.entry");
+    let label = sel(".label");
+    let value = sel(".value");
+    root.select(&entry)
+        .filter_map(|e| {
+            let l = text(e.select(&label).next()?);
+            let v = text(e.select(&value).next()?);
+            Some((l.trim_end_matches(':').to_ascii_lowercase(), v))
+        })
+        .collect()
+}
+
+/// Parse a citizen profile page. Returns None if the page carries no handle.
+pub fn parse_profile(html: &str) -> Option<RsiProfile> {
+    let doc = Html::parse_document(html);
+    let root = doc.root_element();
+    let fields = entries(root);
+    let handle = fields.get("handle name")?.clone();
+
+    let display_name = doc
+        .select(&sel(".profile .info p.entry"))
+        .find(|e| e.select(&sel(".label")).next().is_none())
+        .and_then(|e| e.select(&sel(".value")).next())
+        .map(text)
+        .unwrap_or_else(|| handle.clone());
+
+    let enlisted = fields.get("enlisted").and_then(|d| NaiveDate::parse_from_str(d, "%b %d, %Y").ok());
+
+    let main_org = doc.select(&sel(".main-org")).next().and_then(|org| {
+        // hidden/redacted affiliations carry visibility-H / visibility-R
+        if org.value().classes().any(|c| c == "visibility-H" || c == "visibility-R") {
+            return None;
+        }
+        let f = entries(org);
+        let name = org.select(&sel(".info p.entry a.value")).next().map(text)?;
+        Some(RsiOrgMembership {
+            sid: f.get("spectrum identification (sid)").cloned()?,
+            name,
+            rank: f.get("organization rank").cloned(),
+        })
+    });
+
+    Some(RsiProfile { handle, display_name, enlisted, main_org })
+}
+
+fn valid_handle(h: &str) -> bool {
+    !h.is_empty() && h.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Blocking RSI client; must not be called from within an async runtime.
+pub struct HttpRsiClient {
+    base_url: String,
+    timeout: Duration,
+    ttl: Duration,
+    cache: Mutex<HashMap<String, (Instant, Option<RsiProfile>)>>,
+}
+
+impl HttpRsiClient {
+    pub fn new() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(15),
+            ttl: DEFAULT_CACHE_TTL,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Point the client at another site root (e.g. a mock server).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn cached(&self, key: &str) -> Option<Option<RsiProfile>> {
+        let cache = self.cache.lock().ok()?;
+        cache.get(key).filter(|(at, _)| at.elapsed() < self.ttl).map(|(_, p)| p.clone())
+    }
+
+    /// Public profile for `handle`; Ok(None) if no such citizen exists.
+    pub fn fetch_profile(&self, handle: &str) -> Result<Option<RsiProfile>, String> {
+        if !valid_handle(handle) {
+            return Ok(None);
+        }
+        let key = handle.to_ascii_lowercase();
+        if let Some(p) = self.cached(&key) {
+            return Ok(p);
+        }
+
+        let client = reqwest::blocking::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        let resp = client
+            .get(format!("{}/citizens/{}", self.base_url, handle))
+            .send()
+            .map_err(|e| format!("rsi request failed: {}", e))?;
+        let profile = match resp.status() {
+            s if s.is_success() => parse_profile(&resp.text().map_err(|e| e.to_string())?),
+            reqwest::StatusCode::NOT_FOUND => None,
+            s => return Err(format!("rsi returned {}", s)),
+        };
+
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(key, (Instant::now(), profile.clone()));
+        }
+        Ok(profile)
+    }
+
+    /// Verifier closure for `MemberHandler::add_with_rsi`.
+    pub fn verifier(&self) -> impl FnMut(&str) -> Result<bool, String> + '_ {
+        move |h| self.verify_handle(h)
+    }
+
+    /// Public member list of the organization with spectrum id `sid` (not cached).
+    pub fn fetch_org_roster(&self, sid: &str) -> Result<Vec<RsiOrgMember>, String> {
+        if !valid_handle(sid) {
+            return Err(format!("invalid organization sid: {:?}", sid));
+        }
+        let client = reqwest::blocking::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())?;
+        let mut out = vec![];
+        for page in 1..=ROSTER_MAX_PAGES {
+            let resp = client
+                .post(format!("{}/api/orgs/getOrgMembers", self.base_url))
+                .json(&serde_json::json!({ "symbol": sid, "search": "", "pagesize": ROSTER_PAGE_SIZE, "page": page }))
+                .send()
+                .map_err(|e| format!("rsi request failed: {}", e))?;
+            if !resp.status().is_success() {
+                return Err(format!("rsi returned {}", resp.status()));
+            }
+            let body: RosterPage = resp.json().map_err(|e| format!("rsi roster: invalid json: {}", e))?;
+            if body.success != 1 {
+                return Err(format!("rsi roster error: {}", body.msg.unwrap_or_default()));
+            }
+            let data = body.data.ok_or("rsi roster: missing data")?;
+            let members = parse_roster_html(&data.html);
+            let done = members.is_empty() || out.len() + members.len() >= data.totalrows;
+            out.extend(members);
+            if done {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn clear_cache(&self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.clear();
+        }
+    }
+}
+
+impl Default for HttpRsiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RsiClient for HttpRsiClient {
+    fn verify_handle(&self, handle: &str) -> Result<bool, String> {
+        Ok(self.fetch_profile(handle)?.is_some())
+    }
+}
@@ -0,0 +1,129 @@
+//! RSI organization roster parsing.
+//!
+//! The public member list is served by `POST /api/orgs/getOrgMembers` as JSON wrapping an HTML fragment of
+//! `li.member-item` cards; `parse_roster_html` turns one page into `RsiOrgMember`s.
+
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+
+/// Visibility of a member's affiliation, from the card's `org-visibility-*` class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RosterVisibility {
+    Visible,
+    /// Member is listed but the handle is withheld
+    Redacted,
+    Hidden,
+}
+
+/// One card of an organization's public member list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RsiOrgMember {
+    /// None for redacted/hidden members
+    pub handle: Option<String>,
+    pub display_name: Option<String>,
+    pub rank: Option<String>,
+    /// Rank stars 0-5
+    pub stars: u8,
+    /// Affiliate (as opposed to main) membership
+    pub affiliate: bool,
+    pub visibility: RosterVisibility,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RosterPage {
+    pub success: u8,
+    #[serde(default)]
+    pub msg: Option<String>,
+    #[serde(default)]
+    pub data: Option<RosterPageData>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RosterPageData {
+    #[serde(default)]
+    pub totalrows: usize,
+    #[serde(default)]
+    pub html: String,
+}
+
+fn sel(s: &str) -> Selector {
+    Selector::parse(s).unwrap_or_else(|e| panic!("invalid selector {:?}: {:?}", s, e))
+}
+
+fn first_text(el: scraper::ElementRef, selector: &Selector) -> Option<String> {
+    el.select(selector)
+        .next()
+        .map(|e| e.text().collect::<String>().trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+/// Parse the HTML fragment of one roster page.
+pub fn parse_roster_html(html: &str) -> Vec<RsiOrgMember> {
+    let frag = Html::parse_fragment(html);
+    let (item, nick, name, rank, title, stars) =
+        (sel("li.member-item"), sel(".nick"), sel(".name"), sel(".rank"), sel(".title"), sel(".stars"));
+
+    frag.select(&item)
+        .map(|li| {
+            let classes: Vec<&str> = li.value().classes().collect();
+            let visibility = if classes.contains(&"org-visibility-R") {
+                RosterVisibility::Redacted
+            } else if classes.contains(&"org-visibility-H") {
+                RosterVisibility::Hidden
+            } else {
+                RosterVisibility::Visible
+            };
+            let visible = visibility == RosterVisibility::Visible;
+            // star width is rendered as "width: 60%;"
+            let stars = li
+                .select(&stars)
+                .next()
+                .and_then(|s| s.value().attr("style"))
+                .and_then(|s| s.split(':').nth(1))
+                .and_then(|w| w.trim().trim_end_matches(';').trim_end_matches('%').trim().parse::<f64>().ok())
+                .map(|w| (w / 20.0).round().clamp(0.0, 5.0) as u8)
+                .unwrap_or(0);
+            RsiOrgMember {
+                handle: first_text(li, &nick).filter(|_| visible),
+                display_name: first_text(li, &name).filter(|_| visible),
+                rank: first_text(li, &rank),
+                stars,
+                affiliate: first_text(li, &title).is_some_and(|t| t.eq_ignore_ascii_case("affiliate")),
+                visibility,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_member_cards() {
+        let html = r#"
+            <li class="member-item js-member-item org-visibility-V">
+              <a class="membercard" href="/citizens/Alice_SF">
+                <span class="frontinfo">
+                  <span class="name-wrap"><span class="name">Alice Starfarer</span><span class="nick data2">Alice_SF</span></span>
+                  <span class="ranking-stars"><span class="stars" style="width: 80%;"></span></span>
+                  <span class="rank">Captain</span>
+                </span>
+              </a>
+            </li>
+            <li class="member-item js-member-item org-visibility-V">
+              <span class="title">Affiliate</span><span class="nick">bob</span><span class="rank">Recruit</span>
+            </li>
+            <li class="member-item js-member-item org-visibility-R"><span class="nick">REDACTED</span></li>
+        "#;
+        let members = parse_roster_html(html);
+        assert_eq!(members.len(), 3);
+        assert_eq!(members[0].handle.as_deref(), Some("Alice_SF"));
+        assert_eq!(members[0].rank.as_deref(), Some("Captain"));
+        assert_eq!(members[0].stars, 4);
+        assert!(!members[0].affiliate);
+        assert!(members[1].affiliate);
+        assert_eq!(members[2].visibility, RosterVisibility::Redacted);
+        assert!(members[2].handle.is_none());
+    }
+}
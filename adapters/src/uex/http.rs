@@ -0,0 +1,230 @@
+//! HTTP client for the UEX API (<https://api.uexcorp.space/2.0>).
+//!
+//! Reads `/commodities_prices_all`, which lists buy/sell prices of every commodity per terminal.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use serde::Deserialize;
+
+use super::{CommodityPrice, PriceSnapshot};
+use crate::adapter_api::{AdapterData, AdapterHealth, DataAdapter, RateLimit};
+use crate::health;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.uexcorp.space/2.0";
+
+#[derive(Deserialize)]
+struct UexResponse<T> {
+    status: String,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default = "Vec::new")]
+    data: Vec<T>,
+}
+
+#[derive(Deserialize)]
+struct UexPriceRow {
+    commodity_name: String,
+    #[serde(default)]
+    commodity_code: Option<String>,
+    terminal_name: String,
+    #[serde(default)]
+    terminal_code: Option<String>,
+    #[serde(default)]
+    star_system_name: Option<String>,
+    #[serde(default)]
+    planet_name: Option<String>,
+    #[serde(default)]
+    price_buy: Option<f64>,
+    #[serde(default)]
+    price_sell: Option<f64>,
+    #[serde(default)]
+    scu_buy: Option<f64>,
+    #[serde(default)]
+    scu_sell: Option<f64>,
+    /// Unix timestamp (seconds)
+    #[serde(default)]
+    date_modified: Option<i64>,
+}
+
+/// UEX reports "not traded here" as 0; keep only real prices.
+fn positive(v: Option<f64>) -> Option<f64> {
+    v.filter(|x| *x > 0.0)
+}
+
+impl UexPriceRow {
+    fn into_price(self) -> CommodityPrice {
+        CommodityPrice {
+            commodity: self.commodity_name,
+            commodity_code: self.commodity_code,
+            terminal: self.terminal_name,
+            terminal_code: self.terminal_code,
+            system: self.star_system_name.unwrap_or_default(),
+            planet: self.planet_name,
+            buy_price: positive(self.price_buy),
+            sell_price: positive(self.price_sell),
+            scu_available: self.scu_buy,
+            scu_demand: self.scu_sell,
+            updated_at: self.date_modified.and_then(|t| Utc.timestamp_opt(t, 0).single()),
+        }
+    }
+}
+
+/// UEX API client implementing `DataAdapter` (publishes `AdapterData::Prices`).
+pub struct HttpUexClient {
+    base_url: String,
+    api_token: Option<String>,
+    timeout: Duration,
+}
+
+impl HttpUexClient {
+    pub fn new() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_token: None,
+            timeout: Duration::from_secs(15),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Application token sent as a bearer token.
+    pub fn with_api_token(mut self, token: impl Into<String>) -> Self {
+        self.api_token = Some(token.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn request(&self, client: &reqwest::Client, path: &str) -> reqwest::RequestBuilder {
+        let req = client.get(format!("{}{}", self.base_url, path));
+        match &self.api_token {
+            Some(t) => req.bearer_auth(t),
+            None => req,
+        }
+    }
+
+    fn client(&self) -> crate::Result<reqwest::Client> {
+        reqwest::Client::builder().timeout(self.timeout).build().map_err(|e| e.to_string())
+    }
+
+    /// Current prices of all commodities at all terminals.
+    pub async fn fetch_prices(&self) -> crate::Result<PriceSnapshot> {
+        let resp = self
+            .request(&self.client()?, "/commodities_prices_all")
+            .send()
+            .await
+            .map_err(|e| format!("uex request failed: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("uex returned {}", resp.status()));
+        }
+        let body: UexResponse<UexPriceRow> = resp.json().await.map_err(|e| format!("uex: invalid json: {}", e))?;
+        if body.status != "ok" {
+            return Err(format!("uex error: {}", body.message.unwrap_or(body.status)));
+        }
+        Ok(PriceSnapshot::new(body.data.into_iter().map(UexPriceRow::into_price).collect(), Utc::now()))
+    }
+}
+
+impl Default for HttpUexClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataAdapter for HttpUexClient {
+    fn name(&self) -> &str { "uex" }
+    fn version(&self) -> &str { "0.1.0" }
+    fn source_url(&self) -> &str { &self.base_url }
+    fn attribution(&self) -> &str { "Commodity prices provided by UEX (uexcorp.space)" }
+    fn license(&self) -> &str { "UEX API terms of use" }
+    fn hub_id(&self) -> Option<&str> { Some("uex") }
+
+    async fn health_check(&self) -> crate::Result<AdapterHealth> {
+        Ok(health::probe("uex", self.request(&self.client()?, "/star_systems")).await)
+    }
+
+    async fn fetch(&self) -> crate::Result<AdapterData> {
+        self.fetch_prices().await?.into_adapter_data()
+    }
+
+    fn rate_limit(&self) -> RateLimit { RateLimit { requests_per_hour: 60, burst: 5 } }
+    fn cache_ttl(&self) -> Duration { Duration::from_secs(15 * 60) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter_api::HealthStatus;
+    use httpmock::{Method::GET, MockServer};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn fetch_publishes_typed_prices() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/commodities_prices_all").header("authorization", "Bearer tok");
+            then.status(200).json_body(json!({
+                "status": "ok",
+                "data": [
+                    {
+                        "id_commodity": 1, "commodity_name": "Laranite", "commodity_code": "LARA",
+                        "terminal_name": "ArcCorp Mining Area 045", "terminal_code": "AM045",
+                        "star_system_name": "Stanton", "planet_name": "Wala",
+                        "price_buy": 0, "price_sell": 3100.5, "scu_buy": 0, "scu_sell": 1200,
+                        "date_modified": 1735473600
+                    },
+                    {
+                        "commodity_name": "Laranite", "terminal_name": "Area 18 TDD",
+                        "star_system_name": "Stanton", "price_buy": 2650, "price_sell": 0, "scu_buy": 800
+                    }
+                ]
+            }));
+        });
+
+        let c = HttpUexClient::new().with_base_url(server.base_url()).with_api_token("tok");
+        let data = c.fetch().await.expect("fetch");
+        let snap = PriceSnapshot::from_adapter_data(&data).expect("snapshot");
+        assert_eq!(snap.schema_version, 1);
+        assert_eq!(snap.prices.len(), 2);
+
+        let p = &snap.prices[0];
+        assert_eq!(p.commodity_code.as_deref(), Some("LARA"));
+        assert_eq!(p.system, "Stanton");
+        assert_eq!(p.buy_price, None);
+        assert_eq!(p.sell_price, Some(3100.5));
+        assert_eq!(p.scu_demand, Some(1200.0));
+        assert_eq!(p.updated_at.map(|t| t.timestamp()), Some(1735473600));
+        assert_eq!(snap.prices[1].updated_at, None);
+        assert_eq!(snap.for_commodity("lara").count(), 1);
+        assert_eq!(snap.for_commodity("Laranite").count(), 2);
+
+        // rows without a date do not change the content hash between fetches
+        let again = c.fetch().await.expect("fetch");
+        assert_eq!(crate::delta::content_hash(&data), crate::delta::content_hash(&again));
+    }
+
+    #[tokio::test]
+    async fn api_errors_are_reported() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/commodities_prices_all");
+            then.status(200).json_body(json!({"status": "requests_limit_reached", "message": "too many requests"}));
+        });
+        let c = HttpUexClient::new().with_base_url(server.base_url());
+        let err = c.fetch().await.unwrap_err();
+        assert!(err.contains("too many requests"));
+        let health = c.health_check().await.expect("health");
+        // the probe endpoint is not mocked
+        assert!(matches!(health.status, HealthStatus::Degraded));
+        assert!(health.last_error.is_some_and(|e| e.contains("404")));
+    }
+}
@@ -0,0 +1,78 @@
+//! UEX adapter (outbound)
+//!
+//! Typed commodity price model and an HTTP client for the UEX API (`http`). Prices are published as
+//! `AdapterData::Prices` carrying a serialized `PriceSnapshot`;
+//! `routes` ranks trade routes over a snapshot.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::adapter_api::AdapterData;
+
+pub mod http;
+pub mod routes;
+pub use http::HttpUexClient;
+pub use routes::{RouteFilter, RouteLeg, TradePlanner, TradeRoute};
+
+/// Schema version of the `PriceSnapshot` payload; bump on breaking changes.
+pub const PRICE_SCHEMA_VERSION: u32 = 1;
+
+/// Buy/sell prices of one commodity at one terminal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommodityPrice {
+    pub commodity: String,
+    pub commodity_code: Option<String>,
+    pub terminal: String,
+    pub terminal_code: Option<String>,
+    pub system: String,
+    pub planet: Option<String>,
+    /// Price per SCU the terminal sells at (what a trader pays), if it sells the commodity
+    pub buy_price: Option<f64>,
+    /// Price per SCU the terminal buys at (what a trader receives), if it buys the commodity
+    pub sell_price: Option<f64>,
+    /// SCU available to buy at the terminal
+    pub scu_available: Option<f64>,
+    /// SCU the terminal is willing to buy
+    pub scu_demand: Option<f64>,
+    /// When UEX last updated this price; `None` when it does not say
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Payload of `AdapterData::Prices`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceSnapshot {
+    pub schema_version: u32,
+    pub source: String,
+    pub fetched_at: DateTime<Utc>,
+    pub prices: Vec<CommodityPrice>,
+}
+
+impl PriceSnapshot {
+    pub fn new(prices: Vec<CommodityPrice>, fetched_at: DateTime<Utc>) -> Self {
+        Self { schema_version: PRICE_SCHEMA_VERSION, source: "uex".to_string(), fetched_at, prices }
+    }
+
+    pub fn into_adapter_data(self) -> crate::Result<AdapterData> {
+        serde_json::to_value(self).map(AdapterData::Prices).map_err(|e| e.to_string())
+    }
+
+    /// Decode a snapshot from `AdapterData::Prices`; other variants and unknown schema versions are rejected.
+    pub fn from_adapter_data(data: &AdapterData) -> crate::Result<Self> {
+        let AdapterData::Prices(v) = data else {
+            return Err("expected AdapterData::Prices".to_string());
+        };
+        let snap: PriceSnapshot = serde_json::from_value(v.clone()).map_err(|e| e.to_string())?;
+        if snap.schema_version != PRICE_SCHEMA_VERSION {
+            return Err(format!("unsupported price schema version {}", snap.schema_version));
+        }
+        Ok(snap)
+    }
+
+    /// Prices for one commodity, by name or code.
+    pub fn for_commodity<'a>(&'a self, commodity: &'a str) -> impl Iterator<Item = &'a CommodityPrice> + 'a {
+        self.prices
+            .iter()
+            .filter(move |p| p.commodity.eq_ignore_ascii_case(commodity) || p.commodity_code.as_deref().is_some_and(|c| c.eq_ignore_ascii_case(commodity)))
+    }
+}
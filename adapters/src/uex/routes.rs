@@ -0,0 +1,314 @@
+//! Trade route planner over a UEX `PriceSnapshot`.
+//!
+//! Ranks buy→sell routes for a given cargo capacity. A leg buys one commodity at one terminal and sells it at
+//! another; multi-stop routes chain legs where each leg starts at the terminal the previous one sold at,
+//! without repeating a hop.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::{CommodityPrice, PriceSnapshot};
+
+/// Outgoing legs kept per terminal when chaining multi-stop routes.
+const MAX_BRANCHING: usize = 5;
+
+/// Upper bound on `RouteFilter::max_stops`; larger values are clamped.
+pub const MAX_STOPS: usize = 4;
+
+/// Planner filters. The purchase cap of a leg is the lower of `max_investment` and `treasury_budget`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteFilter {
+    /// Only terminals in this star system (case-insensitive)
+    pub system: Option<String>,
+    /// Maximum aUEC spent on a single purchase
+    pub max_investment: Option<f64>,
+    /// Funds the org treasury makes available for the run
+    pub treasury_budget: Option<f64>,
+    /// Maximum number of legs per route (1 = direct buy→sell), at most `MAX_STOPS`
+    pub max_stops: usize,
+    /// Maximum number of routes returned
+    pub limit: usize,
+}
+
+impl Default for RouteFilter {
+    fn default() -> Self {
+        Self { system: None, max_investment: None, treasury_budget: None, max_stops: 1, limit: 10 }
+    }
+}
+
+impl RouteFilter {
+    fn budget(&self) -> Option<f64> {
+        match (self.max_investment, self.treasury_budget) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// One buy→sell hop.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RouteLeg {
+    pub commodity: String,
+    pub buy_terminal: String,
+    pub sell_terminal: String,
+    pub buy_system: String,
+    pub sell_system: String,
+    pub buy_price: f64,
+    pub sell_price: f64,
+    pub scu: f64,
+    pub investment: f64,
+    pub profit: f64,
+}
+
+/// A ranked route of one or more legs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TradeRoute {
+    pub legs: Vec<RouteLeg>,
+    /// Sum of leg profits
+    pub profit_per_run: f64,
+    /// Profit divided by total SCU hauled
+    pub profit_per_scu: f64,
+    /// Largest single purchase along the route (capital needed up front)
+    pub investment: f64,
+}
+
+impl TradeRoute {
+    fn from_legs(legs: Vec<RouteLeg>) -> Self {
+        let profit_per_run: f64 = legs.iter().map(|l| l.profit).sum();
+        let scu: f64 = legs.iter().map(|l| l.scu).sum();
+        let investment = legs.iter().map(|l| l.investment).fold(0.0, f64::max);
+        Self { profit_per_run, profit_per_scu: if scu > 0.0 { profit_per_run / scu } else { 0.0 }, investment, legs }
+    }
+}
+
+/// Route planner over one price snapshot.
+pub struct TradePlanner<'a> {
+    snapshot: &'a PriceSnapshot,
+}
+
+impl<'a> TradePlanner<'a> {
+    pub fn new(snapshot: &'a PriceSnapshot) -> Self {
+        Self { snapshot }
+    }
+
+    /// Routes for a ship with `cargo_scu` capacity, best profit per run first.
+    pub fn plan(&self, cargo_scu: f64, filter: &RouteFilter) -> Vec<TradeRoute> {
+        if cargo_scu <= 0.0 || filter.limit == 0 {
+            return vec![];
+        }
+        let legs = self.best_legs(cargo_scu, filter);
+
+        let mut routes: Vec<TradeRoute> = if filter.max_stops <= 1 {
+            legs.into_iter().map(|l| TradeRoute::from_legs(vec![l])).collect()
+        } else {
+            let mut by_start: HashMap<&str, Vec<&RouteLeg>> = HashMap::new();
+            for l in &legs {
+                by_start.entry(l.buy_terminal.as_str()).or_default().push(l);
+            }
+            for out in by_start.values_mut() {
+                out.sort_by(|a, b| b.profit.total_cmp(&a.profit));
+                out.truncate(MAX_BRANCHING);
+            }
+            let mut chainer = Chainer {
+                by_start: &by_start,
+                max_stops: filter.max_stops.min(MAX_STOPS),
+                limit: filter.limit,
+                best_leg_profit: legs.iter().map(|l| l.profit).fold(0.0, f64::max),
+                routes: vec![],
+            };
+            for l in &legs {
+                chainer.chain(&mut vec![l]);
+            }
+            chainer.routes
+        };
+
+        routes.sort_by(|a, b| b.profit_per_run.total_cmp(&a.profit_per_run));
+        routes.truncate(filter.limit);
+        routes
+    }
+
+    /// Most profitable commodity per (buy terminal, sell terminal) pair.
+    fn best_legs(&self, cargo_scu: f64, filter: &RouteFilter) -> Vec<RouteLeg> {
+        let in_system = |p: &CommodityPrice| filter.system.as_deref().is_none_or(|s| p.system.eq_ignore_ascii_case(s));
+        let budget = filter.budget();
+
+        let mut best: HashMap<(String, String), RouteLeg> = HashMap::new();
+        for buy in self.snapshot.prices.iter().filter(|p| in_system(p)) {
+            let Some(buy_price) = buy.buy_price else { continue };
+            for sell in self.snapshot.prices.iter().filter(|p| in_system(p)) {
+                let Some(sell_price) = sell.sell_price else { continue };
+                if sell.commodity != buy.commodity || sell.terminal == buy.terminal || sell_price <= buy_price {
+                    continue;
+                }
+                let mut scu = cargo_scu;
+                for cap in [buy.scu_available, sell.scu_demand, budget.map(|b| b / buy_price)].into_iter().flatten() {
+                    scu = scu.min(cap);
+                }
+                let scu = scu.floor();
+                if scu < 1.0 {
+                    continue;
+                }
+                let leg = RouteLeg {
+                    commodity: buy.commodity.clone(),
+                    buy_terminal: buy.terminal.clone(),
+                    sell_terminal: sell.terminal.clone(),
+                    buy_system: buy.system.clone(),
+                    sell_system: sell.system.clone(),
+                    buy_price,
+                    sell_price,
+                    scu,
+                    investment: scu * buy_price,
+                    profit: scu * (sell_price - buy_price),
+                };
+                let key = (leg.buy_terminal.clone(), leg.sell_terminal.clone());
+                if best.get(&key).is_none_or(|b| leg.profit > b.profit) {
+                    best.insert(key, leg);
+                }
+            }
+        }
+        best.into_values().collect()
+    }
+}
+
+/// Depth-first chaining of legs that keeps only the `limit` most profitable routes and skips
+/// paths that cannot beat them even if every remaining stop earned the best leg profit.
+struct Chainer<'l, 'm> {
+    by_start: &'m HashMap<&'l str, Vec<&'l RouteLeg>>,
+    max_stops: usize,
+    limit: usize,
+    best_leg_profit: f64,
+    /// Best routes so far, most profitable first
+    routes: Vec<TradeRoute>,
+}
+
+impl<'l> Chainer<'l, '_> {
+    /// Profit a route has to exceed to be kept.
+    fn floor(&self) -> f64 {
+        if self.routes.len() < self.limit {
+            f64::NEG_INFINITY
+        } else {
+            self.routes[self.limit - 1].profit_per_run
+        }
+    }
+
+    fn chain(&mut self, path: &mut Vec<&'l RouteLeg>) {
+        let profit: f64 = path.iter().map(|l| l.profit).sum();
+        if profit > self.floor() {
+            let route = TradeRoute::from_legs(path.iter().map(|l| (*l).clone()).collect());
+            let pos = self.routes.partition_point(|r| r.profit_per_run >= profit);
+            self.routes.insert(pos, route);
+            self.routes.truncate(self.limit);
+        }
+        let remaining = self.max_stops.saturating_sub(path.len());
+        if remaining == 0 || profit + remaining as f64 * self.best_leg_profit <= self.floor() {
+            return;
+        }
+        let by_start = self.by_start;
+        let Some(last) = path.last() else { return };
+        let Some(next) = by_start.get(last.sell_terminal.as_str()) else { return };
+        for l in next {
+            // round trips are fine, repeating the same hop is not
+            if path.iter().any(|p| p.buy_terminal == l.buy_terminal && p.sell_terminal == l.sell_terminal) {
+                continue;
+            }
+            path.push(l);
+            self.chain(path);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn price(commodity: &str, terminal: &str, system: &str, buy: Option<f64>, sell: Option<f64>, stock: Option<f64>) -> CommodityPrice {
+        CommodityPrice {
+            commodity: commodity.into(),
+            commodity_code: None,
+            terminal: terminal.into(),
+            terminal_code: None,
+            system: system.into(),
+            planet: None,
+            buy_price: buy,
+            sell_price: sell,
+            scu_available: stock,
+            scu_demand: None,
+            updated_at: Some(Utc::now()),
+        }
+    }
+
+    fn snapshot() -> PriceSnapshot {
+        PriceSnapshot::new(
+            vec![
+                price("Laranite", "A18", "Stanton", Some(26.0), None, Some(1000.0)),
+                price("Laranite", "Lorville", "Stanton", None, Some(31.0), None),
+                price("Agricium", "Lorville", "Stanton", Some(24.0), None, None),
+                price("Agricium", "A18", "Stanton", None, Some(27.5), None),
+                price("Laranite", "Pyro Gateway", "Pyro", None, Some(40.0), None),
+            ],
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn ranks_direct_routes_by_profit() {
+        let snap = snapshot();
+        let routes = TradePlanner::new(&snap).plan(100.0, &RouteFilter::default());
+        assert_eq!(routes.len(), 3);
+        let best = &routes[0];
+        assert_eq!(best.legs[0].sell_terminal, "Pyro Gateway");
+        assert_eq!(best.profit_per_run, 1400.0);
+        assert_eq!(best.profit_per_scu, 14.0);
+        assert_eq!(best.investment, 2600.0);
+    }
+
+    #[test]
+    fn filters_by_system_and_budget() {
+        let snap = snapshot();
+        let filter = RouteFilter {
+            system: Some("stanton".into()),
+            max_investment: Some(5000.0),
+            treasury_budget: Some(1300.0),
+            ..RouteFilter::default()
+        };
+        let routes = TradePlanner::new(&snap).plan(100.0, &filter);
+        assert!(routes.iter().all(|r| r.legs.iter().all(|l| l.sell_system == "Stanton")));
+        let lara = routes.iter().find(|r| r.legs[0].commodity == "Laranite").expect("laranite route");
+        // 1300 / 26 = 50 SCU
+        assert_eq!(lara.legs[0].scu, 50.0);
+        assert!(lara.investment <= 1300.0);
+    }
+
+    #[test]
+    fn chains_multi_stop_routes() {
+        let snap = snapshot();
+        let filter = RouteFilter { system: Some("Stanton".into()), max_stops: 3, ..RouteFilter::default() };
+        let routes = TradePlanner::new(&snap).plan(100.0, &filter);
+        let best = &routes[0];
+        assert_eq!(best.legs.len(), 2);
+        assert_eq!(best.legs[0].sell_terminal, best.legs[1].buy_terminal);
+        assert_eq!(best.profit_per_run, 500.0 + 350.0);
+    }
+
+    #[test]
+    fn caps_stops_and_keeps_only_the_best_routes() {
+        // every terminal buys and sells two commodities, so any terminal chains to any other
+        let terminals: Vec<String> = (0..12).map(|i| format!("T{}", i)).collect();
+        let mut prices = vec![];
+        for (i, t) in terminals.iter().enumerate() {
+            let p = 10.0 + i as f64;
+            prices.push(price("Laranite", t, "Stanton", Some(p), Some(p + 5.0), None));
+            prices.push(price("Agricium", t, "Stanton", Some(30.0 - p), Some(35.0 - p), None));
+        }
+        let snap = PriceSnapshot::new(prices, Utc::now());
+        let filter = RouteFilter { max_stops: usize::MAX, limit: 3, ..RouteFilter::default() };
+        let routes = TradePlanner::new(&snap).plan(10.0, &filter);
+        assert_eq!(routes.len(), 3);
+        assert!(routes.iter().all(|r| r.legs.len() <= MAX_STOPS));
+        assert!(routes.windows(2).all(|w| w[0].profit_per_run >= w[1].profit_per_run));
+        assert_eq!(routes[0].legs.len(), MAX_STOPS);
+    }
+}
@@ -0,0 +1,85 @@
+use httpmock::{Method::GET, MockServer};
+use sc_manager_adapters::comm_link::{parse_feed, CommLinkAdapter, NewsBatch, NewsSource};
+use sc_manager_adapters::delta::DataDelta;
+use sc_manager_adapters::{AdapterData, AdapterRegistry, DataAdapter, EventPublisher, Schedule};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+const RSS: &str = include_str!("fixtures/comm_link/comm_link_rss.xml");
+const ATOM: &str = include_str!("fixtures/comm_link/comm_link_atom.xml");
+const PATCH_NOTES: &str = include_str!("fixtures/comm_link/patch_notes.html");
+
+#[derive(Default)]
+struct Recorder {
+    events: Mutex<Vec<(String, Vec<u8>)>>,
+}
+
+impl EventPublisher for Recorder {
+    fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> Pin<Box<dyn Future<Output = sc_manager_adapters::Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            self.events.lock().map_err(|e| e.to_string())?.push((subject.to_string(), payload));
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn parses_rss_and_atom_fixtures() {
+    let rss = parse_feed(RSS.as_bytes()).expect("rss");
+    assert_eq!(rss.len(), 2);
+    assert_eq!(rss[0].id, "comm-link-20001");
+    assert_eq!(rss[0].title, "This Week in Star Citizen");
+    assert_eq!(rss[0].category.as_deref(), Some("Transmission"));
+    assert_eq!(rss[0].published.map(|d| d.to_rfc3339()).as_deref(), Some("2026-10-12T16:00:00+00:00"));
+    assert!(rss[0].summary.as_deref().is_some_and(|s| s.contains("'verse")));
+
+    let atom = parse_feed(ATOM.as_bytes()).expect("atom");
+    assert_eq!(atom[0].category.as_deref(), Some("Engineering"));
+    assert!(atom[0].link.ends_with("20003-SCL-Ship-Balance"));
+}
+
+#[tokio::test]
+async fn new_items_are_published_as_delta() {
+    let server = MockServer::start_async().await;
+    let mut feed = server.mock(|when, then| {
+        when.method(GET).path("/comm-link/rss");
+        then.status(200).header("content-type", "application/rss+xml").body(RSS);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/patch-notes");
+        then.status(200).body(PATCH_NOTES);
+    });
+    let adapter = CommLinkAdapter::new()
+        .with_feed_url(server.url("/comm-link/rss"))
+        .with_patch_notes_url(Some(server.url("/patch-notes")));
+
+    let listing = NewsBatch::from_adapter_data(&adapter.fetch().await.expect("fetch")).expect("batch");
+    assert_eq!(listing.items.len(), 4);
+    assert_eq!(listing.items[0].title, "Star Citizen Alpha 4.3.3 PTU Patch Notes");
+    let live = listing.items.iter().find(|i| i.source == NewsSource::PatchNotes && i.category.as_deref() == Some("LIVE")).expect("live notes");
+    assert_eq!(live.link, format!("{}/patch-notes/4-3-2-live-9876543", server.base_url()));
+
+    let mut reg = AdapterRegistry::new();
+    reg.register(Arc::new(adapter), Schedule::OnDemand).expect("register");
+    let recorder = Arc::new(Recorder::default());
+    reg.fetch_and_update("rsi_comms", Some(recorder.clone())).await.expect("first fetch");
+
+    // the feed grows by one entry
+    feed.delete();
+    server.mock(|when, then| {
+        when.method(GET).path("/comm-link/rss");
+        then.status(200).body(RSS.replace("</channel>", r#"<item><title>Patch Watch</title><link>https://robertsspaceindustries.com/comm-link/transmission/20004</link><guid>comm-link-20004</guid><pubDate>Sat, 17 Oct 2026 10:00:00 GMT</pubDate></item></channel>"#));
+    });
+    reg.force_refresh("rsi_comms", Some(recorder.clone())).await.expect("second fetch");
+
+    let events = recorder.events.lock().expect("events");
+    let subjects: Vec<&str> = events.iter().map(|(s, _)| s.as_str()).collect();
+    assert_eq!(subjects, ["adapters.rsi_comms.data", "adapters.rsi_comms.delta"]);
+    let first: AdapterData = serde_json::from_slice(&events[0].1).expect("data");
+    assert_eq!(NewsBatch::from_adapter_data(&first).expect("batch").items.len(), 4);
+    let delta: DataDelta = serde_json::from_slice(&events[1].1).expect("delta");
+    assert!(delta.removed.is_empty() && delta.changed.is_empty());
+    assert_eq!(delta.added.len(), 1);
+    assert_eq!(delta.added[0].value["id"], "comm-link-20004");
+}
@@ -0,0 +1,102 @@
+use httpmock::{Method::GET, MockServer};
+use sc_manager_adapters::adapter_api::{AdapterData, DataAdapter};
+use sc_manager_adapters::erkul::{ComponentKind, ErkulClient, Grade, HttpErkulClient};
+
+fn serve_fixtures(server: &MockServer) {
+    for (path, body) in [
+        ("/live/weapons", include_str!("fixtures/erkul/weapons.json")),
+        ("/live/shields", include_str!("fixtures/erkul/shields.json")),
+        ("/live/power-plants", include_str!("fixtures/erkul/power-plants.json")),
+        ("/live/coolers", include_str!("fixtures/erkul/coolers.json")),
+        ("/live/qdrives", include_str!("fixtures/erkul/qdrives.json")),
+        ("/loadouts/GlAd1us", include_str!("fixtures/erkul/loadout_gladius.json")),
+    ] {
+        server.mock(|when, then| {
+            when.method(GET).path(path);
+            then.status(200).header("content-type", "application/json").body(body);
+        });
+    }
+}
+
+#[test]
+fn catalog_is_typed_from_fixtures() {
+    let server = MockServer::start();
+    serve_fixtures(&server);
+    let client = HttpErkulClient::new().with_base_url(server.base_url());
+
+    let catalog = client.fetch_catalog_blocking().expect("catalog");
+    assert_eq!(catalog.len(), 6);
+    assert_eq!(catalog.of_kind(ComponentKind::Weapon).count(), 2);
+
+    let shield = catalog.get("shld_godi_s02_fr66").expect("shield");
+    assert_eq!(shield.kind(), ComponentKind::Shield);
+    assert_eq!(shield.size, 2);
+    assert_eq!(shield.grade, Some(Grade::A));
+    assert_eq!(shield.manufacturer.as_deref(), Some("Gorgon Defender Industries"));
+    assert!(shield.to_equipment().read_only);
+}
+
+#[test]
+fn loadout_import_derives_dps_shield_and_power() {
+    let server = MockServer::start();
+    serve_fixtures(&server);
+    let client = HttpErkulClient::new().with_base_url(server.base_url());
+    let catalog = client.fetch_catalog_blocking().expect("catalog");
+
+    let loadout = client
+        .import_loadout_blocking("https://www.erkul.games/loadout/GlAd1us", &catalog)
+        .expect("import");
+    assert_eq!(loadout.ship, "AEGS_Gladius");
+    assert_eq!(loadout.slots.len(), 8);
+    assert_eq!(loadout.unresolved, vec!["MRCK_S02_BEHR_Dual".to_string()]);
+
+    let stats = loadout.stats();
+    assert!((stats.total_dps - (624.5 * 2.0 + 410.0)).abs() < 1e-9);
+    assert_eq!(stats.shield_hp, 5940.0);
+    assert_eq!(stats.power_output, 120.0);
+    // 2x9.5 + 3 + 18 + 2x6 + 12
+    assert_eq!(stats.power_draw, 64.0);
+    assert_eq!(stats.power_budget, 56.0);
+
+    // bare code works as well
+    let again = client.import_loadout_blocking("GlAd1us", &catalog).expect("import by code");
+    assert_eq!(again, loadout);
+}
+
+#[test]
+fn missing_loadout_is_an_error() {
+    let server = MockServer::start();
+    serve_fixtures(&server);
+    let client = HttpErkulClient::new().with_base_url(server.base_url());
+    let err = client.import_loadout_blocking("Unknown1", &Default::default()).unwrap_err();
+    assert!(err.contains("404"));
+}
+
+#[test]
+fn erkul_client_lists_the_catalog_as_equipment() {
+    let server = MockServer::start();
+    serve_fixtures(&server);
+    let client = HttpErkulClient::new().with_base_url(server.base_url());
+
+    let equipment = client.fetch_equipment(None, None).expect("equipment");
+    assert_eq!(equipment.len(), 6);
+    assert!(equipment.iter().all(|e| e.read_only));
+    assert!(equipment.iter().any(|e| e.id == "SHLD_GODI_S02_FR66"));
+    assert!(client.fetch_equipment(Some("org-1"), None).is_err());
+}
+
+#[tokio::test]
+async fn adapter_publishes_the_catalog() {
+    let server = MockServer::start_async().await;
+    serve_fixtures(&server);
+    let client = HttpErkulClient::new().with_base_url(server.base_url());
+    assert_eq!(client.hub_id(), Some("erkul"));
+
+    let AdapterData::Loadouts(v) = client.fetch().await.expect("fetch") else { panic!("unexpected variant") };
+    let components = v["components"].as_array().expect("components");
+    assert_eq!(components.len(), 6);
+    assert!(components.windows(2).all(|w| w[0]["id"].as_str() < w[1]["id"].as_str()));
+
+    let loadout = client.import_loadout("GlAd1us", &client.fetch_catalog().await.expect("catalog")).await.expect("import");
+    assert_eq!(loadout.ship, "AEGS_Gladius");
+}
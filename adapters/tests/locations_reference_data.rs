@@ -0,0 +1,112 @@
+use httpmock::{Method, MockServer};
+use sc_manager_adapters::locations::{GalactapediaAdapter, LocationIndex, LocationKind, LocationSnapshot, VerseGuideAdapter};
+use sc_manager_adapters::DataAdapter;
+use std::time::Duration;
+
+async fn galactapedia_snapshot(server: &MockServer) -> LocationSnapshot {
+    for (page, body) in [
+        ("1", include_str!("fixtures/locations/galactapedia_page1.json")),
+        ("2", include_str!("fixtures/locations/galactapedia_page2.json")),
+    ] {
+        server.mock(|when, then| {
+            when.method(Method::GET).path("/galactapedia/articles").query_param("type", "location").query_param("page", page);
+            then.status(200).header("content-type", "application/json").body(body);
+        });
+    }
+    let data = GalactapediaAdapter::new().with_base_url(server.base_url()).fetch().await.expect("fetch");
+    LocationSnapshot::from_adapter_data(&data).expect("snapshot")
+}
+
+async fn verseguide_snapshot(server: &MockServer) -> LocationSnapshot {
+    for (path, body) in [
+        ("/api/starmap/bootup", include_str!("fixtures/locations/verseguide_bootup.json")),
+        ("/api/starmap/star-systems/STANTON", include_str!("fixtures/locations/verseguide_stanton.json")),
+    ] {
+        server.mock(|when, then| {
+            when.method(Method::POST).path(path);
+            then.status(200).header("content-type", "application/json").body(body);
+        });
+    }
+    let data = VerseGuideAdapter::new()
+        .with_base_url(server.base_url())
+        .with_request_interval(Duration::ZERO)
+        .fetch()
+        .await
+        .expect("fetch");
+    LocationSnapshot::from_adapter_data(&data).expect("snapshot")
+}
+
+#[tokio::test]
+async fn galactapedia_articles_become_locations() {
+    let server = MockServer::start_async().await;
+    let snap = galactapedia_snapshot(&server).await;
+    assert_eq!(snap.source, "galactapedia");
+    // the organization article is skipped
+    assert_eq!(snap.locations.len(), 5);
+
+    let aberdeen = snap.locations.iter().find(|l| l.name == "Aberdeen").expect("aberdeen");
+    assert_eq!(aberdeen.id, "stanton/aberdeen");
+    assert_eq!(aberdeen.kind, LocationKind::Moon);
+    assert_eq!(aberdeen.parent_id.as_deref(), Some("stanton/hurston"));
+    let pyro = snap.locations.iter().find(|l| l.id == "pyro").expect("pyro");
+    assert_eq!(pyro.kind, LocationKind::System);
+}
+
+#[tokio::test]
+async fn verseguide_objects_become_locations() {
+    let server = MockServer::start_async().await;
+    let snap = verseguide_snapshot(&server).await;
+    let ids: Vec<&str> = snap.locations.iter().map(|l| l.id.as_str()).collect();
+    assert_eq!(ids, vec!["stanton", "stanton/hurston", "stanton/arial", "stanton/hur-l1-green-glade-station", "stanton/lorville"]);
+
+    let station = &snap.locations[3];
+    assert_eq!(station.kind, LocationKind::Station);
+    // the star is not a location; its children hang off the system
+    assert_eq!(station.parent_id.as_deref(), Some("stanton"));
+    assert_eq!(station.aliases, vec!["HUR-L1".to_string()]);
+    assert_eq!(snap.locations[4].kind, LocationKind::Outpost);
+    assert_eq!(snap.locations[4].parent_id.as_deref(), Some("stanton/hurston"));
+}
+
+#[tokio::test]
+async fn verseguide_ids_stay_unique_when_names_collide() {
+    let server = MockServer::start_async().await;
+    let system = r#"{"success":1,"data":{"resultset":[{"code":"CRUSADER","name":"Crusader","celestial_objects":[
+        {"id":"1","type":"PLANET","name":"Crusader"},
+        {"id":"2","type":"SATELLITE","name":"Cellin","parent_id":"1"},
+        {"id":"3","type":"LZ","name":"Orison","parent_id":"1"},
+        {"id":"4","type":"POI","name":"Orison","parent_id":"2"}
+    ]}]}}"#;
+    for (path, body) in [
+        ("/api/starmap/bootup", r#"{"success":1,"data":{"systems":{"resultset":[{"code":"CRUSADER","name":"Crusader"}]}}}"#),
+        ("/api/starmap/star-systems/CRUSADER", system),
+    ] {
+        server.mock(|when, then| {
+            when.method(Method::POST).path(path);
+            then.status(200).header("content-type", "application/json").body(body);
+        });
+    }
+    let adapter = VerseGuideAdapter::new().with_base_url(server.base_url()).with_request_interval(Duration::ZERO);
+    let snap = LocationSnapshot::from_adapter_data(&adapter.fetch().await.expect("fetch")).expect("snapshot");
+
+    let ids: Vec<&str> = snap.locations.iter().map(|l| l.id.as_str()).collect();
+    assert_eq!(ids, vec!["crusader", "crusader/crusader", "crusader/cellin", "crusader/orison", "crusader/cellin/orison"]);
+    assert_eq!(snap.locations[4].parent_id.as_deref(), Some("crusader/cellin"));
+}
+
+#[tokio::test]
+async fn sources_merge_into_one_index() {
+    let server = MockServer::start_async().await;
+    let a = galactapedia_snapshot(&server).await;
+    let b = verseguide_snapshot(&server).await;
+    let idx = LocationIndex::from_snapshots([&a, &b]);
+
+    assert_eq!(idx.len(), 7);
+    let hurston = idx.resolve("stanton i").expect("designation resolves");
+    assert_eq!(hurston.id, "stanton/hurston");
+    assert_eq!(hurston.summary.as_deref(), Some("Home of Hurston Dynamics."));
+    assert_eq!(idx.resolve("HUR-L1").map(|l| l.name.as_str()), Some("HUR-L1 Green Glade Station"));
+    assert_eq!(idx.resolve("STANTON").map(|l| l.kind), Some(LocationKind::System));
+    assert_eq!(idx.children("stanton/hurston").count(), 3);
+    assert!(idx.resolve("Nowhere").is_none());
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use httpmock::{Method::GET, MockServer};
+use sc_manager_adapters::rsi::{HttpRsiClient, RsiClient};
+
+fn serve<'a>(server: &'a MockServer, handle: &str, body: &'static str) -> httpmock::Mock<'a> {
+    let path = format!("/citizens/{}", handle);
+    server.mock(move |when, then| {
+        when.method(GET).path(path);
+        then.status(200).header("content-type", "text/html").body(body);
+    })
+}
+
+#[test]
+fn parses_profile_with_main_org() {
+    let server = MockServer::start();
+    serve(&server, "Alice_SF", include_str!("fixtures/rsi/citizen_alice.html"));
+    let client = HttpRsiClient::new().with_base_url(server.base_url());
+
+    let p = client.fetch_profile("Alice_SF").expect("fetch").expect("profile");
+    assert_eq!(p.handle, "Alice_SF");
+    assert_eq!(p.display_name, "Alice Starfarer");
+    assert_eq!(p.enlisted, NaiveDate::from_ymd_opt(2013, 11, 13));
+    let org = p.main_org.expect("main org");
+    assert_eq!(org.sid, "TESTSQ");
+    assert_eq!(org.name, "Test Squadron - Best Squadron");
+    assert_eq!(org.rank.as_deref(), Some("Captain"));
+}
+
+#[test]
+fn redacted_org_is_omitted() {
+    let server = MockServer::start();
+    serve(&server, "bob", include_str!("fixtures/rsi/citizen_bob_redacted.html"));
+    let client = HttpRsiClient::new().with_base_url(server.base_url());
+
+    let p = client.fetch_profile("bob").expect("fetch").expect("profile");
+    assert_eq!(p.display_name, "bob");
+    assert_eq!(p.enlisted, NaiveDate::from_ymd_opt(2021, 1, 2));
+    assert!(p.main_org.is_none());
+}
+
+#[test]
+fn unknown_handles_and_errors() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/citizens/ghost");
+        then.status(404).body("<html><body>404 - Not found</body></html>");
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/citizens/flaky");
+        then.status(503);
+    });
+    let client = HttpRsiClient::new().with_base_url(server.base_url());
+
+    assert!(!client.verify_handle("ghost").expect("verify"));
+    assert!(!client.verify_handle("../etc").expect("invalid handles are not found"));
+    assert!(client.verify_handle("flaky").unwrap_err().contains("503"));
+}
+
+#[test]
+fn results_are_cached_for_ttl() {
+    let server = MockServer::start();
+    let page = serve(&server, "Alice_SF", include_str!("fixtures/rsi/citizen_alice.html"));
+
+    let cached = HttpRsiClient::new().with_base_url(server.base_url());
+    assert!(cached.verify_handle("Alice_SF").expect("verify"));
+    assert!(cached.verify_handle("alice_sf").expect("verify"));
+    assert_eq!(page.hits(), 1);
+
+    let uncached = HttpRsiClient::new().with_base_url(server.base_url()).with_cache_ttl(Duration::ZERO);
+    uncached.verify_handle("Alice_SF").expect("verify");
+    uncached.verify_handle("Alice_SF").expect("verify");
+    assert_eq!(page.hits(), 3);
+}
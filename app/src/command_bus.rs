@@ -0,0 +1,387 @@
+//! Command bus: commands declare the permission and resource they need, and a
+//! middleware pipeline handles authorization, validation, idempotency and
+//! signed-event emission around the handler.
+
+use crate::services::game_event_ingestor::DedupeWindow;
+use crate::services::{PermissionCache, PolicyContext, PolicyService};
+use sc_manager_core::domain::ResourceHierarchy;
+use sc_manager_core::events::{sign_event, DomainEventPayload, EventEnvelope, KeyPair, SignedEvent};
+use sc_manager_core::repositories::{MemberRepository, PermissionRepository, RepositoryError, RoleRepository};
+use serde::Serialize;
+
+/// Default number of idempotency keys remembered by `IdempotencyMiddleware`.
+pub const DEFAULT_IDEMPOTENCY_WINDOW: usize = 10_000;
+
+/// Payload of the domain event emitted for a command; implemented for every `Serialize` type.
+pub trait EventPayload {
+    fn event_payload(&self) -> serde_json::Value;
+}
+
+impl<T: Serialize> EventPayload for T {
+    fn event_payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// A command dispatched through the `CommandBus`.
+pub trait Command: EventPayload {
+    /// Permission the actor needs, checked by `AuthorizationMiddleware`.
+    fn required_permission(&self) -> &'static str;
+
+    /// Stable command name, used as the kind of the emitted event; defaults to the permission id.
+    fn name(&self) -> &'static str {
+        self.required_permission()
+    }
+
+    /// Resource the permission is checked on; `None` requires a global grant.
+    fn resource_id(&self) -> Option<&str> {
+        None
+    }
+
+    /// Input validation run by `ValidationMiddleware`.
+    fn validate(&self) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
+
+/// Who dispatches a command. The optional idempotency key is supplied by the caller (e.g. a
+/// request id), so retries of the same request are skipped while identical commands sent as
+/// separate requests still run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Caller<'c> {
+    pub actor: &'c str,
+    pub idempotency_key: Option<&'c str>,
+}
+
+impl<'c> Caller<'c> {
+    pub fn new(actor: &'c str) -> Self {
+        Self {
+            actor,
+            idempotency_key: None,
+        }
+    }
+
+    pub fn with_idempotency_key(mut self, key: &'c str) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+}
+
+impl<'c> From<&'c str> for Caller<'c> {
+    fn from(actor: &'c str) -> Self {
+        Self::new(actor)
+    }
+}
+
+/// Repositories consulted by middleware; handed back to the handler so commands may mutate them.
+pub struct PolicyRepos<'a, M: MemberRepository, R: RoleRepository, P: PermissionRepository> {
+    pub members: &'a mut M,
+    pub roles: &'a mut R,
+    pub permissions: &'a mut P,
+    /// Lets grants on a parent resource cover the command's resource (see `PolicyContext::hierarchy`).
+    pub hierarchy: Option<&'a dyn ResourceHierarchy>,
+}
+
+impl<'a, M: MemberRepository, R: RoleRepository, P: PermissionRepository> PolicyRepos<'a, M, R, P> {
+    pub fn new(members: &'a mut M, roles: &'a mut R, permissions: &'a mut P) -> Self {
+        Self {
+            members,
+            roles,
+            permissions,
+            hierarchy: None,
+        }
+    }
+
+    pub fn with_hierarchy(mut self, hierarchy: &'a dyn ResourceHierarchy) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
+    }
+}
+
+/// Whether the pipeline should go on to run the handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    /// Command was already handled; the handler is not run.
+    Skip,
+}
+
+/// Domain events raised by a handler, forwarded to every middleware via `Middleware::on_event`.
+pub trait RaisedEvents {
+    fn events(&self) -> &[EventEnvelope];
+}
+
+impl RaisedEvents for () {
+    fn events(&self) -> &[EventEnvelope] {
+        &[]
+    }
+}
+
+impl RaisedEvents for EventEnvelope {
+    fn events(&self) -> &[EventEnvelope] {
+        std::slice::from_ref(self)
+    }
+}
+
+impl RaisedEvents for Option<EventEnvelope> {
+    fn events(&self) -> &[EventEnvelope] {
+        self.as_slice()
+    }
+}
+
+impl RaisedEvents for Vec<EventEnvelope> {
+    fn events(&self) -> &[EventEnvelope] {
+        self.as_slice()
+    }
+}
+
+/// Result of a dispatch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dispatched<T> {
+    Executed(T),
+    Duplicate,
+}
+
+/// A pipeline stage. `before` runs in registration order ahead of the handler and may abort
+/// with an error or skip the handler; `after` runs only when the handler succeeded, followed by
+/// `on_event` for each domain event the handler raised or that was passed to `CommandBus::publish`.
+pub trait Middleware<M: MemberRepository, R: RoleRepository, P: PermissionRepository> {
+    fn before(&mut self, _caller: &Caller<'_>, _cmd: &dyn Command, _repos: &PolicyRepos<'_, M, R, P>) -> Result<Flow, RepositoryError> {
+        Ok(Flow::Continue)
+    }
+
+    fn after(&mut self, _caller: &Caller<'_>, _cmd: &dyn Command) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    fn on_event(&mut self, _event: &EventEnvelope) {}
+}
+
+pub struct CommandBus<'m, M: MemberRepository, R: RoleRepository, P: PermissionRepository> {
+    middleware: Vec<Box<dyn Middleware<M, R, P> + 'm>>,
+}
+
+impl<'m, M: MemberRepository, R: RoleRepository, P: PermissionRepository> CommandBus<'m, M, R, P> {
+    /// Empty pipeline; see `with_defaults` for the standard stages.
+    pub fn new() -> Self {
+        Self { middleware: vec![] }
+    }
+
+    /// Validation, authorization and idempotency, in that order.
+    pub fn with_defaults() -> Self {
+        Self::new()
+            .with(ValidationMiddleware)
+            .with(AuthorizationMiddleware::new())
+            .with(IdempotencyMiddleware::new())
+    }
+
+    pub fn with(mut self, middleware: impl Middleware<M, R, P> + 'm) -> Self {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
+    /// Runs `cmd` through the pipeline and, unless a stage aborts or skips it, through `handler`.
+    pub fn dispatch<'c, C: Command, T: RaisedEvents>(
+        &mut self,
+        caller: impl Into<Caller<'c>>,
+        cmd: C,
+        repos: &mut PolicyRepos<'_, M, R, P>,
+        handler: impl FnOnce(&C, &mut PolicyRepos<'_, M, R, P>) -> Result<T, RepositoryError>,
+    ) -> Result<Dispatched<T>, RepositoryError> {
+        let caller = caller.into();
+        for mw in self.middleware.iter_mut() {
+            if mw.before(&caller, &cmd, repos)? == Flow::Skip {
+                return Ok(Dispatched::Duplicate);
+            }
+        }
+        let out = handler(&cmd, repos)?;
+        for mw in self.middleware.iter_mut() {
+            mw.after(&caller, &cmd)?;
+        }
+        for ev in out.events() {
+            self.publish(ev);
+        }
+        Ok(Dispatched::Executed(out))
+    }
+
+    /// Hands a domain event raised outside a dispatch (e.g. by `RoleAssignmentSweeper`) to every stage.
+    pub fn publish(&mut self, event: &EventEnvelope) {
+        for mw in self.middleware.iter_mut() {
+            mw.on_event(event);
+        }
+    }
+}
+
+impl<M: MemberRepository, R: RoleRepository, P: PermissionRepository> Default for CommandBus<'_, M, R, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rejects commands whose `validate` fails.
+pub struct ValidationMiddleware;
+
+impl<M: MemberRepository, R: RoleRepository, P: PermissionRepository> Middleware<M, R, P> for ValidationMiddleware {
+    fn before(&mut self, _caller: &Caller<'_>, cmd: &dyn Command, _repos: &PolicyRepos<'_, M, R, P>) -> Result<Flow, RepositoryError> {
+        cmd.validate()?;
+        Ok(Flow::Continue)
+    }
+}
+
+/// Checks the command's required permission on its resource via `PolicyService`, or through
+/// a `PermissionCache` kept current by the role events flowing over the bus.
+pub struct AuthorizationMiddleware {
+    /// Fixed evaluation time; `None` evaluates at the current time.
+    pub now: Option<i64>,
+    cache: Option<PermissionCache>,
+}
+
+impl AuthorizationMiddleware {
+    pub fn new() -> Self {
+        Self { now: None, cache: None }
+    }
+
+    pub fn at(now: i64) -> Self {
+        Self {
+            now: Some(now),
+            cache: None,
+        }
+    }
+
+    pub fn with_cache(mut self, cache: PermissionCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+}
+
+impl Default for AuthorizationMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: MemberRepository, R: RoleRepository, P: PermissionRepository> Middleware<M, R, P> for AuthorizationMiddleware {
+    fn before(&mut self, caller: &Caller<'_>, cmd: &dyn Command, repos: &PolicyRepos<'_, M, R, P>) -> Result<Flow, RepositoryError> {
+        let mut ctx = self.now.map(PolicyContext::at).unwrap_or_else(PolicyContext::current);
+        if let Some(h) = repos.hierarchy {
+            ctx = ctx.with_hierarchy(h);
+        }
+        let (members, roles, permissions) = (&*repos.members, &*repos.roles, &*repos.permissions);
+        let (permission, resource) = (cmd.required_permission(), cmd.resource_id());
+        let allowed = match self.cache.as_mut() {
+            Some(cache) => cache.check_permission_in(&ctx, caller.actor, permission, resource, members, roles, permissions)?,
+            None => PolicyService::check_permission_in(&ctx, caller.actor, permission, resource, members, roles, permissions)?,
+        };
+        if !allowed {
+            return Err(RepositoryError::Unauthorized);
+        }
+        Ok(Flow::Continue)
+    }
+
+    fn on_event(&mut self, event: &EventEnvelope) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.handle_event(event);
+        }
+    }
+}
+
+/// Skips commands whose caller-supplied idempotency key already completed successfully.
+/// Keys are scoped to the actor and command name; only the most recent `capacity` keys are kept.
+pub struct IdempotencyMiddleware {
+    window: DedupeWindow,
+}
+
+impl IdempotencyMiddleware {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_IDEMPOTENCY_WINDOW)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            window: DedupeWindow::new(capacity),
+        }
+    }
+
+    fn scoped_key(caller: &Caller<'_>, cmd: &dyn Command) -> Option<String> {
+        caller
+            .idempotency_key
+            .map(|key| format!("{}:{}:{}", caller.actor, cmd.name(), key))
+    }
+}
+
+impl Default for IdempotencyMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: MemberRepository, R: RoleRepository, P: PermissionRepository> Middleware<M, R, P> for IdempotencyMiddleware {
+    fn before(&mut self, caller: &Caller<'_>, cmd: &dyn Command, _repos: &PolicyRepos<'_, M, R, P>) -> Result<Flow, RepositoryError> {
+        match Self::scoped_key(caller, cmd) {
+            Some(key) if self.window.contains(&key) => Ok(Flow::Skip),
+            _ => Ok(Flow::Continue),
+        }
+    }
+
+    fn after(&mut self, caller: &Caller<'_>, cmd: &dyn Command) -> Result<(), RepositoryError> {
+        if let Some(key) = Self::scoped_key(caller, cmd) {
+            self.window.insert(&key);
+        }
+        Ok(())
+    }
+}
+
+/// Destination for signed events emitted by `SigningMiddleware`.
+pub trait EventSink {
+    fn emit(&mut self, event: SignedEvent) -> Result<(), RepositoryError>;
+}
+
+impl EventSink for Vec<SignedEvent> {
+    fn emit(&mut self, event: SignedEvent) -> Result<(), RepositoryError> {
+        self.push(event);
+        Ok(())
+    }
+}
+
+impl<S: EventSink + ?Sized> EventSink for &mut S {
+    fn emit(&mut self, event: SignedEvent) -> Result<(), RepositoryError> {
+        (**self).emit(event)
+    }
+}
+
+/// Signs a domain event for every successfully handled command and hands it to `sink`.
+pub struct SigningMiddleware<'k, S: EventSink> {
+    keypair: &'k KeyPair,
+    sink: S,
+    seq: u64,
+}
+
+impl<'k, S: EventSink> SigningMiddleware<'k, S> {
+    pub fn new(keypair: &'k KeyPair, sink: S) -> Self {
+        Self { keypair, sink, seq: 0 }
+    }
+}
+
+impl<M: MemberRepository, R: RoleRepository, P: PermissionRepository, S: EventSink> Middleware<M, R, P> for SigningMiddleware<'_, S> {
+    fn after(&mut self, caller: &Caller<'_>, cmd: &dyn Command) -> Result<(), RepositoryError> {
+        self.seq += 1;
+        let id = caller.idempotency_key.map(|key| format!("{}:{}", cmd.name(), key)).unwrap_or_else(|| {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos())
+                .unwrap_or_default();
+            format!("cmd-{}-{}", nanos, self.seq)
+        });
+        let ev = DomainEventPayload {
+            id,
+            kind: cmd.name().to_string(),
+            payload: serde_json::json!({
+                "actor": caller.actor,
+                "resource_id": cmd.resource_id(),
+                "command": cmd.event_payload(),
+            }),
+        };
+        let signed = sign_event(self.keypair, &ev).map_err(|_| RepositoryError::Internal)?;
+        self.sink.emit(signed)
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+/// Attaches an already recorded event to the active session.
+#[derive(Debug, Clone, Serialize)]
+pub struct AddSessionEventCommand {
+    pub event_id: String,
+    pub org_id: Option<String>,
+}
+
+impl AddSessionEventCommand {
+    pub fn new(event_id: impl Into<String>) -> Self {
+        Self {
+            event_id: event_id.into(),
+            org_id: None,
+        }
+    }
+
+    pub fn with_org(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+}
+
+impl crate::command_bus::Command for AddSessionEventCommand {
+    fn name(&self) -> &'static str {
+        "session.add_event"
+    }
+
+    fn required_permission(&self) -> &'static str {
+        "event.create"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.org_id.as_deref()
+    }
+}
@@ -1,13 +1,26 @@
-pub struct AssignPermissionToRoleCommand {
-    pub role_id: String,
-    pub permission_id: String,
-}
-
-impl AssignPermissionToRoleCommand {
-    pub fn new(role_id: impl Into<String>, permission_id: impl Into<String>) -> Self {
-        Self {
-            role_id: role_id.into(),
-            permission_id: permission_id.into(),
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssignPermissionToRoleCommand {
+    pub role_id: String,
+    pub permission_id: String,
+}
+
+impl AssignPermissionToRoleCommand {
+    pub fn new(role_id: impl Into<String>, permission_id: impl Into<String>) -> Self {
+        Self {
+            role_id: role_id.into(),
+            permission_id: permission_id.into(),
+        }
+    }
+}
+
+impl crate::command_bus::Command for AssignPermissionToRoleCommand {
+    fn name(&self) -> &'static str {
+        "role.assign_permission"
+    }
+
+    fn required_permission(&self) -> &'static str {
+        "role.update"
+    }
+}
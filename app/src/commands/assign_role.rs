@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssignRoleCommand {
+    pub member_id: String,
+    pub role_id: String,
+    /// Resource the role is scoped to; `None` assigns it globally
+    pub scope: Option<String>,
+}
+
+impl AssignRoleCommand {
+    pub fn new(member_id: impl Into<String>, role_id: impl Into<String>, scope: Option<String>) -> Self {
+        Self {
+            member_id: member_id.into(),
+            role_id: role_id.into(),
+            scope,
+        }
+    }
+}
+
+impl crate::command_bus::Command for AssignRoleCommand {
+    fn required_permission(&self) -> &'static str {
+        "member.assign_role"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.member_id.trim().is_empty() || self.role_id.trim().is_empty() {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
@@ -1,22 +1,42 @@
-pub struct CreateEventCommand {
-    pub id: String,
-    pub title: String,
-    pub timestamp: i64,
-    pub org_id: Option<String>,
-}
-
-impl CreateEventCommand {
-    pub fn new(
-        id: impl Into<String>,
-        title: impl Into<String>,
-        timestamp: i64,
-        org_id: Option<String>,
-    ) -> Self {
-        Self {
-            id: id.into(),
-            title: title.into(),
-            timestamp,
-            org_id,
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateEventCommand {
+    pub id: String,
+    pub title: String,
+    pub timestamp: i64,
+    pub org_id: Option<String>,
+}
+
+impl CreateEventCommand {
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        timestamp: i64,
+        org_id: Option<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            timestamp,
+            org_id,
+        }
+    }
+}
+
+impl crate::command_bus::Command for CreateEventCommand {
+    fn required_permission(&self) -> &'static str {
+        "event.create"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.org_id.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.title.trim().is_empty() || self.timestamp < 0 {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
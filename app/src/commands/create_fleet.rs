@@ -1,13 +1,40 @@
-pub struct CreateFleetCommand {
-    pub id: String,
-    pub name: String,
-}
-
-impl CreateFleetCommand {
-    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
-        Self {
-            id: id.into(),
-            name: name.into(),
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateFleetCommand {
+    pub id: String,
+    pub name: String,
+    pub org_id: Option<String>,
+}
+
+impl CreateFleetCommand {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            org_id: None,
+        }
+    }
+
+    pub fn with_org(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+}
+
+impl crate::command_bus::Command for CreateFleetCommand {
+    fn resource_id(&self) -> Option<&str> {
+        self.org_id.as_deref()
+    }
+
+    fn required_permission(&self) -> &'static str {
+        "fleet.create"
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.name.trim().is_empty() {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
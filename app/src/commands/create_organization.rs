@@ -1,13 +1,29 @@
-pub struct CreateOrganizationCommand {
-    pub id: String,
-    pub name: String,
-}
-
-impl CreateOrganizationCommand {
-    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
-        Self {
-            id: id.into(),
-            name: name.into(),
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateOrganizationCommand {
+    pub id: String,
+    pub name: String,
+}
+
+impl CreateOrganizationCommand {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl crate::command_bus::Command for CreateOrganizationCommand {
+    fn required_permission(&self) -> &'static str {
+        "org.create"
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.name.trim().is_empty() {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
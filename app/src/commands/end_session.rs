@@ -1,13 +1,46 @@
-pub struct EndSessionCommand {
-    pub id: String,
-    pub end_ts: i64,
-}
-
-impl EndSessionCommand {
-    pub fn new(id: impl Into<String>, end_ts: i64) -> Self {
-        Self {
-            id: id.into(),
-            end_ts,
-        }
-    }
-}
+use sc_manager_core::domain::Session;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EndSessionCommand {
+    pub id: String,
+    pub end_ts: i64,
+    /// Organization of the session, which `session.end` is checked on
+    pub org_id: Option<String>,
+}
+
+impl EndSessionCommand {
+    pub fn new(id: impl Into<String>, end_ts: i64) -> Self {
+        Self {
+            id: id.into(),
+            end_ts,
+            org_id: None,
+        }
+    }
+
+    /// Ends `session`, scoping the permission check to its organization.
+    pub fn for_session(session: &Session, end_ts: i64) -> Self {
+        Self {
+            id: session.id.clone(),
+            end_ts,
+            org_id: session.org_id.clone(),
+        }
+    }
+}
+
+impl crate::command_bus::Command for EndSessionCommand {
+    fn required_permission(&self) -> &'static str {
+        "session.end"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.org_id.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.end_ts < 0 {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
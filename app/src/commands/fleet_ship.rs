@@ -0,0 +1,59 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AddShipToFleetCommand {
+    pub fleet_id: String,
+    pub ship_id: String,
+}
+
+impl AddShipToFleetCommand {
+    pub fn new(fleet_id: impl Into<String>, ship_id: impl Into<String>) -> Self {
+        Self {
+            fleet_id: fleet_id.into(),
+            ship_id: ship_id.into(),
+        }
+    }
+}
+
+impl crate::command_bus::Command for AddShipToFleetCommand {
+    fn name(&self) -> &'static str {
+        "fleet.add_ship"
+    }
+
+    fn required_permission(&self) -> &'static str {
+        "fleet.update"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        Some(&self.fleet_id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoveShipFromFleetCommand {
+    pub fleet_id: String,
+    pub ship_id: String,
+}
+
+impl RemoveShipFromFleetCommand {
+    pub fn new(fleet_id: impl Into<String>, ship_id: impl Into<String>) -> Self {
+        Self {
+            fleet_id: fleet_id.into(),
+            ship_id: ship_id.into(),
+        }
+    }
+}
+
+impl crate::command_bus::Command for RemoveShipFromFleetCommand {
+    fn name(&self) -> &'static str {
+        "fleet.remove_ship"
+    }
+
+    fn required_permission(&self) -> &'static str {
+        "fleet.update"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        Some(&self.fleet_id)
+    }
+}
@@ -0,0 +1,44 @@
+use sc_manager_core::domain::RoleAssignment;
+use serde::Serialize;
+
+/// Grants a prepared (time-bounded or conditional) role assignment to a member.
+#[derive(Debug, Clone, Serialize)]
+pub struct GrantRoleCommand {
+    pub member_id: String,
+    pub assignment: RoleAssignment,
+}
+
+impl GrantRoleCommand {
+    pub fn new(member_id: impl Into<String>, assignment: RoleAssignment) -> Self {
+        Self {
+            member_id: member_id.into(),
+            assignment,
+        }
+    }
+}
+
+impl crate::command_bus::Command for GrantRoleCommand {
+    fn name(&self) -> &'static str {
+        "member.grant_role"
+    }
+
+    fn required_permission(&self) -> &'static str {
+        "member.assign_role"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.assignment.resource_id.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.member_id.trim().is_empty() || self.assignment.role_id.trim().is_empty() {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        if let (Some(from), Some(until)) = (self.assignment.valid_from, self.assignment.valid_until) {
+            if until <= from {
+                return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,27 +1,37 @@
-pub mod add_member;
-pub mod assign_permission;
-pub mod create_event;
-pub mod create_fleet;
-pub mod create_organization;
-pub mod create_permission;
-pub mod create_role;
-pub mod register_equipment;
-pub mod register_ship;
-pub mod remove_member;
-pub mod update_member;
-
-pub use self::add_member::AddMemberCommand;
-pub use self::assign_permission::AssignPermissionToRoleCommand;
-pub use self::create_event::CreateEventCommand;
-pub use self::create_fleet::CreateFleetCommand;
-pub use self::create_organization::CreateOrganizationCommand;
-pub use self::create_permission::CreatePermissionCommand;
-pub use self::create_role::CreateRoleCommand;
-pub use self::register_equipment::RegisterEquipmentCommand;
-pub use self::register_ship::RegisterShipCommand;
-pub use self::remove_member::RemoveMemberCommand;
-pub use self::update_member::UpdateMemberCommand;
-pub mod end_session;
-pub mod start_session;
-pub use self::end_session::EndSessionCommand;
-pub use self::start_session::StartSessionCommand;
+pub mod add_member;
+pub mod add_session_event;
+pub mod assign_permission;
+pub mod assign_role;
+pub mod create_event;
+pub mod create_fleet;
+pub mod create_organization;
+pub mod create_permission;
+pub mod create_role;
+pub mod fleet_ship;
+pub mod grant_role;
+pub mod register_equipment;
+pub mod register_ship;
+pub mod remove_member;
+pub mod remove_ship;
+pub mod update_member;
+
+pub use self::add_member::AddMemberCommand;
+pub use self::add_session_event::AddSessionEventCommand;
+pub use self::assign_permission::AssignPermissionToRoleCommand;
+pub use self::assign_role::AssignRoleCommand;
+pub use self::create_event::CreateEventCommand;
+pub use self::create_fleet::CreateFleetCommand;
+pub use self::create_organization::CreateOrganizationCommand;
+pub use self::create_permission::CreatePermissionCommand;
+pub use self::create_role::CreateRoleCommand;
+pub use self::fleet_ship::{AddShipToFleetCommand, RemoveShipFromFleetCommand};
+pub use self::grant_role::GrantRoleCommand;
+pub use self::register_equipment::RegisterEquipmentCommand;
+pub use self::register_ship::RegisterShipCommand;
+pub use self::remove_member::RemoveMemberCommand;
+pub use self::remove_ship::RemoveShipCommand;
+pub use self::update_member::UpdateMemberCommand;
+pub mod end_session;
+pub mod start_session;
+pub use self::end_session::EndSessionCommand;
+pub use self::start_session::StartSessionCommand;
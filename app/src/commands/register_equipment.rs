@@ -1,15 +1,31 @@
-pub struct RegisterEquipmentCommand {
-    pub id: String,
-    pub name: String,
-    pub read_only: bool,
-}
-
-impl RegisterEquipmentCommand {
-    pub fn new(id: impl Into<String>, name: impl Into<String>, read_only: bool) -> Self {
-        Self {
-            id: id.into(),
-            name: name.into(),
-            read_only,
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegisterEquipmentCommand {
+    pub id: String,
+    pub name: String,
+    pub read_only: bool,
+}
+
+impl RegisterEquipmentCommand {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, read_only: bool) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            read_only,
+        }
+    }
+}
+
+impl crate::command_bus::Command for RegisterEquipmentCommand {
+    fn required_permission(&self) -> &'static str {
+        "equipment.register"
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.name.trim().is_empty() {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
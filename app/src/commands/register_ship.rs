@@ -1,15 +1,35 @@
-pub struct RegisterShipCommand {
-    pub id: String,
-    pub model: String,
-    pub owner_org: Option<String>,
-}
-
-impl RegisterShipCommand {
-    pub fn new(id: impl Into<String>, model: impl Into<String>, owner_org: Option<String>) -> Self {
-        Self {
-            id: id.into(),
-            model: model.into(),
-            owner_org,
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegisterShipCommand {
+    pub id: String,
+    pub model: String,
+    pub owner_org: Option<String>,
+}
+
+impl RegisterShipCommand {
+    pub fn new(id: impl Into<String>, model: impl Into<String>, owner_org: Option<String>) -> Self {
+        Self {
+            id: id.into(),
+            model: model.into(),
+            owner_org,
+        }
+    }
+}
+
+impl crate::command_bus::Command for RegisterShipCommand {
+    fn required_permission(&self) -> &'static str {
+        "ship.register"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.owner_org.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.model.trim().is_empty() {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoveShipCommand {
+    pub id: String,
+}
+
+impl RemoveShipCommand {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+impl crate::command_bus::Command for RemoveShipCommand {
+    fn required_permission(&self) -> &'static str {
+        "ship.remove"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
@@ -1,15 +1,42 @@
-pub struct StartSessionCommand {
-    pub id: String,
-    pub start_ts: i64,
-    pub org_id: Option<String>,
-}
-
-impl StartSessionCommand {
-    pub fn new(id: impl Into<String>, start_ts: i64, org_id: Option<String>) -> Self {
-        Self {
-            id: id.into(),
-            start_ts,
-            org_id,
-        }
-    }
-}
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StartSessionCommand {
+    pub id: String,
+    pub start_ts: i64,
+    pub org_id: Option<String>,
+    pub participant: Option<String>,
+}
+
+impl StartSessionCommand {
+    pub fn new(id: impl Into<String>, start_ts: i64, org_id: Option<String>) -> Self {
+        Self {
+            id: id.into(),
+            start_ts,
+            org_id,
+            participant: None,
+        }
+    }
+
+    pub fn with_participant(mut self, participant: impl Into<String>) -> Self {
+        self.participant = Some(participant.into());
+        self
+    }
+}
+
+impl crate::command_bus::Command for StartSessionCommand {
+    fn required_permission(&self) -> &'static str {
+        "session.start"
+    }
+
+    fn resource_id(&self) -> Option<&str> {
+        self.org_id.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), sc_manager_core::repositories::RepositoryError> {
+        if self.id.trim().is_empty() || self.start_ts < 0 {
+            return Err(sc_manager_core::repositories::RepositoryError::InvalidInput);
+        }
+        Ok(())
+    }
+}
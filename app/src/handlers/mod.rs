@@ -1,17 +1,19 @@
-pub mod equipment_handler;
-pub mod event_handler;
-pub mod fleet_handler;
-pub mod member_handler;
-pub mod organization_handler;
-pub mod role_handler;
-pub mod session_handler;
-pub mod ship_handler;
-
-pub use self::equipment_handler::EquipmentHandler;
-pub use self::event_handler::EventHandler;
-pub use self::fleet_handler::FleetHandler;
-pub use self::member_handler::MemberHandler;
-pub use self::organization_handler::CreateOrganizationHandler;
-pub use self::role_handler::RoleHandler;
-pub use self::session_handler::SessionHandler;
-pub use self::ship_handler::ShipHandler;
+pub mod equipment_handler;
+pub mod event_handler;
+pub mod fleet_handler;
+pub mod member_handler;
+pub mod organization_handler;
+pub mod query_handler;
+pub mod role_handler;
+pub mod session_handler;
+pub mod ship_handler;
+
+pub use self::equipment_handler::EquipmentHandler;
+pub use self::event_handler::EventHandler;
+pub use self::fleet_handler::FleetHandler;
+pub use self::member_handler::MemberHandler;
+pub use self::organization_handler::CreateOrganizationHandler;
+pub use self::query_handler::QueryHandler;
+pub use self::role_handler::RoleHandler;
+pub use self::session_handler::SessionHandler;
+pub use self::ship_handler::ShipHandler;
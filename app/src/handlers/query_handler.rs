@@ -0,0 +1,178 @@
+use crate::queries::{
+    FleetDetail, GetActivityReportQuery, GetFleetDetailQuery, GetMemberProfileQuery, GetOrganizationQuery,
+    GetSessionHistoryQuery, MemberCounts, MemberProfile, OrgOverview, SessionSummary, ShipSummary,
+};
+use crate::services::{ActivityReport, ActivityReportService, PolicyContext, PolicyService};
+use sc_manager_core::repositories::{
+    FleetRepository, MemberRepository, OrganizationRepository, PermissionRepository, RepositoryError, RoleRepository,
+    SessionRepository, ShipRepository,
+};
+
+/// Read side: answers queries with DTOs after authorizing the actor through `PolicyService`.
+pub struct QueryHandler<'a, M: MemberRepository + 'a, R: RoleRepository + 'a, P: PermissionRepository + 'a> {
+    pub member_repo: &'a M,
+    pub role_repo: &'a R,
+    pub perm_repo: &'a P,
+    ctx: PolicyContext<'a>,
+}
+
+impl<'a, M: MemberRepository, R: RoleRepository, P: PermissionRepository> QueryHandler<'a, M, R, P> {
+    pub fn new(member_repo: &'a M, role_repo: &'a R, perm_repo: &'a P) -> Self {
+        Self {
+            member_repo,
+            role_repo,
+            perm_repo,
+            ctx: PolicyContext::current(),
+        }
+    }
+
+    /// Evaluate permissions in `ctx` (e.g. with a resource hierarchy) instead of the current time only.
+    pub fn with_policy_context(mut self, ctx: PolicyContext<'a>) -> Self {
+        self.ctx = ctx;
+        self
+    }
+
+    fn authorize(&self, actor: &str, permission_id: &str, resource_id: Option<&str>) -> Result<(), RepositoryError> {
+        let allowed = PolicyService::check_permission_in(
+            &self.ctx,
+            actor,
+            permission_id,
+            resource_id,
+            self.member_repo,
+            self.role_repo,
+            self.perm_repo,
+        )?;
+        if !allowed {
+            return Err(RepositoryError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub fn org_overview<O: OrganizationRepository, F: FleetRepository, S: ShipRepository>(
+        &self,
+        actor: &str,
+        query: GetOrganizationQuery,
+        org_repo: &O,
+        fleet_repo: &F,
+        ship_repo: &S,
+    ) -> Result<OrgOverview, RepositoryError> {
+        self.authorize(actor, "org.read", Some(&query.id))?;
+        let org = org_repo.get(&query.id)?;
+        let members = self.member_repo.list_by_org(&org.id)?;
+        let online = members.iter().filter(|m| m.online).count();
+        let mut fleets: Vec<String> = fleet_repo.list_by_org(&org.id)?.into_iter().map(|f| f.id).collect();
+        fleets.sort();
+        Ok(OrgOverview {
+            members: MemberCounts {
+                total: members.len(),
+                online,
+                offline: members.len() - online,
+            },
+            divisions: org.divisions.iter().map(|d| d.id.clone()).collect(),
+            fleets,
+            ship_count: ship_repo.list_by_owner_org(&org.id)?.len(),
+            id: org.id,
+            name: org.name,
+        })
+    }
+
+    pub fn member_profile<S: SessionRepository>(
+        &self,
+        actor: &str,
+        query: GetMemberProfileQuery,
+        session_repo: &S,
+    ) -> Result<MemberProfile, RepositoryError> {
+        // authorize before loading, so unauthorized callers cannot probe which members exist
+        if actor != query.member_id {
+            self.authorize(actor, "member.read", query.org_id.as_deref())?;
+        }
+        let member = self.member_repo.get(&query.member_id)?;
+        if query.org_id.is_some() && member.org_id != query.org_id {
+            return Err(RepositoryError::NotFound);
+        }
+        let mut sessions: Vec<SessionSummary> = session_repo
+            .list_all()?
+            .iter()
+            .filter(|s| s.participant.as_deref() == Some(member.id.as_str()))
+            .map(SessionSummary::from)
+            .collect();
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.start_ts));
+        sessions.truncate(query.recent_sessions);
+        Ok(MemberProfile {
+            id: member.id,
+            rsi_handle: member.rsi_handle,
+            org_id: member.org_id,
+            online: member.online,
+            last_seen: member.last_seen,
+            roles: member.roles,
+            recent_sessions: sessions,
+        })
+    }
+
+    pub fn fleet_detail<F: FleetRepository>(
+        &self,
+        actor: &str,
+        query: GetFleetDetailQuery,
+        fleet_repo: &F,
+    ) -> Result<FleetDetail, RepositoryError> {
+        self.authorize(actor, "fleet.read", Some(&query.fleet_id))?;
+        let fleet = fleet_repo.get(&query.fleet_id)?;
+        Ok(FleetDetail {
+            ships: fleet
+                .ships
+                .iter()
+                .map(|s| ShipSummary {
+                    id: s.id.clone(),
+                    model: s.model.clone(),
+                    owner_org: s.owner_org.clone(),
+                })
+                .collect(),
+            id: fleet.id,
+            name: fleet.name,
+            org_id: fleet.org_id,
+        })
+    }
+
+    pub fn activity_report<S: SessionRepository>(
+        &self,
+        actor: &str,
+        query: GetActivityReportQuery,
+        session_repo: &S,
+    ) -> Result<ActivityReport, RepositoryError> {
+        self.authorize(actor, "member.read", Some(&query.org_id))?;
+        ActivityReportService::build(
+            &query.org_id,
+            query.from,
+            query.to,
+            query.inactive_after_days,
+            self.member_repo,
+            session_repo,
+        )
+    }
+
+    /// Newest first, at most `query.limit` entries.
+    pub fn session_history<S: SessionRepository>(
+        &self,
+        actor: &str,
+        query: GetSessionHistoryQuery,
+        session_repo: &S,
+    ) -> Result<Vec<SessionSummary>, RepositoryError> {
+        let own_history = query.org_id.is_none() && query.participant.as_deref() == Some(actor);
+        if !own_history {
+            self.authorize(actor, "session.read", query.org_id.as_deref())?;
+        }
+        let sessions = match query.org_id.as_deref() {
+            Some(org) => session_repo.list_by_org(org)?,
+            None => session_repo.list_all()?,
+        };
+        let mut out: Vec<SessionSummary> = sessions
+            .iter()
+            .filter(|s| query.participant.is_none() || s.participant == query.participant)
+            .filter(|s| query.since.is_none_or(|since| s.start_ts >= since))
+            .map(SessionSummary::from)
+            .collect();
+        out.sort_by_key(|s| std::cmp::Reverse(s.start_ts));
+        out.truncate(query.limit);
+        Ok(out)
+    }
+}
@@ -0,0 +1,84 @@
+use sc_manager_core::domain::{Fleet, Organization, ResourceHierarchy, ResourceKind, Ship};
+use sc_manager_core::repositories::RepositoryError;
+use std::collections::HashMap;
+
+/// In-memory resource tree (organization > division > fleet > ship).
+pub struct InMemoryResourceHierarchy {
+    kinds: HashMap<String, ResourceKind>,
+    parents: HashMap<String, String>,
+}
+
+impl InMemoryResourceHierarchy {
+    pub fn new() -> Self {
+        Self {
+            kinds: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Registers a resource, optionally below `parent_id`.
+    /// Fails with `NotFound` for an unknown parent and `InvalidInput` for an invalid nesting or a cycle.
+    pub fn insert(&mut self, id: &str, kind: ResourceKind, parent_id: Option<&str>) -> Result<(), RepositoryError> {
+        if let Some(parent_id) = parent_id {
+            let parent_kind = self.kinds.get(parent_id).copied().ok_or(RepositoryError::NotFound)?;
+            if !parent_kind.can_contain(kind) || parent_id == id || self.ancestors(parent_id).iter().any(|a| a == id) {
+                return Err(RepositoryError::InvalidInput);
+            }
+            self.parents.insert(id.to_string(), parent_id.to_string());
+        } else {
+            self.parents.remove(id);
+        }
+        self.kinds.insert(id.to_string(), kind);
+        Ok(())
+    }
+
+    /// Registers an organization and its divisions; a division's `parent` names another division,
+    /// otherwise it hangs off the organization.
+    pub fn add_organization(&mut self, org: &Organization) -> Result<(), RepositoryError> {
+        self.insert(&org.id, ResourceKind::Organization, None)?;
+        for d in org.divisions.iter() {
+            self.kinds.insert(d.id.clone(), ResourceKind::Division);
+        }
+        for d in org.divisions.iter() {
+            let parent = d.parent.as_deref().filter(|p| org.divisions.iter().any(|o| o.id == *p));
+            self.insert(&d.id, ResourceKind::Division, Some(parent.unwrap_or(&org.id)))?;
+        }
+        Ok(())
+    }
+
+    /// Registers a fleet below an organization or division, together with its ships.
+    pub fn add_fleet(&mut self, fleet: &Fleet, parent_id: &str) -> Result<(), RepositoryError> {
+        self.insert(&fleet.id, ResourceKind::Fleet, Some(parent_id))?;
+        for s in fleet.ships.iter() {
+            self.insert(&s.id, ResourceKind::Ship, Some(&fleet.id))?;
+        }
+        Ok(())
+    }
+
+    /// Registers a ship outside a fleet, below its owning organization when known.
+    pub fn add_ship(&mut self, ship: &Ship) -> Result<(), RepositoryError> {
+        self.insert(&ship.id, ResourceKind::Ship, ship.owner_org.as_deref())
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.kinds.remove(id);
+        self.parents.remove(id);
+        self.parents.retain(|_, p| p != id);
+    }
+}
+
+impl Default for InMemoryResourceHierarchy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResourceHierarchy for InMemoryResourceHierarchy {
+    fn kind(&self, resource_id: &str) -> Option<ResourceKind> {
+        self.kinds.get(resource_id).copied()
+    }
+
+    fn parent(&self, resource_id: &str) -> Option<String> {
+        self.parents.get(resource_id).cloned()
+    }
+}
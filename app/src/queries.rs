@@ -1,4 +0,0 @@
-/// Query types for the application layer
-pub struct GetOrganizationQuery {
-    pub id: String,
-}
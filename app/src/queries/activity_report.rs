@@ -0,0 +1,20 @@
+/// Activity report of an organization over `[from, to)`; requires `member.read` on the organization.
+/// The report itself is `services::ActivityReport`.
+pub struct GetActivityReportQuery {
+    pub org_id: String,
+    pub from: i64,
+    pub to: i64,
+    /// Members inactive for longer than this many days are listed as inactive
+    pub inactive_after_days: i64,
+}
+
+impl GetActivityReportQuery {
+    pub fn new(org_id: impl Into<String>, from: i64, to: i64) -> Self {
+        Self {
+            org_id: org_id.into(),
+            from,
+            to,
+            inactive_after_days: 30,
+        }
+    }
+}
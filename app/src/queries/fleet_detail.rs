@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+/// Fleet with its ships; requires `fleet.read` on the fleet.
+pub struct GetFleetDetailQuery {
+    pub fleet_id: String,
+}
+
+impl GetFleetDetailQuery {
+    pub fn new(fleet_id: impl Into<String>) -> Self {
+        Self {
+            fleet_id: fleet_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShipSummary {
+    pub id: String,
+    pub model: String,
+    pub owner_org: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FleetDetail {
+    pub id: String,
+    pub name: String,
+    pub org_id: Option<String>,
+    pub ships: Vec<ShipSummary>,
+}
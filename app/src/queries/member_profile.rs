@@ -0,0 +1,39 @@
+use crate::queries::SessionSummary;
+use sc_manager_core::domain::RoleAssignment;
+use serde::Serialize;
+
+/// Profile of a member; requires `member.read` on the queried org, except for the member themself.
+pub struct GetMemberProfileQuery {
+    pub member_id: String,
+    /// Org the member is looked up in; `member.read` is checked on it before the member is loaded
+    pub org_id: Option<String>,
+    /// Number of most recent sessions to include
+    pub recent_sessions: usize,
+}
+
+impl GetMemberProfileQuery {
+    pub fn new(member_id: impl Into<String>) -> Self {
+        Self {
+            member_id: member_id.into(),
+            org_id: None,
+            recent_sessions: 5,
+        }
+    }
+
+    pub fn in_org(mut self, org_id: impl Into<String>) -> Self {
+        self.org_id = Some(org_id.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MemberProfile {
+    pub id: String,
+    pub rsi_handle: Option<String>,
+    pub org_id: Option<String>,
+    pub online: bool,
+    pub last_seen: Option<i64>,
+    pub roles: Vec<RoleAssignment>,
+    /// Newest first
+    pub recent_sessions: Vec<SessionSummary>,
+}
@@ -0,0 +1,12 @@
+//! Query types for the application layer; DTOs live next to their query.
+pub mod activity_report;
+pub mod fleet_detail;
+pub mod member_profile;
+pub mod org_overview;
+pub mod session_history;
+
+pub use self::activity_report::GetActivityReportQuery;
+pub use self::fleet_detail::{FleetDetail, GetFleetDetailQuery, ShipSummary};
+pub use self::member_profile::{GetMemberProfileQuery, MemberProfile};
+pub use self::org_overview::{GetOrganizationQuery, MemberCounts, OrgOverview};
+pub use self::session_history::{GetSessionHistoryQuery, SessionSummary};
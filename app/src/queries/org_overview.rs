@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+/// Overview of an organization; requires `org.read` on the organization.
+pub struct GetOrganizationQuery {
+    pub id: String,
+}
+
+impl GetOrganizationQuery {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MemberCounts {
+    pub total: usize,
+    pub online: usize,
+    pub offline: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrgOverview {
+    pub id: String,
+    pub name: String,
+    pub members: MemberCounts,
+    pub divisions: Vec<String>,
+    pub fleets: Vec<String>,
+    pub ship_count: usize,
+}
@@ -0,0 +1,58 @@
+use sc_manager_core::domain::Session;
+use serde::Serialize;
+
+/// Sessions of an organization, optionally narrowed to one participant; requires
+/// `session.read` on the organization, except for a member reading their own history.
+pub struct GetSessionHistoryQuery {
+    pub org_id: Option<String>,
+    pub participant: Option<String>,
+    /// Only sessions started at or after this timestamp
+    pub since: Option<i64>,
+    pub limit: usize,
+}
+
+impl GetSessionHistoryQuery {
+    pub fn for_org(org_id: impl Into<String>) -> Self {
+        Self {
+            org_id: Some(org_id.into()),
+            participant: None,
+            since: None,
+            limit: 50,
+        }
+    }
+
+    pub fn for_participant(participant: impl Into<String>) -> Self {
+        Self {
+            org_id: None,
+            participant: Some(participant.into()),
+            since: None,
+            limit: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionSummary {
+    pub id: String,
+    pub participant: Option<String>,
+    pub org_id: Option<String>,
+    pub start_ts: i64,
+    pub end_ts: Option<i64>,
+    /// Seconds between start and end; `None` while the session is active
+    pub duration_secs: Option<i64>,
+    pub event_count: usize,
+}
+
+impl From<&Session> for SessionSummary {
+    fn from(s: &Session) -> Self {
+        Self {
+            id: s.id.clone(),
+            participant: s.participant.clone(),
+            org_id: s.org_id.clone(),
+            start_ts: s.start_ts,
+            end_ts: s.end_ts,
+            duration_secs: s.end_ts.map(|end| end - s.start_ts),
+            event_count: s.events.len(),
+        }
+    }
+}
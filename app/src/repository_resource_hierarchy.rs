@@ -0,0 +1,59 @@
+use crate::in_memory_resource_hierarchy::InMemoryResourceHierarchy;
+use sc_manager_core::domain::{ResourceHierarchy, ResourceKind};
+use sc_manager_core::repositories::{FleetRepository, ShipRepository};
+
+/// Resource tree read from the fleet and ship repositories: a fleet hangs off its organization,
+/// a ship off the fleet of its owning organization that lists it, or else off that organization.
+/// Ids not found in either repository are treated as organizations (roots).
+pub struct RepositoryResourceHierarchy<'a, F: FleetRepository, S: ShipRepository> {
+    fleets: &'a F,
+    ships: &'a S,
+}
+
+impl<'a, F: FleetRepository, S: ShipRepository> RepositoryResourceHierarchy<'a, F, S> {
+    pub fn new(fleets: &'a F, ships: &'a S) -> Self {
+        Self { fleets, ships }
+    }
+
+    /// Owned copy of the parent chain of `resource_id`. Use it where the repositories are borrowed
+    /// mutably while permissions are checked, e.g. for a `CommandBus` handler that updates a fleet.
+    pub fn resolve(&self, resource_id: &str) -> InMemoryResourceHierarchy {
+        let mut chain = vec![resource_id.to_string()];
+        chain.extend(self.ancestors(resource_id));
+        let mut out = InMemoryResourceHierarchy::new();
+        let mut parent: Option<&str> = None;
+        for id in chain.iter().rev() {
+            let kind = self.kind(id).unwrap_or(ResourceKind::Organization);
+            if out.insert(id, kind, parent).is_err() {
+                break;
+            }
+            parent = Some(id);
+        }
+        out
+    }
+}
+
+impl<F: FleetRepository, S: ShipRepository> ResourceHierarchy for RepositoryResourceHierarchy<'_, F, S> {
+    fn kind(&self, resource_id: &str) -> Option<ResourceKind> {
+        if self.fleets.get(resource_id).is_ok() {
+            Some(ResourceKind::Fleet)
+        } else if self.ships.get(resource_id).is_ok() {
+            Some(ResourceKind::Ship)
+        } else {
+            None
+        }
+    }
+
+    fn parent(&self, resource_id: &str) -> Option<String> {
+        if let Ok(fleet) = self.fleets.get(resource_id) {
+            return fleet.org_id;
+        }
+        let org = self.ships.get(resource_id).ok()?.owner_org?;
+        let fleet = self
+            .fleets
+            .list_by_org(&org)
+            .ok()
+            .and_then(|fleets| fleets.into_iter().find(|f| f.ships.iter().any(|s| s.id == resource_id)));
+        Some(fleet.map(|f| f.id).unwrap_or(org))
+    }
+}
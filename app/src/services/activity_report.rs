@@ -0,0 +1,191 @@
+use sc_manager_core::domain::{Member, Session};
+use sc_manager_core::repositories::{MemberRepository, RepositoryError, SessionRepository};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+const DAY_SECS: i64 = 24 * 60 * 60;
+
+/// Activity of one member within the report period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MemberActivity {
+    pub member_id: String,
+    pub division_id: Option<String>,
+    /// Sessions overlapping the period
+    pub sessions: usize,
+    /// Session time inside the period; active sessions count up to the period end
+    pub playtime_secs: i64,
+    /// Longest run of consecutive (UTC) days with a session
+    pub longest_streak_days: u32,
+    /// Run of consecutive days with a session ending on the last day of the period
+    pub current_streak_days: u32,
+    /// Latest of `last_seen` and session activity, up to the period end
+    pub last_active: Option<i64>,
+    /// Whole days between `last_active` and the period end; `None` if never active
+    pub days_inactive: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DivisionActivity {
+    /// `None` groups members without a division
+    pub division_id: Option<String>,
+    pub members: usize,
+    /// Members with at least one session in the period
+    pub active_members: usize,
+    pub sessions: usize,
+    pub playtime_secs: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActivityReport {
+    pub org_id: String,
+    pub from: i64,
+    pub to: i64,
+    pub members: Vec<MemberActivity>,
+    pub divisions: Vec<DivisionActivity>,
+    /// Members inactive for longer than the requested number of days (or never active)
+    pub inactive: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CsvRow<'a> {
+    member_id: &'a str,
+    division_id: &'a str,
+    sessions: usize,
+    playtime_hours: String,
+    longest_streak_days: u32,
+    current_streak_days: u32,
+    last_active: String,
+    days_inactive: String,
+    inactive: bool,
+}
+
+impl ActivityReport {
+    /// One row per member, for spreadsheets.
+    pub fn to_csv(&self) -> Result<String, RepositoryError> {
+        let mut w = csv::Writer::from_writer(vec![]);
+        for m in self.members.iter() {
+            w.serialize(CsvRow {
+                member_id: &m.member_id,
+                division_id: m.division_id.as_deref().unwrap_or_default(),
+                sessions: m.sessions,
+                playtime_hours: format!("{:.2}", m.playtime_secs as f64 / 3600.0),
+                longest_streak_days: m.longest_streak_days,
+                current_streak_days: m.current_streak_days,
+                last_active: m.last_active.map(|t| t.to_string()).unwrap_or_default(),
+                days_inactive: m.days_inactive.map(|d| d.to_string()).unwrap_or_default(),
+                inactive: self.inactive.contains(&m.member_id),
+            })
+            .map_err(|_| RepositoryError::Internal)?;
+        }
+        let bytes = w.into_inner().map_err(|_| RepositoryError::Internal)?;
+        String::from_utf8(bytes).map_err(|_| RepositoryError::Internal)
+    }
+}
+
+/// Builds activity reports for an organization from sessions and `Member.last_seen`.
+/// Callers authorize through `QueryHandler::activity_report`.
+pub struct ActivityReportService;
+
+impl ActivityReportService {
+    /// Report for members of `org_id` over `[from, to)`; members whose last activity is more
+    /// than `inactive_after_days` before `to` are listed as inactive.
+    pub fn build<M: MemberRepository, S: SessionRepository>(
+        org_id: &str,
+        from: i64,
+        to: i64,
+        inactive_after_days: i64,
+        member_repo: &M,
+        session_repo: &S,
+    ) -> Result<ActivityReport, RepositoryError> {
+        if to <= from || inactive_after_days < 0 {
+            return Err(RepositoryError::InvalidInput);
+        }
+        let mut members = member_repo.list_by_org(org_id)?;
+        members.sort_by(|a, b| a.id.cmp(&b.id));
+        let sessions = session_repo.list_all()?;
+
+        let activity: Vec<MemberActivity> = members
+            .iter()
+            .map(|m| Self::member_activity(m, &sessions, from, to))
+            .collect();
+
+        let mut divisions: BTreeMap<Option<String>, DivisionActivity> = BTreeMap::new();
+        for a in activity.iter() {
+            let d = divisions.entry(a.division_id.clone()).or_insert_with(|| DivisionActivity {
+                division_id: a.division_id.clone(),
+                members: 0,
+                active_members: 0,
+                sessions: 0,
+                playtime_secs: 0,
+            });
+            d.members += 1;
+            d.sessions += a.sessions;
+            d.playtime_secs += a.playtime_secs;
+            if a.sessions > 0 {
+                d.active_members += 1;
+            }
+        }
+
+        let inactive = activity
+            .iter()
+            .filter(|a| a.days_inactive.is_none_or(|d| d > inactive_after_days))
+            .map(|a| a.member_id.clone())
+            .collect();
+
+        Ok(ActivityReport {
+            org_id: org_id.to_string(),
+            from,
+            to,
+            members: activity,
+            divisions: divisions.into_values().collect(),
+            inactive,
+        })
+    }
+
+    fn member_activity(member: &Member, sessions: &[Session], from: i64, to: i64) -> MemberActivity {
+        let mut count = 0;
+        let mut playtime = 0;
+        let mut days: BTreeSet<i64> = BTreeSet::new();
+        let mut last_active = member.last_seen.filter(|t| *t < to);
+
+        for s in sessions.iter().filter(|s| s.participant.as_deref() == Some(member.id.as_str())) {
+            let start = s.start_ts.max(from);
+            let end = s.end_ts.unwrap_or(to).min(to);
+            // skip sessions outside the period
+            if s.start_ts >= to || s.end_ts.is_some_and(|e| e < from) {
+                continue;
+            }
+            count += 1;
+            playtime += (end - start).max(0);
+            // every UTC day the session touched; `end` is exclusive
+            let mut day = start.div_euclid(DAY_SECS);
+            let end_day = if end > start { (end - 1).div_euclid(DAY_SECS) } else { day };
+            while day <= end_day {
+                days.insert(day);
+                day += 1;
+            }
+            let seen = s.last_activity().max(s.end_ts.unwrap_or(s.start_ts)).min(to - 1);
+            last_active = Some(last_active.map_or(seen, |t| t.max(seen)));
+        }
+
+        let (mut longest, mut run, mut prev) = (0u32, 0u32, None::<i64>);
+        for d in days.iter() {
+            run = if prev == Some(d - 1) { run + 1 } else { 1 };
+            longest = longest.max(run);
+            prev = Some(*d);
+        }
+        let last_day = (to - 1).div_euclid(DAY_SECS);
+        let current = if prev == Some(last_day) { run } else { 0 };
+
+        MemberActivity {
+            member_id: member.id.clone(),
+            division_id: member.division_id.clone(),
+            sessions: count,
+            playtime_secs: playtime,
+            longest_streak_days: longest,
+            current_streak_days: current,
+            last_active,
+            days_inactive: last_active.map(|t| (to - t).div_euclid(DAY_SECS)),
+        }
+    }
+}
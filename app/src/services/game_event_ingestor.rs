@@ -0,0 +1,121 @@
+use crate::services::session_service::{SessionService, DEFAULT_IDLE_TIMEOUT_SECS};
+use sc_manager_core::domain::ResourceHierarchy;
+use sc_manager_core::events::EventEnvelope;
+use sc_manager_core::repositories::{
+    EventRepository, MemberRepository, PermissionRepository, RepositoryError, RoleRepository, SessionRepository,
+};
+use std::collections::{HashSet, VecDeque};
+
+/// Default number of recent event ids remembered by `GameEventIngestor`.
+pub const DEFAULT_DEDUPE_WINDOW: usize = 10_000;
+
+/// Minimum advance in event time between two idle-session sweeps.
+pub const IDLE_SWEEP_STEP_SECS: i64 = 60;
+
+/// Bounded set of the most recently seen ids; the oldest id is forgotten first.
+pub struct DedupeWindow {
+    capacity: usize,
+    order: VecDeque<String>,
+    seen: HashSet<String>,
+}
+
+impl DedupeWindow {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.seen.contains(id)
+    }
+
+    /// Remembers `id`; returns false if it was already in the window.
+    pub fn insert(&mut self, id: &str) -> bool {
+        if self.capacity == 0 || !self.seen.insert(id.to_string()) {
+            return false;
+        }
+        self.order.push_back(id.to_string());
+        while self.order.len() > self.capacity {
+            if let Some(old) = self.order.pop_front() {
+                self.seen.remove(&old);
+            }
+        }
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+/// Ingestion path for parsed game events: drops envelopes whose content-addressed id was
+/// already processed within the dedupe window, then hands them to `SessionService`.
+/// Replays older than the window are absorbed downstream: `EventRepository::append` ignores
+/// known event ids and `SessionService` ignores starts of sessions it has already recorded.
+///
+/// As event time advances (in steps of at least `IDLE_SWEEP_STEP_SECS`), sessions idle for
+/// longer than the idle timeout are closed at their last activity.
+pub struct GameEventIngestor {
+    window: DedupeWindow,
+    idle_timeout: i64,
+    last_sweep: Option<i64>,
+}
+
+impl GameEventIngestor {
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window: DedupeWindow::new(window_size),
+            idle_timeout: DEFAULT_IDLE_TIMEOUT_SECS,
+            last_sweep: None,
+        }
+    }
+
+    pub fn with_idle_timeout(mut self, idle_timeout: i64) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Returns `Ok(false)` when the envelope was a duplicate and skipped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn ingest<S: SessionRepository, E: EventRepository, M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        &mut self,
+        env: EventEnvelope,
+        session_repo: &mut S,
+        event_repo: &mut E,
+        member_repo: Option<&mut M>,
+        role_repo: Option<&R>,
+        permission_repo: Option<&P>,
+        hierarchy: Option<&dyn ResourceHierarchy>,
+    ) -> Result<bool, RepositoryError> {
+        let (id, timestamp) = match &env {
+            EventEnvelope::GameEvent { id, timestamp, .. } => (Some(id.clone()), Some(*timestamp)),
+            _ => (None, None),
+        };
+        if id.as_deref().is_some_and(|id| self.window.contains(id)) {
+            return Ok(false);
+        }
+        if let Some(now) = timestamp.filter(|now| self.last_sweep.is_none_or(|last| now - last >= IDLE_SWEEP_STEP_SECS)) {
+            SessionService::close_idle_sessions(session_repo, now, self.idle_timeout)?;
+            self.last_sweep = Some(now);
+        }
+        SessionService::process_envelope(env, session_repo, event_repo, member_repo, role_repo, permission_repo, hierarchy)?;
+        // only successfully processed events count as seen, so failures can be retried
+        if let Some(id) = id {
+            self.window.insert(&id);
+        }
+        Ok(true)
+    }
+}
+
+impl Default for GameEventIngestor {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEDUPE_WINDOW)
+    }
+}
@@ -1,5 +1,15 @@
-pub mod policy_service;
-pub mod session_service;
-
-pub use self::policy_service::PolicyService;
-pub use self::session_service::SessionService;
+pub mod activity_report;
+pub mod game_event_ingestor;
+pub mod permission_cache;
+pub mod policy_service;
+pub mod role_assignment_sweeper;
+pub mod roster_sync;
+pub mod session_service;
+
+pub use self::activity_report::{ActivityReport, ActivityReportService};
+pub use self::game_event_ingestor::GameEventIngestor;
+pub use self::permission_cache::PermissionCache;
+pub use self::policy_service::{PolicyContext, PolicyDecision, PolicyService};
+pub use self::role_assignment_sweeper::RoleAssignmentSweeper;
+pub use self::roster_sync::{RemoteRosterMember, RosterChange, RosterConfirmation, RosterDiff, RosterSyncService, UnverifiableMember};
+pub use self::session_service::SessionService;
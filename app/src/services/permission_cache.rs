@@ -0,0 +1,164 @@
+use crate::services::policy_service::{PolicyContext, PolicyDecision, PolicyService};
+use sc_manager_core::domain::member::AssignmentCondition;
+use sc_manager_core::domain::{Member, Role};
+use sc_manager_core::events::EventEnvelope;
+use sc_manager_core::repositories::{MemberRepository, PermissionRepository, RepositoryError, RoleRepository};
+use std::collections::HashMap;
+
+/// A member's assignments and the roles they reference, as loaded on the last miss.
+struct CachedMember {
+    member: Member,
+    /// Roles found at load time; a role missing then stays missing until the entry is dropped.
+    roles: HashMap<String, Role>,
+    role_ids: Vec<String>,
+    /// Presence changes do not invalidate the cache, so `MemberOnline` conditions re-read the member.
+    needs_presence: bool,
+}
+
+/// Per-member effective-permission cache in front of `PolicyService`.
+/// Entries are dropped on `RoleAssigned` / `RoleRevoked` (that member) and
+/// `RolePermissionChanged` (every member holding the role); see `handle_event`.
+/// `AuthorizationMiddleware::with_cache` puts it on the `CommandBus`, which forwards those events.
+pub struct PermissionCache {
+    entries: HashMap<String, CachedMember>,
+    metrics: Option<(prometheus::IntCounter, prometheus::IntCounter)>,
+}
+
+impl PermissionCache {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            metrics: None,
+        }
+    }
+
+    /// Registers `policy_cache_hits_total` / `policy_cache_misses_total` on `registry`.
+    pub fn with_metrics(mut self, registry: &prometheus::Registry) -> Result<Self, prometheus::Error> {
+        let hits = prometheus::IntCounter::new("policy_cache_hits_total", "Permission cache hits")?;
+        let misses = prometheus::IntCounter::new("policy_cache_misses_total", "Permission cache misses")?;
+        registry.register(Box::new(hits.clone()))?;
+        registry.register(Box::new(misses.clone()))?;
+        self.metrics = Some((hits, misses));
+        Ok(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn invalidate(&mut self, member_id: &str) {
+        self.entries.remove(member_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drops entries affected by a domain event.
+    pub fn handle_event(&mut self, ev: &EventEnvelope) {
+        match ev {
+            EventEnvelope::RoleAssigned { member_id, .. } | EventEnvelope::RoleRevoked { member_id, .. } => {
+                self.invalidate(member_id)
+            }
+            EventEnvelope::RolePermissionChanged { role_id, .. } => {
+                self.entries.retain(|_, e| !e.role_ids.contains(role_id))
+            }
+            _ => {}
+        }
+    }
+
+    /// Cached equivalent of `PolicyService::check_permission_in`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_permission_in<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        &mut self,
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        permission_repo: &P,
+    ) -> Result<bool, RepositoryError> {
+        self.evaluate(ctx, member_id, permission_id, resource_id, member_repo, role_repo, permission_repo)
+            .map(|d| d.allowed)
+    }
+
+    /// Cached equivalent of `PolicyService::evaluate`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn evaluate<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        &mut self,
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        _permission_repo: &P,
+    ) -> Result<PolicyDecision, RepositoryError> {
+        if self.entries.contains_key(member_id) {
+            self.record(true);
+        } else {
+            self.record(false);
+            let entry = Self::load(member_id, member_repo, role_repo)?;
+            self.entries.insert(member_id.to_string(), entry);
+        }
+        let entry = self.entries.get_mut(member_id).ok_or(RepositoryError::Internal)?;
+        if entry.needs_presence {
+            let fresh = member_repo.get(member_id).map_err(|_| RepositoryError::NotFound)?;
+            entry.member.online = fresh.online;
+        }
+        let roles = &entry.roles;
+        Ok(PolicyService::evaluate_member(ctx, &entry.member, permission_id, resource_id, |role_id| {
+            roles.get(role_id).cloned()
+        }))
+    }
+
+    fn record(&self, hit: bool) {
+        if let Some((hits, misses)) = self.metrics.as_ref() {
+            if hit {
+                hits.inc();
+            } else {
+                misses.inc();
+            }
+        }
+    }
+
+    fn load<M: MemberRepository, R: RoleRepository>(
+        member_id: &str,
+        member_repo: &M,
+        role_repo: &R,
+    ) -> Result<CachedMember, RepositoryError> {
+        let member = member_repo.get(member_id).map_err(|_| RepositoryError::NotFound)?;
+        let mut roles = HashMap::new();
+        let mut role_ids = vec![];
+        for ra in member.roles.iter() {
+            if role_ids.contains(&ra.role_id) {
+                continue;
+            }
+            role_ids.push(ra.role_id.clone());
+            if let Ok(role) = role_repo.get(&ra.role_id) {
+                roles.insert(ra.role_id.clone(), role);
+            }
+        }
+        let needs_presence = member
+            .roles
+            .iter()
+            .any(|ra| ra.condition == Some(AssignmentCondition::MemberOnline));
+        Ok(CachedMember {
+            member,
+            roles,
+            role_ids,
+            needs_presence,
+        })
+    }
+}
+
+impl Default for PermissionCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
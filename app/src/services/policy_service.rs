@@ -1,96 +1,394 @@
-use sc_manager_core::repositories::{
-    MemberRepository, PermissionRepository, RepositoryError, RoleRepository,
-};
-
-/// PolicyService performs resource-level permission checks.
-pub struct PolicyService;
-
-impl PolicyService {
-    /// Checks whether `member_id` has `permission_id` on optional `resource_id`.
-    /// Role assignments on member can be global (resource_id None) or resource-scoped (resource_id Some).
-    pub fn check_permission<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
-        member_id: &str,
-        permission_id: &str,
-        resource_id: Option<&str>,
-        member_repo: &M,
-        role_repo: &R,
-        _permission_repo: &P,
-    ) -> Result<bool, RepositoryError> {
-        let member = member_repo
-            .get(member_id)
-            .map_err(|_| RepositoryError::NotFound)?;
-        // For each role assignment on the member, load role and check permissions
-        for ra in member.roles.iter() {
-            // role may not exist
-            if let Ok(role) = role_repo.get(&ra.role_id) {
-                if role.permissions.iter().any(|p| p == permission_id) {
-                    // resource check: either assignment is global or matches requested resource
-                    let ra_res = ra.resource_id.as_deref();
-                    println!("DEBUG PolicyService: member={} role={} ra_res={:?} requested_res={:?}", member_id, &ra.role_id, ra_res, resource_id);
-                    if ra.resource_id.is_none()
-                        || resource_id.is_none()
-                        || ra.resource_id.as_deref() == resource_id
-                    {
-                        println!("DEBUG PolicyService: allowed by role={}", &ra.role_id);
-                        return Ok(true);
-                    }
-                }
-            }
-        }
-        Ok(false)
-    }
-
-    /// Convenience helper: can the member create events on the optional resource?
-    pub fn can_create_event<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
-        member_id: &str,
-        resource_id: Option<&str>,
-        member_repo: &M,
-        role_repo: &R,
-        permission_repo: &P,
-    ) -> Result<bool, RepositoryError> {
-        Self::check_permission(
-            member_id,
-            "event.create",
-            resource_id,
-            member_repo,
-            role_repo,
-            permission_repo,
-        )
-    }
-
-    /// Convenience helper: can the member start sessions for the optional resource?
-    pub fn can_start_session<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
-        member_id: &str,
-        resource_id: Option<&str>,
-        member_repo: &M,
-        role_repo: &R,
-        permission_repo: &P,
-    ) -> Result<bool, RepositoryError> {
-        Self::check_permission(
-            member_id,
-            "session.start",
-            resource_id,
-            member_repo,
-            role_repo,
-            permission_repo,
-        )
-    }
-
-    /// Convenience helper: can the member end sessions for the optional resource?
-    pub fn can_end_session<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
-        member_id: &str,
-        resource_id: Option<&str>,
-        member_repo: &M,
-        role_repo: &R,
-        permission_repo: &P,
-    ) -> Result<bool, RepositoryError> {
-        Self::check_permission(
-            member_id,
-            "session.end",
-            resource_id,
-            member_repo,
-            role_repo,
-            permission_repo,
-        )
-    }
-}
+use serde::Serialize;
+use sc_manager_core::domain::member::{AssignmentCondition, RoleAssignment};
+use sc_manager_core::domain::{Member, Permission, ResourceHierarchy, Role};
+use sc_manager_core::repositories::{
+    MemberRepository, PermissionRepository, RepositoryError, RoleRepository, SessionRepository,
+};
+
+/// Evaluation context for time-bounded and conditional role assignments.
+pub struct PolicyContext<'a> {
+    /// Unix timestamp (seconds) the check is evaluated at.
+    pub now: i64,
+    /// Used to evaluate `AssignmentCondition::SessionActive`; without it such assignments never apply.
+    pub sessions: Option<&'a dyn SessionRepository>,
+    /// Lets a grant on a parent resource (e.g. an organization) cover its children;
+    /// without it scoped grants only match their exact resource.
+    pub hierarchy: Option<&'a dyn ResourceHierarchy>,
+}
+
+impl<'a> PolicyContext<'a> {
+    pub fn at(now: i64) -> Self {
+        Self {
+            now,
+            sessions: None,
+            hierarchy: None,
+        }
+    }
+
+    /// Context evaluated at the current system time.
+    pub fn current() -> Self {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        Self::at(now)
+    }
+
+    pub fn with_sessions(mut self, sessions: &'a dyn SessionRepository) -> Self {
+        self.sessions = Some(sessions);
+        self
+    }
+
+    pub fn with_hierarchy(mut self, hierarchy: &'a dyn ResourceHierarchy) -> Self {
+        self.hierarchy = Some(hierarchy);
+        self
+    }
+
+    /// Whether an assignment scoped to `scope` (None = global) applies to the requested resource.
+    /// Scoped grants never match unscoped requests.
+    pub fn scope_covers(&self, scope: Option<&str>, resource_id: Option<&str>) -> bool {
+        match (scope, resource_id) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(scope), Some(resource_id)) => match self.hierarchy {
+                Some(h) => h.covers(scope, resource_id),
+                None => scope == resource_id,
+            },
+        }
+    }
+
+    /// Whether the assignment is inside its validity window and its condition holds.
+    pub fn is_assignment_active(&self, member: &Member, ra: &RoleAssignment) -> bool {
+        if !ra.is_valid_at(self.now) {
+            return false;
+        }
+        match &ra.condition {
+            None => true,
+            Some(AssignmentCondition::MemberOnline) => member.online,
+            Some(AssignmentCondition::SessionActive(session_id)) => self
+                .sessions
+                .and_then(|repo| repo.get(session_id).ok())
+                .is_some_and(|s| s.is_active()),
+        }
+    }
+}
+
+/// Rule that blocked an assignment whose role would otherwise grant the permission.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum DenyRule {
+    NotYetValid { valid_from: i64 },
+    Expired { valid_until: i64 },
+    ConditionNotMet { condition: Option<AssignmentCondition> },
+    ScopeMismatch { assignment_scope: Option<String> },
+}
+
+impl std::fmt::Display for DenyRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DenyRule::NotYetValid { valid_from } => write!(f, "the assignment is not valid before {}", valid_from),
+            DenyRule::Expired { valid_until } => write!(f, "the assignment expired at {}", valid_until),
+            DenyRule::ConditionNotMet { condition } => write!(f, "the assignment condition {:?} is not met", condition),
+            DenyRule::ScopeMismatch { assignment_scope: Some(scope) } => {
+                write!(f, "the assignment is scoped to '{}'", scope)
+            }
+            DenyRule::ScopeMismatch { assignment_scope: None } => write!(f, "the assignment scope does not apply"),
+        }
+    }
+}
+
+/// Outcome of evaluating one role assignment.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum AssignmentOutcome {
+    /// Granted through `grant`, possibly a wildcard such as `fleet.*`.
+    Granted { grant: String },
+    RoleNotFound,
+    PermissionNotGranted,
+    Denied(DenyRule),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AssignmentEvaluation {
+    pub assignment: RoleAssignment,
+    pub outcome: AssignmentOutcome,
+}
+
+/// Structured result of a permission check, suitable for logs and "why can't I" answers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PolicyDecision {
+    pub allowed: bool,
+    pub member_id: String,
+    pub permission_id: String,
+    pub resource_id: Option<String>,
+    /// Assignment that granted the permission; its `resource_id` is the matched scope.
+    pub matched_assignment: Option<RoleAssignment>,
+    /// Role permission that matched, possibly a wildcard.
+    pub matched_grant: Option<String>,
+    /// First rule that denied an otherwise granting assignment.
+    pub deny_rule: Option<DenyRule>,
+    pub reason: String,
+    /// Every assignment evaluated, in order.
+    pub evaluated: Vec<AssignmentEvaluation>,
+}
+
+impl PolicyDecision {
+    fn from_evaluations(
+        member_id: &str,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        evaluated: Vec<AssignmentEvaluation>,
+    ) -> Self {
+        let granted = evaluated.iter().find_map(|e| match &e.outcome {
+            AssignmentOutcome::Granted { grant } => Some((e.assignment.clone(), grant.clone())),
+            _ => None,
+        });
+        let denied = evaluated.iter().find_map(|e| match &e.outcome {
+            AssignmentOutcome::Denied(rule) => Some((e.assignment.role_id.clone(), rule.clone())),
+            _ => None,
+        });
+
+        let (allowed, matched_assignment, matched_grant, deny_rule, reason) = match (granted, denied) {
+            (Some((ra, grant)), _) => {
+                let scope = match &ra.resource_id {
+                    Some(r) => format!("scoped to '{}'", r),
+                    None => "global".to_string(),
+                };
+                let reason = format!("granted by role '{}' via '{}' ({})", ra.role_id, grant, scope);
+                (true, Some(ra), Some(grant), None, reason)
+            }
+            (None, Some((role_id, rule))) => {
+                let reason = format!("role '{}' grants '{}' but {}", role_id, permission_id, rule);
+                (false, None, None, Some(rule), reason)
+            }
+            (None, None) if evaluated.is_empty() => {
+                (false, None, None, None, format!("member '{}' has no role assignments", member_id))
+            }
+            (None, None) => (false, None, None, None, format!("no assigned role grants '{}'", permission_id)),
+        };
+
+        Self {
+            allowed,
+            member_id: member_id.to_string(),
+            permission_id: permission_id.to_string(),
+            resource_id: resource_id.map(str::to_string),
+            matched_assignment,
+            matched_grant,
+            deny_rule,
+            reason,
+            evaluated,
+        }
+    }
+}
+
+/// PolicyService performs resource-level permission checks.
+pub struct PolicyService;
+
+impl PolicyService {
+    /// Checks whether `member_id` has `permission_id` on optional `resource_id`.
+    /// Role assignments on member can be global (resource_id None) or resource-scoped (resource_id Some);
+    /// scoped assignments only apply to requests naming that resource (or a child, see `PolicyContext::with_hierarchy`).
+    /// Role permissions may be wildcard grants (`fleet.*`, `*`), see `Permission::grants`.
+    /// Evaluated at the current time; see `check_permission_in` for an explicit context.
+    pub fn check_permission<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        member_id: &str,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        permission_repo: &P,
+    ) -> Result<bool, RepositoryError> {
+        Self::check_permission_in(
+            &PolicyContext::current(),
+            member_id,
+            permission_id,
+            resource_id,
+            member_repo,
+            role_repo,
+            permission_repo,
+        )
+    }
+
+    /// Like `check_permission`, but expired, not yet valid or unmet conditional
+    /// assignments are judged against `ctx`.
+    pub fn check_permission_in<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        permission_repo: &P,
+    ) -> Result<bool, RepositoryError> {
+        Self::evaluate(
+            ctx,
+            member_id,
+            permission_id,
+            resource_id,
+            member_repo,
+            role_repo,
+            permission_repo,
+        )
+        .map(|d| d.allowed)
+    }
+
+    /// Full decision for `member_id` / `permission_id` on optional `resource_id`:
+    /// the outcome, the assignment that matched or the rule that denied, and why.
+    /// The decision is also recorded on a `policy.check` tracing span.
+    pub fn evaluate<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        _permission_repo: &P,
+    ) -> Result<PolicyDecision, RepositoryError> {
+        let member = member_repo
+            .get(member_id)
+            .map_err(|_| RepositoryError::NotFound)?;
+        Ok(Self::evaluate_member(ctx, &member, permission_id, resource_id, |role_id| {
+            role_repo.get(role_id).ok()
+        }))
+    }
+
+    /// `evaluate` for an already loaded member; `role` looks up the roles its assignments reference.
+    pub fn evaluate_member(
+        ctx: &PolicyContext<'_>,
+        member: &Member,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        role: impl Fn(&str) -> Option<Role>,
+    ) -> PolicyDecision {
+        let member_id = member.id.as_str();
+        let span = tracing::debug_span!(
+            "policy.check",
+            member = member_id,
+            permission = permission_id,
+            resource = ?resource_id,
+            allowed = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+
+        // Evaluate assignments in order; the first granting one wins
+        let mut evaluated = vec![];
+        for ra in member.roles.iter() {
+            let outcome = Self::evaluate_assignment(ctx, member, ra, permission_id, resource_id, role(&ra.role_id));
+            let granted = matches!(outcome, AssignmentOutcome::Granted { .. });
+            evaluated.push(AssignmentEvaluation {
+                assignment: ra.clone(),
+                outcome,
+            });
+            if granted {
+                break;
+            }
+        }
+        let decision = PolicyDecision::from_evaluations(member_id, permission_id, resource_id, evaluated);
+
+        span.record("allowed", decision.allowed);
+        tracing::debug!(reason = %decision.reason, "policy decision");
+        decision
+    }
+
+    fn evaluate_assignment(
+        ctx: &PolicyContext<'_>,
+        member: &Member,
+        ra: &RoleAssignment,
+        permission_id: &str,
+        resource_id: Option<&str>,
+        role: Option<Role>,
+    ) -> AssignmentOutcome {
+        // role may not exist
+        let role = match role {
+            Some(role) => role,
+            None => return AssignmentOutcome::RoleNotFound,
+        };
+        let grant = match role
+            .permissions
+            .iter()
+            .find(|p| Permission::grants(p, permission_id))
+        {
+            Some(grant) => grant.clone(),
+            None => return AssignmentOutcome::PermissionNotGranted,
+        };
+        if let Some(from) = ra.valid_from.filter(|from| ctx.now < *from) {
+            return AssignmentOutcome::Denied(DenyRule::NotYetValid { valid_from: from });
+        }
+        if let Some(until) = ra.valid_until.filter(|_| ra.is_expired_at(ctx.now)) {
+            return AssignmentOutcome::Denied(DenyRule::Expired { valid_until: until });
+        }
+        if !ctx.is_assignment_active(member, ra) {
+            return AssignmentOutcome::Denied(DenyRule::ConditionNotMet {
+                condition: ra.condition.clone(),
+            });
+        }
+        // resource check: assignment is global or scoped to the requested resource or one of its parents
+        if ctx.scope_covers(ra.resource_id.as_deref(), resource_id) {
+            AssignmentOutcome::Granted { grant }
+        } else {
+            AssignmentOutcome::Denied(DenyRule::ScopeMismatch {
+                assignment_scope: ra.resource_id.clone(),
+            })
+        }
+    }
+
+    /// Convenience helper: can the member create events on the optional resource in `ctx`?
+    pub fn can_create_event<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        permission_repo: &P,
+    ) -> Result<bool, RepositoryError> {
+        Self::check_permission_in(
+            ctx,
+            member_id,
+            "event.create",
+            resource_id,
+            member_repo,
+            role_repo,
+            permission_repo,
+        )
+    }
+
+    /// Convenience helper: can the member start sessions for the optional resource in `ctx`?
+    pub fn can_start_session<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        permission_repo: &P,
+    ) -> Result<bool, RepositoryError> {
+        Self::check_permission_in(
+            ctx,
+            member_id,
+            "session.start",
+            resource_id,
+            member_repo,
+            role_repo,
+            permission_repo,
+        )
+    }
+
+    /// Convenience helper: can the member end sessions for the optional resource in `ctx`?
+    pub fn can_end_session<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        ctx: &PolicyContext<'_>,
+        member_id: &str,
+        resource_id: Option<&str>,
+        member_repo: &M,
+        role_repo: &R,
+        permission_repo: &P,
+    ) -> Result<bool, RepositoryError> {
+        Self::check_permission_in(
+            ctx,
+            member_id,
+            "session.end",
+            resource_id,
+            member_repo,
+            role_repo,
+            permission_repo,
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use sc_manager_core::events::EventEnvelope;
+use sc_manager_core::repositories::{MemberRepository, RepositoryError};
+
+/// Revokes role assignments whose validity window has ended.
+pub struct RoleAssignmentSweeper;
+
+impl RoleAssignmentSweeper {
+    /// Removes every assignment expired at `now` and returns one `RoleRevoked` event per
+    /// revocation so the caller can publish them.
+    pub fn sweep<M: MemberRepository>(
+        now: i64,
+        member_repo: &mut M,
+    ) -> Result<Vec<EventEnvelope>, RepositoryError> {
+        let mut events = vec![];
+        for mut member in member_repo.list_all()? {
+            let expired = member.remove_expired_roles(now);
+            if expired.is_empty() {
+                continue;
+            }
+            for ra in expired {
+                events.push(EventEnvelope::RoleRevoked {
+                    member_id: member.id.clone(),
+                    role_id: ra.role_id,
+                    resource_id: ra.resource_id,
+                    reason: "expired".to_string(),
+                    timestamp: now,
+                });
+            }
+            member_repo.update(member)?;
+        }
+        Ok(events)
+    }
+}
@@ -0,0 +1,255 @@
+use sc_manager_core::domain::Member;
+use sc_manager_core::repositories::{MemberRepository, PermissionRepository, RepositoryError, RoleRepository};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::commands::{AddMemberCommand, UpdateMemberCommand};
+use crate::handlers::member_handler::MemberHandler;
+
+/// Roster entry as listed by RSI. Redacted/hidden members have no handle.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RemoteRosterMember {
+    pub handle: Option<String>,
+    pub rank: Option<String>,
+    pub affiliate: bool,
+}
+
+impl RemoteRosterMember {
+    pub fn new(handle: impl Into<String>, rank: Option<String>) -> Self {
+        Self { handle: Some(handle.into()), rank, affiliate: false }
+    }
+
+    pub fn hidden() -> Self {
+        Self { handle: None, rank: None, affiliate: false }
+    }
+
+    pub fn affiliate(mut self) -> Self {
+        self.affiliate = true;
+        self
+    }
+}
+
+/// One difference between the RSI roster and the member repository.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum RosterChange {
+    /// Listed on RSI but not in the org; `member_id` is set if the handle belongs to a known member outside the org
+    NewApplicant { handle: String, rank: Option<String>, affiliate: bool, member_id: Option<String> },
+    /// Org member whose handle is no longer listed
+    Departed { member_id: String, handle: String },
+    RankChanged { member_id: String, handle: String, from: Option<String>, to: Option<String> },
+}
+
+impl RosterChange {
+    /// Stable key used by officers to accept individual changes.
+    pub fn key(&self) -> String {
+        match self {
+            RosterChange::NewApplicant { handle, .. } => format!("new:{}", handle.to_ascii_lowercase()),
+            RosterChange::Departed { member_id, .. } => format!("departed:{}", member_id),
+            RosterChange::RankChanged { member_id, .. } => format!("rank:{}", member_id),
+        }
+    }
+}
+
+/// Org member whose handle is not listed while the roster has hidden entries; they may be one of them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnverifiableMember {
+    pub member_id: String,
+    pub handle: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RosterDiff {
+    pub org_id: String,
+    pub changes: Vec<RosterChange>,
+    /// Redacted/hidden RSI entries that cannot be matched by handle
+    pub hidden_members: usize,
+    /// Unlisted org members while `hidden_members > 0`; reported instead of `Departed` and never applied
+    pub unverifiable: Vec<UnverifiableMember>,
+}
+
+impl RosterDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn new_applicants(&self) -> impl Iterator<Item = &RosterChange> {
+        self.changes.iter().filter(|c| matches!(c, RosterChange::NewApplicant { .. }))
+    }
+
+    pub fn departed(&self) -> impl Iterator<Item = &RosterChange> {
+        self.changes.iter().filter(|c| matches!(c, RosterChange::Departed { .. }))
+    }
+
+    pub fn rank_changes(&self) -> impl Iterator<Item = &RosterChange> {
+        self.changes.iter().filter(|c| matches!(c, RosterChange::RankChanged { .. }))
+    }
+}
+
+/// Officer sign-off for applying a diff; `accepted: None` accepts every change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RosterConfirmation {
+    pub officer_id: String,
+    pub accepted: Option<HashSet<String>>,
+}
+
+impl RosterConfirmation {
+    pub fn all(officer_id: impl Into<String>) -> Self {
+        Self { officer_id: officer_id.into(), accepted: None }
+    }
+
+    /// Accept only the changes with the given `RosterChange::key`s.
+    pub fn only<K: Into<String>>(officer_id: impl Into<String>, keys: impl IntoIterator<Item = K>) -> Self {
+        Self { officer_id: officer_id.into(), accepted: Some(keys.into_iter().map(Into::into).collect()) }
+    }
+
+    fn accepts(&self, change: &RosterChange) -> bool {
+        self.accepted.as_ref().is_none_or(|keys| keys.contains(&change.key()))
+    }
+}
+
+/// Reconciles an RSI organization roster with the member repository.
+pub struct RosterSyncService;
+
+impl RosterSyncService {
+    /// Member id used for applicants not yet known locally.
+    pub fn member_id_for(handle: &str) -> String {
+        format!("rsi-{}", handle.to_ascii_lowercase())
+    }
+
+    /// Compare the RSI roster of `org_id` with the org's members (matched by RSI handle, case-insensitive).
+    /// Members without an RSI handle are ignored.
+    pub fn diff<M: MemberRepository>(
+        org_id: &str,
+        remote: &[RemoteRosterMember],
+        member_repo: &M,
+    ) -> Result<RosterDiff, RepositoryError> {
+        let by_handle = |members: Vec<Member>| -> BTreeMap<String, Member> {
+            members
+                .into_iter()
+                .filter_map(|m| Some((m.rsi_handle.as_deref()?.to_ascii_lowercase(), m)))
+                .collect()
+        };
+        let org_members = by_handle(member_repo.list_by_org(org_id)?);
+        let all_members = by_handle(member_repo.list_all()?);
+
+        let mut changes = vec![];
+        let mut seen = HashSet::new();
+        let mut hidden_members = 0;
+        for r in remote {
+            let Some(handle) = r.handle.as_deref() else {
+                hidden_members += 1;
+                continue;
+            };
+            let key = handle.to_ascii_lowercase();
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+            match org_members.get(&key) {
+                Some(m) if m.rank != r.rank => changes.push(RosterChange::RankChanged {
+                    member_id: m.id.clone(),
+                    handle: handle.to_string(),
+                    from: m.rank.clone(),
+                    to: r.rank.clone(),
+                }),
+                Some(_) => {}
+                None => changes.push(RosterChange::NewApplicant {
+                    handle: handle.to_string(),
+                    rank: r.rank.clone(),
+                    affiliate: r.affiliate,
+                    member_id: all_members.get(&key).map(|m| m.id.clone()),
+                }),
+            }
+        }
+        let mut unverifiable = vec![];
+        for (key, m) in &org_members {
+            if seen.contains(key) {
+                continue;
+            }
+            let member_id = m.id.clone();
+            let handle = m.rsi_handle.clone().unwrap_or_default();
+            // an unlisted member may have hidden their membership
+            if hidden_members > 0 {
+                unverifiable.push(UnverifiableMember { member_id, handle });
+            } else {
+                changes.push(RosterChange::Departed { member_id, handle });
+            }
+        }
+
+        Ok(RosterDiff { org_id: org_id.to_string(), changes, hidden_members, unverifiable })
+    }
+
+    /// Apply the confirmed changes of `diff` through member commands. The confirming officer needs
+    /// `member.sync_roster` on the organization. Departed members are detached from the org, not deleted.
+    /// Every accepted change is checked against the repository before any is applied, so a stale diff
+    /// fails without mutating members. Returns the applied changes.
+    pub fn apply<M: MemberRepository, R: RoleRepository, P: PermissionRepository>(
+        diff: &RosterDiff,
+        confirmation: &RosterConfirmation,
+        member_repo: &mut M,
+        role_repo: &R,
+        perm_repo: &P,
+    ) -> Result<Vec<RosterChange>, RepositoryError> {
+        let allowed = crate::services::policy_service::PolicyService::check_permission(
+            &confirmation.officer_id,
+            "member.sync_roster",
+            Some(&diff.org_id),
+            member_repo,
+            role_repo,
+            perm_repo,
+        )?;
+        if !allowed {
+            return Err(RepositoryError::Unauthorized);
+        }
+
+        let accepted: Vec<&RosterChange> = diff.changes.iter().filter(|c| confirmation.accepts(c)).collect();
+        for change in &accepted {
+            let (id, must_exist) = match change {
+                RosterChange::NewApplicant { handle, member_id: None, .. } => (Self::member_id_for(handle), false),
+                RosterChange::NewApplicant { member_id: Some(id), .. }
+                | RosterChange::Departed { member_id: id, .. }
+                | RosterChange::RankChanged { member_id: id, .. } => (id.clone(), true),
+            };
+            match (member_repo.get(&id).is_ok(), must_exist) {
+                (false, true) => return Err(RepositoryError::NotFound),
+                (true, false) => return Err(RepositoryError::AlreadyExists),
+                _ => {}
+            }
+        }
+
+        let mut handler = MemberHandler::new(member_repo);
+        let mut applied = vec![];
+        for change in accepted {
+            let update = match change {
+                RosterChange::NewApplicant { handle, rank, member_id, .. } => {
+                    let id = match member_id {
+                        Some(id) => id.clone(),
+                        None => {
+                            let id = Self::member_id_for(handle);
+                            handler.add(AddMemberCommand::new(id.clone(), Some(handle.clone()), None))?;
+                            id
+                        }
+                    };
+                    let mut cmd = UpdateMemberCommand::new(id);
+                    cmd.org_id = Some(Some(diff.org_id.clone()));
+                    cmd.rank = Some(rank.clone());
+                    cmd
+                }
+                RosterChange::Departed { member_id, .. } => {
+                    let mut cmd = UpdateMemberCommand::new(member_id.clone());
+                    cmd.org_id = Some(None);
+                    cmd.rank = Some(None);
+                    cmd
+                }
+                RosterChange::RankChanged { member_id, to, .. } => {
+                    let mut cmd = UpdateMemberCommand::new(member_id.clone());
+                    cmd.rank = Some(to.clone());
+                    cmd
+                }
+            };
+            handler.update(update)?;
+            applied.push(change.clone());
+        }
+        Ok(applied)
+    }
+}
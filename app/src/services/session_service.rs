@@ -1,142 +1,232 @@
-use crate::handlers::event_handler::EventHandler;
-use crate::handlers::session_handler::SessionHandler;
-use sc_manager_core::domain::game_event::GameEventType;
-use sc_manager_core::events::EventEnvelope;
-use sc_manager_core::repositories::{EventRepository, RepositoryError, SessionRepository};
-
-/// Processes game log envelopes and wires them into Event + Session repositories.
-pub struct SessionService;
-
-impl SessionService {
-    pub fn process_envelope<
-        S: SessionRepository,
-        E: EventRepository,
-        M: sc_manager_core::repositories::MemberRepository,
-        R: sc_manager_core::repositories::RoleRepository,
-        P: sc_manager_core::repositories::PermissionRepository,
-    >(
-        env: EventEnvelope,
-        session_repo: &mut S,
-        event_repo: &mut E,
-        mut member_repo: Option<&mut M>,
-        role_repo: Option<&R>,
-        permission_repo: Option<&P>,
-    ) -> Result<(), RepositoryError> {
-        match env {
-            EventEnvelope::GameEvent {
-                id,
-                event_type,
-                timestamp,
-                details,
-            } => {
-                // If SessionStart -> create session
-                match event_type {
-                    GameEventType::SessionStart => {
-                        // extract optional member from details (member=ID)
-                        let mut member_id_opt: Option<String> = None;
-                        if let Some(ref d) = details {
-                            for tok in d.split_whitespace() {
-                                if let Some(rest) = tok.strip_prefix("member=") {
-                                    member_id_opt = Some(rest.trim_matches('"').to_string());
-                                }
-                            }
-                        }
-                        // If actor present and repos provided, check permission to start session
-                        if let Some(ref actor) = member_id_opt {
-                            if let (Some(mrepo_mut), Some(rrepo), Some(prepo)) =
-                                (member_repo.as_mut(), role_repo, permission_repo)
-                            {
-                                let mrepo_ref: &M = mrepo_mut;
-                                let allowed = crate::services::policy_service::PolicyService::can_start_session(actor, None, mrepo_ref, rrepo, prepo)?;
-                                if !allowed {
-                                    return Err(RepositoryError::Unauthorized);
-                                }
-                            }
-                        }
-
-                        let mut handler = SessionHandler::new(session_repo);
-                        let sess_id = format!("sess-{}", timestamp);
-                        handler.start(sess_id.clone(), timestamp, None, member_id_opt.clone())?;
-
-                        // update member if provided
-                        if let (Some(mrepo_mut), Some(mid)) = (member_repo.as_mut(), member_id_opt)
-                        {
-                            if let Ok(mut mm) = mrepo_mut.get(&mid) {
-                                mm.last_session_id = Some(sess_id.clone());
-                                mm.last_seen = Some(timestamp);
-                                mrepo_mut.update(mm)?;
-                            }
-                        }
-
-                        Ok(())
-                    }
-                    GameEventType::SessionEnd => {
-                        // end last active session
-                        // find active session id first (avoid overlapping borrows)
-                        let sessions = session_repo.list_all()?;
-                        if let Some(s) = sessions.into_iter().rev().find(|s| s.is_active()) {
-                            let mut handler = SessionHandler::new(session_repo);
-                            handler.end(&s.id, timestamp)
-                        } else {
-                            Err(RepositoryError::NotFound)
-                        }
-                    }
-                    _ => {
-                        // extract optional member from details (member=ID)
-                        let mut member_id_opt: Option<String> = None;
-                        if let Some(ref d) = details {
-                            for tok in d.split_whitespace() {
-                                if let Some(rest) = tok.strip_prefix("member=") {
-                                    member_id_opt = Some(rest.trim_matches('"').to_string());
-                                }
-                            }
-                        }
-
-                        // If actor present and role+permission repos provided, check permission
-                        if let Some(ref actor) = member_id_opt {
-                            if let (Some(mrepo_mut), Some(rrepo), Some(prepo)) =
-                                (member_repo.as_mut(), role_repo, permission_repo)
-                            {
-                                // create an immutable borrow for check
-                                let mrepo_ref: &M = mrepo_mut;
-                                let allowed = crate::services::policy_service::PolicyService::check_permission(actor, "event.create", None, mrepo_ref, rrepo, prepo)?;
-                                if !allowed {
-                                    return Err(RepositoryError::Unauthorized);
-                                }
-                            }
-                        }
-
-                        // create event and attach to active session
-                        let mut evt_handler = EventHandler::new(event_repo);
-                        let title = if let Some(d) = details {
-                            format!("{:?} {}", event_type, d)
-                        } else {
-                            format!("{:?}", event_type)
-                        };
-                        evt_handler.create(crate::commands::CreateEventCommand::new(
-                            id.clone(),
-                            title,
-                            timestamp,
-                            None,
-                        ))?;
-
-                        // update member last_seen if member repo present
-                        if let Some(ref actor) = member_id_opt {
-                            if let Some(mrepo_mut) = member_repo.as_mut() {
-                                if let Ok(mut mm) = mrepo_mut.get(actor) {
-                                    mm.last_seen = Some(timestamp);
-                                    let _ = mrepo_mut.update(mm);
-                                }
-                            }
-                        }
-
-                        // attach to session if active
-                        let mut sess_handler = SessionHandler::new(session_repo);
-                        sess_handler.add_event_to_active_session(id)
-                    }
-                }
-            }
-            _ => Ok(()),
-        }
-    }
-}
+use crate::handlers::event_handler::EventHandler;
+use crate::handlers::session_handler::SessionHandler;
+use crate::services::policy_service::{PolicyContext, PolicyService};
+use sc_manager_core::domain::game_event::GameEventType;
+use sc_manager_core::domain::{ResourceHierarchy, Session};
+use sc_manager_core::events::EventEnvelope;
+use sc_manager_core::repositories::{EventRepository, RepositoryError, SessionRepository};
+
+/// Processes game log envelopes and wires them into Event + Session repositories.
+///
+/// Sessions are keyed per participant (`member=`) and org (`org=`) taken from the event
+/// details, so several members can stream logs at once. Lines without a participant fall
+/// back to the anonymous session for the org, or to the only active session when there is
+/// exactly one (single-player logs usually omit the member on SessionEnd).
+///
+/// Replays are idempotent: a session's id is derived from its participant, org and start time,
+/// so a replayed `SessionStart` finds its session already recorded and is ignored, and a
+/// `SessionEnd` older than the participant's active session does not end it.
+pub struct SessionService;
+
+/// Default idle timeout for `GameEventIngestor` (4 hours).
+pub const DEFAULT_IDLE_TIMEOUT_SECS: i64 = 4 * 60 * 60;
+
+/// Value of a `key=value` token in game event details, with surrounding quotes removed.
+fn detail_value(details: Option<&str>, key: &str) -> Option<String> {
+    let prefix = format!("{}=", key);
+    details?
+        .split_whitespace()
+        .find_map(|tok| tok.strip_prefix(prefix.as_str()))
+        .map(|v| v.trim_matches('"').to_string())
+        .filter(|v| !v.is_empty())
+}
+
+impl SessionService {
+    /// Session id derived from org, participant and start time. Each part is tagged and
+    /// length-prefixed (`sess-o5.org-1-p5.alice-1610000000`) so distinct tuples never share an id.
+    pub fn session_id(participant: Option<&str>, org_id: Option<&str>, start_ts: i64) -> String {
+        let mut id = String::from("sess");
+        if let Some(org) = org_id {
+            id.push_str(&format!("-o{}.{}", org.len(), org));
+        }
+        if let Some(p) = participant {
+            id.push_str(&format!("-p{}.{}", p.len(), p));
+        }
+        id.push_str(&format!("-{}", start_ts));
+        id
+    }
+
+    /// Active session for a participant/org pair (see type docs for the fallback rules).
+    pub fn find_active_session<S: SessionRepository>(
+        session_repo: &S,
+        participant: Option<&str>,
+        org_id: Option<&str>,
+    ) -> Result<Option<Session>, RepositoryError> {
+        let active: Vec<Session> = session_repo
+            .list_all()?
+            .into_iter()
+            .filter(|s| s.is_active())
+            .collect();
+        let keyed = active
+            .iter()
+            .rev()
+            .find(|s| s.participant.as_deref() == participant && (org_id.is_none() || s.org_id.as_deref() == org_id));
+        if keyed.is_some() || participant.is_some() {
+            return Ok(keyed.cloned());
+        }
+        Ok(if active.len() == 1 { active.into_iter().next() } else { None })
+    }
+
+    /// Ends active sessions with no activity for `idle_timeout` seconds as of `now`.
+    /// The session is closed at its last activity; returns the closed session ids.
+    pub fn close_idle_sessions<S: SessionRepository>(
+        session_repo: &mut S,
+        now: i64,
+        idle_timeout: i64,
+    ) -> Result<Vec<String>, RepositoryError> {
+        let mut closed = vec![];
+        for mut s in session_repo.list_all()?.into_iter().filter(|s| s.is_active()) {
+            let last = s.last_activity();
+            if now - last >= idle_timeout {
+                s.end(last);
+                closed.push(s.id.clone());
+                session_repo.update(s)?;
+            }
+        }
+        Ok(closed)
+    }
+
+    /// Records a game event. When the member, role and permission repositories are given, the
+    /// sender needs `session.start` / `event.create` on the event's org, checked with `hierarchy`
+    /// when one is given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_envelope<
+        S: SessionRepository,
+        E: EventRepository,
+        M: sc_manager_core::repositories::MemberRepository,
+        R: sc_manager_core::repositories::RoleRepository,
+        P: sc_manager_core::repositories::PermissionRepository,
+    >(
+        env: EventEnvelope,
+        session_repo: &mut S,
+        event_repo: &mut E,
+        mut member_repo: Option<&mut M>,
+        role_repo: Option<&R>,
+        permission_repo: Option<&P>,
+        hierarchy: Option<&dyn ResourceHierarchy>,
+    ) -> Result<(), RepositoryError> {
+        let mut ctx = PolicyContext::current();
+        if let Some(h) = hierarchy {
+            ctx = ctx.with_hierarchy(h);
+        }
+        match env {
+            EventEnvelope::GameEvent {
+                id,
+                event_type,
+                timestamp,
+                details,
+            } => {
+                // optional member and org from details (member=ID org=ID)
+                let member_id_opt = detail_value(details.as_deref(), "member");
+                let org_id_opt = detail_value(details.as_deref(), "org");
+
+                match event_type {
+                    GameEventType::SessionStart => {
+                        // If actor present and repos provided, check permission to start session
+                        if let Some(ref actor) = member_id_opt {
+                            if let (Some(mrepo_mut), Some(rrepo), Some(prepo)) =
+                                (member_repo.as_mut(), role_repo, permission_repo)
+                            {
+                                let mrepo_ref: &M = mrepo_mut;
+                                let allowed = PolicyService::can_start_session(&ctx, actor, org_id_opt.as_deref(), mrepo_ref, rrepo, prepo)?;
+                                if !allowed {
+                                    return Err(RepositoryError::Unauthorized);
+                                }
+                            }
+                        }
+
+                        let sess_id = Self::session_id(member_id_opt.as_deref(), org_id_opt.as_deref(), timestamp);
+                        if session_repo.get(&sess_id).is_ok() {
+                            // replayed start of a session that is already recorded
+                            return Ok(());
+                        }
+
+                        // a new start for the same participant implicitly ends the previous session
+                        if let Some(prev) = Self::find_active_session(session_repo, member_id_opt.as_deref(), org_id_opt.as_deref())?
+                            .filter(|s| s.participant == member_id_opt && s.org_id == org_id_opt)
+                        {
+                            SessionHandler::new(session_repo).end(&prev.id, timestamp)?;
+                        }
+
+                        let mut handler = SessionHandler::new(session_repo);
+                        handler.start(sess_id.clone(), timestamp, org_id_opt, member_id_opt.clone())?;
+
+                        // update member if provided
+                        if let (Some(mrepo_mut), Some(mid)) = (member_repo.as_mut(), member_id_opt)
+                        {
+                            if let Ok(mut mm) = mrepo_mut.get(&mid) {
+                                if mm.last_seen.is_none_or(|seen| seen <= timestamp) {
+                                    mm.last_session_id = Some(sess_id.clone());
+                                    mm.last_seen = Some(timestamp);
+                                    mrepo_mut.update(mm)?;
+                                }
+                            }
+                        }
+
+                        Ok(())
+                    }
+                    GameEventType::SessionEnd => {
+                        // end the sender's active session
+                        match Self::find_active_session(session_repo, member_id_opt.as_deref(), org_id_opt.as_deref())? {
+                            // replayed end of an earlier session
+                            Some(s) if s.start_ts > timestamp => Ok(()),
+                            Some(s) => SessionHandler::new(session_repo).end(&s.id, timestamp),
+                            // already ended by a replay or by the idle timeout
+                            None => Ok(()),
+                        }
+                    }
+                    _ => {
+                        // If actor present and role+permission repos provided, check permission
+                        if let Some(ref actor) = member_id_opt {
+                            if let (Some(mrepo_mut), Some(rrepo), Some(prepo)) =
+                                (member_repo.as_mut(), role_repo, permission_repo)
+                            {
+                                // create an immutable borrow for check
+                                let mrepo_ref: &M = mrepo_mut;
+                                let allowed = PolicyService::can_create_event(&ctx, actor, org_id_opt.as_deref(), mrepo_ref, rrepo, prepo)?;
+                                if !allowed {
+                                    return Err(RepositoryError::Unauthorized);
+                                }
+                            }
+                        }
+
+                        // create event and attach to the sender's active session
+                        let mut evt_handler = EventHandler::new(event_repo);
+                        let title = if let Some(d) = details {
+                            format!("{:?} {}", event_type, d)
+                        } else {
+                            format!("{:?}", event_type)
+                        };
+                        evt_handler.create(crate::commands::CreateEventCommand::new(
+                            id.clone(),
+                            title,
+                            timestamp,
+                            org_id_opt.clone(),
+                        ))?;
+
+                        // update member last_seen if member repo present
+                        if let Some(ref actor) = member_id_opt {
+                            if let Some(mrepo_mut) = member_repo.as_mut() {
+                                if let Ok(mut mm) = mrepo_mut.get(actor) {
+                                    mm.last_seen = Some(mm.last_seen.map_or(timestamp, |seen| seen.max(timestamp)));
+                                    let _ = mrepo_mut.update(mm);
+                                }
+                            }
+                        }
+
+                        // attach to session if active
+                        match Self::find_active_session(session_repo, member_id_opt.as_deref(), org_id_opt.as_deref())? {
+                            Some(mut s) => {
+                                s.add_event(id);
+                                s.touch(timestamp);
+                                session_repo.update(s)
+                            }
+                            None => Err(RepositoryError::NotFound),
+                        }
+                    }
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use sc_manager_adapters::game_log::{GameLogParser, SimpleGameLogParser};
+use sc_manager_app::in_memory_event_repo::InMemoryEventRepo;
+use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
+use sc_manager_app::services::session_service::SessionService;
+use sc_manager_core::repositories::SessionRepository;
+
+fn process(lines: &str, session_repo: &mut InMemorySessionRepo, event_repo: &mut InMemoryEventRepo) {
+    let parser = SimpleGameLogParser;
+    for line in lines.lines() {
+        if let Some(env) = parser.parse_line(line) {
+            SessionService::process_envelope(
+                env,
+                session_repo,
+                event_repo,
+                None::<&mut sc_manager_app::in_memory_member_repo::InMemoryMemberRepo>,
+                None::<&sc_manager_app::in_memory_role_repo::InMemoryRoleRepo>,
+                None::<&sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo>,
+                None,
+            )
+            .unwrap();
+        }
+    }
+}
+
+#[test]
+fn interleaved_participants_keep_separate_sessions() {
+    let data = "SessionStart 1610000000 member=alice org=org-1\n\
+                SessionStart 1610000000 member=bob org=org-1\n\
+                Kill 1610000100 member=alice\n\
+                Kill 1610000150 member=bob\n\
+                Kill 1610000160 member=bob\n\
+                SessionEnd 1610000200 member=alice\n\
+                Death 1610000300 member=bob";
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    process(data, &mut session_repo, &mut event_repo);
+
+    let alice = session_repo.get("sess-o5.org-1-p5.alice-1610000000").unwrap();
+    assert_eq!(alice.end_ts, Some(1610000200));
+    assert_eq!(alice.events.len(), 1);
+
+    let bob = session_repo.get("sess-o5.org-1-p3.bob-1610000000").unwrap();
+    assert!(bob.is_active());
+    assert_eq!(bob.events.len(), 3);
+    assert_eq!(bob.last_activity(), 1610000300);
+}
+
+#[test]
+fn replayed_session_start_does_not_reopen_the_session() {
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    process("SessionStart 1610000000\nSessionEnd 1610000000\nSessionStart 1610000000", &mut session_repo, &mut event_repo);
+
+    assert_eq!(session_repo.list_all().unwrap().len(), 1);
+    assert!(session_repo.get("sess-1610000000").unwrap().end_ts.is_some());
+}
+
+#[test]
+fn ids_do_not_collide_across_dashed_org_and_member_ids() {
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    process("SessionStart 1000 member=x org=o-m1\nSessionStart 1000 member=m1-x org=o", &mut session_repo, &mut event_repo);
+
+    assert_eq!(session_repo.list_all().unwrap().len(), 2);
+    assert!(session_repo.get("sess-o4.o-m1-p1.x-1000").unwrap().is_active());
+    assert!(session_repo.get("sess-o1.o-p4.m1-x-1000").unwrap().is_active());
+}
+
+#[test]
+fn idle_sessions_are_closed_at_last_activity() {
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    process("SessionStart 1000 member=alice\nKill 1500 member=alice\nSessionStart 4000 member=bob", &mut session_repo, &mut event_repo);
+
+    let closed = SessionService::close_idle_sessions(&mut session_repo, 5000, 3000).unwrap();
+    assert_eq!(closed, vec!["sess-p5.alice-1000".to_string()]);
+    assert_eq!(session_repo.get("sess-p5.alice-1000").unwrap().end_ts, Some(1500));
+    assert!(session_repo.get("sess-p3.bob-4000").unwrap().is_active());
+}
@@ -0,0 +1,115 @@
+use sc_manager_adapters::game_log::{GameLogParser, SimpleGameLogParser};
+use sc_manager_app::in_memory_event_repo::InMemoryEventRepo;
+use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
+use sc_manager_app::services::game_event_ingestor::DedupeWindow;
+use sc_manager_app::services::GameEventIngestor;
+use sc_manager_core::domain::Event;
+use sc_manager_core::repositories::{EventRepository, SessionRepository};
+
+fn import(ingestor: &mut GameEventIngestor, data: &str, session_repo: &mut InMemorySessionRepo, event_repo: &mut InMemoryEventRepo) -> usize {
+    let parser = SimpleGameLogParser;
+    let mut processed = 0;
+    for line in data.lines() {
+        if let Some(env) = parser.parse_line_from("client-1", line) {
+            let fresh = ingestor
+                .ingest(
+                    env,
+                    session_repo,
+                    event_repo,
+                    None::<&mut sc_manager_app::in_memory_member_repo::InMemoryMemberRepo>,
+                    None::<&sc_manager_app::in_memory_role_repo::InMemoryRoleRepo>,
+                    None::<&sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo>,
+                    None,
+                )
+                .unwrap();
+            if fresh {
+                processed += 1;
+            }
+        }
+    }
+    processed
+}
+
+#[test]
+fn reimporting_a_log_is_safe() {
+    let data = include_str!("fixtures/game_logs/session_and_kills.log");
+    let mut ingestor = GameEventIngestor::default();
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+
+    assert_eq!(import(&mut ingestor, data, &mut session_repo, &mut event_repo), 5);
+    assert_eq!(import(&mut ingestor, data, &mut session_repo, &mut event_repo), 0);
+
+    assert_eq!(session_repo.list_all().unwrap().len(), 1);
+    assert_eq!(event_repo.list_all().unwrap().len(), 3);
+}
+
+#[test]
+fn replays_older_than_the_window_leave_sessions_alone() {
+    let data = "SessionStart 1000 member=alice\nKill 1100 member=alice\nSessionEnd 1200 member=alice\nSessionStart 1300 member=alice";
+    let mut ingestor = GameEventIngestor::new(1);
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+
+    assert_eq!(import(&mut ingestor, data, &mut session_repo, &mut event_repo), 4);
+    assert_eq!(import(&mut ingestor, data, &mut session_repo, &mut event_repo), 4);
+
+    assert_eq!(session_repo.list_all().unwrap().len(), 2);
+    assert_eq!(session_repo.get("sess-p5.alice-1000").unwrap().end_ts, Some(1200));
+    assert!(session_repo.get("sess-p5.alice-1300").unwrap().is_active());
+    assert_eq!(event_repo.list_all().unwrap().len(), 1);
+}
+
+#[test]
+fn idle_sessions_are_closed_as_event_time_advances() {
+    let data = "SessionStart 1000 member=alice\nKill 1500 member=alice\nSessionStart 4000 member=bob\nKill 5000 member=bob";
+    let mut ingestor = GameEventIngestor::default().with_idle_timeout(3000);
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    import(&mut ingestor, data, &mut session_repo, &mut event_repo);
+
+    assert_eq!(session_repo.get("sess-p5.alice-1000").unwrap().end_ts, Some(1500));
+    assert!(session_repo.get("sess-p3.bob-4000").unwrap().is_active());
+
+    // a late end for the closed session is not an error
+    import(&mut ingestor, "SessionEnd 5100 member=alice", &mut session_repo, &mut event_repo);
+    assert_eq!(session_repo.get("sess-p5.alice-1000").unwrap().end_ts, Some(1500));
+}
+
+#[test]
+fn idle_sweeps_wait_for_event_time_to_advance() {
+    let mut ingestor = GameEventIngestor::default().with_idle_timeout(40);
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    import(&mut ingestor, "SessionStart 1000 member=alice\nSessionStart 1050 member=bob", &mut session_repo, &mut event_repo);
+    assert!(session_repo.get("sess-p5.alice-1000").unwrap().is_active());
+
+    import(&mut ingestor, "Kill 1080 member=bob", &mut session_repo, &mut event_repo);
+    assert_eq!(session_repo.get("sess-p5.alice-1000").unwrap().end_ts, Some(1000));
+}
+
+#[test]
+fn kills_in_the_same_second_get_distinct_ids() {
+    let data = "SessionStart 1610000000\nKill 1610000100 killer=alice victim=bob\nKill 1610000100 killer=alice victim=carol";
+    let mut ingestor = GameEventIngestor::default();
+    let mut session_repo = InMemorySessionRepo::new();
+    let mut event_repo = InMemoryEventRepo::new();
+    import(&mut ingestor, data, &mut session_repo, &mut event_repo);
+    assert_eq!(event_repo.list_all().unwrap().len(), 2);
+}
+
+#[test]
+fn append_ignores_seen_ids_and_window_is_bounded() {
+    let mut event_repo = InMemoryEventRepo::new();
+    event_repo.append(Event::new("evt-1", "Kill", 1)).unwrap();
+    event_repo.append(Event::new("evt-1", "Kill", 1)).unwrap();
+    assert_eq!(event_repo.list_all().unwrap().len(), 1);
+
+    let mut window = DedupeWindow::new(2);
+    assert!(window.insert("a"));
+    assert!(!window.insert("a"));
+    window.insert("b");
+    window.insert("c");
+    assert_eq!(window.len(), 2);
+    assert!(!window.contains("a"));
+}
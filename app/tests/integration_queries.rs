@@ -0,0 +1,146 @@
+use sc_manager_app::handlers::QueryHandler;
+use sc_manager_app::in_memory_fleet_repo::InMemoryFleetRepo;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_repo::InMemoryOrganizationRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
+use sc_manager_app::in_memory_ship_repo::InMemoryShipRepo;
+use sc_manager_app::queries::{GetFleetDetailQuery, GetMemberProfileQuery, GetOrganizationQuery, GetSessionHistoryQuery};
+use sc_manager_core::domain::{Fleet, Member, Organization, Role, RoleAssignment, Session, Ship};
+use sc_manager_core::repositories::{
+    FleetRepository, MemberRepository, OrganizationRepository, RepositoryError, RoleRepository, SessionRepository,
+    ShipRepository,
+};
+
+struct Fixture {
+    members: InMemoryMemberRepo,
+    roles: InMemoryRoleRepo,
+    perms: InMemoryPermissionRepo,
+    orgs: InMemoryOrganizationRepo,
+    fleets: InMemoryFleetRepo,
+    ships: InMemoryShipRepo,
+    sessions: InMemorySessionRepo,
+}
+
+fn fixture() -> Fixture {
+    let mut roles = InMemoryRoleRepo::new();
+    let mut officer = Role::new("officer", "Officer");
+    officer.add_permission("org.read");
+    officer.add_permission("member.read");
+    officer.add_permission("session.read");
+    roles.create(officer).unwrap();
+
+    let mut members = InMemoryMemberRepo::new();
+    let mut alice = Member::new("alice");
+    alice.org_id = Some("org-1".to_string());
+    alice.online = true;
+    alice.add_role_assignment(RoleAssignment::new("officer", Some("org-1".to_string())));
+    members.add(alice).unwrap();
+    let mut bob = Member::new("bob");
+    bob.org_id = Some("org-1".to_string());
+    bob.last_seen = Some(500);
+    members.add(bob).unwrap();
+
+    let mut orgs = InMemoryOrganizationRepo::new();
+    orgs.create(Organization::new("org-1", "Test Org")).unwrap();
+
+    let mut ships = InMemoryShipRepo::new();
+    let mut cutlass = Ship::new("ship-1", "Cutlass");
+    cutlass.owner_org = Some("org-1".to_string());
+    ships.register(cutlass.clone()).unwrap();
+
+    let mut fleets = InMemoryFleetRepo::new();
+    let mut fleet = Fleet::new("fleet-1", "Alpha").with_org("org-1");
+    fleet.add_ship(cutlass);
+    fleets.create(fleet).unwrap();
+
+    let mut sessions = InMemorySessionRepo::new();
+    for (id, start) in [("s1", 100), ("s2", 300), ("s3", 200)] {
+        let mut s = Session::new(id, start, Some("org-1".to_string()), Some("bob".to_string()));
+        s.end(start + 60);
+        sessions.create(s).unwrap();
+    }
+
+    Fixture {
+        members,
+        roles,
+        perms: InMemoryPermissionRepo::new(),
+        orgs,
+        fleets,
+        ships,
+        sessions,
+    }
+}
+
+#[test]
+fn org_overview_counts_members_fleets_and_ships() {
+    let f = fixture();
+    let q = QueryHandler::new(&f.members, &f.roles, &f.perms);
+    let overview = q.org_overview("alice", GetOrganizationQuery::new("org-1"), &f.orgs, &f.fleets, &f.ships).unwrap();
+    assert_eq!(overview.members.total, 2);
+    assert_eq!(overview.members.online, 1);
+    assert_eq!(overview.fleets, vec!["fleet-1".to_string()]);
+    assert_eq!(overview.ship_count, 1);
+
+    let denied = q.org_overview("bob", GetOrganizationQuery::new("org-1"), &f.orgs, &f.fleets, &f.ships);
+    assert_eq!(denied.unwrap_err(), RepositoryError::Unauthorized);
+}
+
+#[test]
+fn member_profile_lists_recent_sessions_newest_first() {
+    let f = fixture();
+    let q = QueryHandler::new(&f.members, &f.roles, &f.perms);
+    let mut query = GetMemberProfileQuery::new("bob").in_org("org-1");
+    query.recent_sessions = 2;
+    let profile = q.member_profile("alice", query, &f.sessions).unwrap();
+    assert_eq!(profile.last_seen, Some(500));
+    let ids: Vec<&str> = profile.recent_sessions.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["s2", "s3"]);
+    assert_eq!(profile.recent_sessions[0].duration_secs, Some(60));
+
+    // members may always read their own profile
+    assert!(q.member_profile("bob", GetMemberProfileQuery::new("bob"), &f.sessions).is_ok());
+    assert_eq!(
+        q.member_profile("bob", GetMemberProfileQuery::new("alice").in_org("org-1"), &f.sessions).unwrap_err(),
+        RepositoryError::Unauthorized
+    );
+}
+
+#[test]
+fn member_profile_authorizes_before_looking_up_the_member() {
+    let f = fixture();
+    let q = QueryHandler::new(&f.members, &f.roles, &f.perms);
+    // an org-scoped grant does not cover a lookup without an org
+    assert_eq!(
+        q.member_profile("alice", GetMemberProfileQuery::new("bob"), &f.sessions).unwrap_err(),
+        RepositoryError::Unauthorized
+    );
+    // unknown members are only reported to authorized callers
+    assert_eq!(
+        q.member_profile("bob", GetMemberProfileQuery::new("ghost").in_org("org-1"), &f.sessions).unwrap_err(),
+        RepositoryError::Unauthorized
+    );
+    assert_eq!(
+        q.member_profile("alice", GetMemberProfileQuery::new("ghost").in_org("org-1"), &f.sessions).unwrap_err(),
+        RepositoryError::NotFound
+    );
+}
+
+#[test]
+fn fleet_detail_and_session_history_are_authorized() {
+    let f = fixture();
+    let q = QueryHandler::new(&f.members, &f.roles, &f.perms);
+    assert_eq!(
+        q.fleet_detail("alice", GetFleetDetailQuery::new("fleet-1"), &f.fleets).unwrap_err(),
+        RepositoryError::Unauthorized
+    );
+
+    let mut history = GetSessionHistoryQuery::for_org("org-1");
+    history.since = Some(200);
+    let sessions = q.session_history("alice", history, &f.sessions).unwrap();
+    assert_eq!(sessions.len(), 2);
+
+    let own = q.session_history("bob", GetSessionHistoryQuery::for_participant("bob"), &f.sessions).unwrap();
+    assert_eq!(own.len(), 3);
+}
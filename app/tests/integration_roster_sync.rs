@@ -0,0 +1,127 @@
+use sc_manager_adapters::rsi::{HttpRsiClient, RsiOrgMember};
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::services::{RemoteRosterMember, RosterChange, RosterConfirmation, RosterSyncService};
+use sc_manager_core::domain::{Member, Role};
+use sc_manager_core::repositories::{MemberRepository, RepositoryError, RoleRepository};
+
+fn card(handle: &str, rank: &str, affiliate: bool) -> String {
+    format!(
+        r#"<li class="member-item js-member-item org-visibility-V">{}<span class="name">{h}</span><span class="nick">{h}</span><span class="rank">{r}</span></li>"#,
+        if affiliate { r#"<span class="title">Affiliate</span>"# } else { "" },
+        h = handle,
+        r = rank
+    )
+}
+
+fn remote(m: RsiOrgMember) -> RemoteRosterMember {
+    RemoteRosterMember { handle: m.handle, rank: m.rank, affiliate: m.affiliate }
+}
+
+fn member(id: &str, handle: &str, org: Option<&str>, rank: Option<&str>) -> Member {
+    let mut m = Member::new(id);
+    m.rsi_handle = Some(handle.into());
+    m.org_id = org.map(Into::into);
+    m.rank = rank.map(Into::into);
+    m
+}
+
+fn setup() -> (InMemoryMemberRepo, InMemoryRoleRepo) {
+    let mut members = InMemoryMemberRepo::new();
+    members.add(member("m-alice", "Alice_SF", Some("TESTSQ"), Some("Lieutenant"))).unwrap();
+    members.add(member("m-bob", "bob", Some("TESTSQ"), Some("Recruit"))).unwrap();
+    members.add(member("m-dave", "dave", None, None)).unwrap();
+    let mut officer = Member::new("officer");
+    officer.org_id = Some("TESTSQ".into());
+    officer.assign_role("r-officer", Some("TESTSQ".into()));
+    members.add(officer).unwrap();
+
+    let mut roles = InMemoryRoleRepo::new();
+    let mut r = Role::new("r-officer", "Officer");
+    r.add_permission("member.sync_roster");
+    roles.create(r).unwrap();
+    (members, roles)
+}
+
+#[test]
+fn roster_diff_from_rsi_is_applied_after_confirmation() {
+    let server = httpmock::MockServer::start();
+    let html = [card("alice_sf", "Captain", false), card("carol", "Recruit", true), card("dave", "Member", false)].concat()
+        + r#"<li class="member-item js-member-item org-visibility-R"><span class="nick">REDACTED</span></li>"#;
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/api/orgs/getOrgMembers").json_body_partial(r#"{"symbol": "TESTSQ"}"#);
+        then.status(200).json_body(serde_json::json!({"success": 1, "code": "OK", "msg": "OK", "data": {"totalrows": 4, "html": html}}));
+    });
+    let roster: Vec<RemoteRosterMember> = HttpRsiClient::new()
+        .with_base_url(server.base_url())
+        .fetch_org_roster("TESTSQ")
+        .expect("roster")
+        .into_iter()
+        .map(remote)
+        .collect();
+    assert_eq!(roster.len(), 4);
+
+    let (mut members, roles) = setup();
+    let perms = InMemoryPermissionRepo::new();
+    let diff = RosterSyncService::diff("TESTSQ", &roster, &members).unwrap();
+    assert_eq!(diff.hidden_members, 1);
+    assert_eq!(diff.new_applicants().count(), 2);
+    // bob is unlisted but may be the hidden entry
+    assert_eq!(diff.departed().count(), 0);
+    assert_eq!(diff.unverifiable.iter().map(|u| u.member_id.as_str()).collect::<Vec<_>>(), vec!["m-bob"]);
+    assert_eq!(
+        diff.rank_changes().collect::<Vec<_>>(),
+        vec![&RosterChange::RankChanged {
+            member_id: "m-alice".into(),
+            handle: "alice_sf".into(),
+            from: Some("Lieutenant".into()),
+            to: Some("Captain".into()),
+        }]
+    );
+    assert!(diff.changes.contains(&RosterChange::NewApplicant {
+        handle: "dave".into(),
+        rank: Some("Member".into()),
+        affiliate: false,
+        member_id: Some("m-dave".into()),
+    }));
+
+    // members without the permission cannot apply
+    let denied = RosterSyncService::apply(&diff, &RosterConfirmation::all("m-bob"), &mut members, &roles, &perms);
+    assert_eq!(denied.unwrap_err(), RepositoryError::Unauthorized);
+
+    let applied = RosterSyncService::apply(&diff, &RosterConfirmation::all("officer"), &mut members, &roles, &perms).unwrap();
+    assert_eq!(applied.len(), 3);
+
+    assert_eq!(members.get("m-alice").unwrap().rank.as_deref(), Some("Captain"));
+    let carol = members.get(&RosterSyncService::member_id_for("carol")).unwrap();
+    assert_eq!(carol.org_id.as_deref(), Some("TESTSQ"));
+    assert_eq!(carol.rank.as_deref(), Some("Recruit"));
+    assert_eq!(members.get("m-dave").unwrap().org_id.as_deref(), Some("TESTSQ"));
+    assert_eq!(members.get("m-bob").unwrap().org_id.as_deref(), Some("TESTSQ"));
+
+    // a second pass has nothing left to apply
+    let diff = RosterSyncService::diff("TESTSQ", &roster, &members).unwrap();
+    assert!(diff.is_empty());
+
+    // without hidden entries bob is reported as departed
+    let visible: Vec<RemoteRosterMember> = roster.into_iter().filter(|r| r.handle.is_some()).collect();
+    let diff = RosterSyncService::diff("TESTSQ", &visible, &members).unwrap();
+    assert_eq!(diff.changes.iter().map(|c| c.key()).collect::<Vec<_>>(), vec!["departed:m-bob".to_string()]);
+    assert!(diff.unverifiable.is_empty());
+}
+
+#[test]
+fn stale_diff_is_rejected_before_any_change() {
+    let (mut members, roles) = setup();
+    let perms = InMemoryPermissionRepo::new();
+    let roster = vec![RemoteRosterMember::new("alice_sf", Some("Captain".into())), RemoteRosterMember::new("erin", None)];
+    let diff = RosterSyncService::diff("TESTSQ", &roster, &members).unwrap();
+    // erin is added locally after the diff was taken
+    members.add(member(&RosterSyncService::member_id_for("erin"), "erin", None, None)).unwrap();
+
+    let res = RosterSyncService::apply(&diff, &RosterConfirmation::all("officer"), &mut members, &roles, &perms);
+    assert_eq!(res.unwrap_err(), RepositoryError::AlreadyExists);
+    assert_eq!(members.get("m-alice").unwrap().rank.as_deref(), Some("Lieutenant"));
+    assert_eq!(members.get("m-bob").unwrap().org_id.as_deref(), Some("TESTSQ"));
+}
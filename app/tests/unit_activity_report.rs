@@ -0,0 +1,113 @@
+use sc_manager_app::handlers::QueryHandler;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
+use sc_manager_app::queries::GetActivityReportQuery;
+use sc_manager_app::services::ActivityReportService;
+use sc_manager_core::domain::{Member, Role, RoleAssignment, Session};
+use sc_manager_core::repositories::{MemberRepository, RepositoryError, RoleRepository, SessionRepository};
+
+const DAY: i64 = 86_400;
+
+fn member(id: &str, division: Option<&str>, last_seen: Option<i64>) -> Member {
+    let mut m = Member::new(id);
+    m.org_id = Some("org-1".to_string());
+    m.division_id = division.map(str::to_string);
+    m.last_seen = last_seen;
+    m
+}
+
+fn setup() -> (InMemoryMemberRepo, InMemorySessionRepo) {
+    let mut members = InMemoryMemberRepo::new();
+    members.add(member("alice", Some("div-ops"), None)).unwrap();
+    members.add(member("bob", Some("div-ops"), Some(2 * DAY))).unwrap();
+    members.add(member("carol", None, None)).unwrap();
+
+    let mut sessions = InMemorySessionRepo::new();
+    // alice plays one hour on days 10, 11 and 13, and is still online on day 14
+    for (i, day) in [10, 11, 13].iter().enumerate() {
+        let mut s = Session::new(format!("a{}", i), day * DAY, None, Some("alice".to_string()));
+        s.end(day * DAY + 3600);
+        sessions.create(s).unwrap();
+    }
+    sessions.create(Session::new("a-live", 14 * DAY, None, Some("alice".to_string()))).unwrap();
+    (members, sessions)
+}
+
+#[test]
+fn report_computes_playtime_streaks_and_inactivity() {
+    let (members, sessions) = setup();
+    let report = ActivityReportService::build("org-1", 10 * DAY, 14 * DAY + 1800, 7, &members, &sessions).unwrap();
+
+    let alice = &report.members[0];
+    assert_eq!(alice.member_id, "alice");
+    assert_eq!(alice.sessions, 4);
+    assert_eq!(alice.playtime_secs, 3 * 3600 + 1800);
+    assert_eq!(alice.longest_streak_days, 2);
+    assert_eq!(alice.current_streak_days, 2);
+    assert_eq!(alice.days_inactive, Some(0));
+
+    assert_eq!(report.inactive, vec!["bob".to_string(), "carol".to_string()]);
+    let bob = &report.members[1];
+    assert_eq!(bob.days_inactive, Some(12));
+
+    let ops = report.divisions.iter().find(|d| d.division_id.as_deref() == Some("div-ops")).unwrap();
+    assert_eq!((ops.members, ops.active_members, ops.sessions), (2, 1, 4));
+    let none = report.divisions.iter().find(|d| d.division_id.is_none()).unwrap();
+    assert_eq!(none.members, 1);
+}
+
+#[test]
+fn midnight_aligned_period_end_is_exclusive() {
+    let mut members = InMemoryMemberRepo::new();
+    members.add(member("alice", None, None)).unwrap();
+    let mut sessions = InMemorySessionRepo::new();
+    sessions.create(Session::new("live", DAY + 100, None, Some("alice".to_string()))).unwrap();
+
+    let report = ActivityReportService::build("org-1", 0, 2 * DAY, 7, &members, &sessions).unwrap();
+    let alice = &report.members[0];
+    assert_eq!(alice.longest_streak_days, 1);
+    assert_eq!(alice.current_streak_days, 1);
+}
+
+#[test]
+fn report_exports_csv_and_requires_permission() {
+    let (members, sessions) = setup();
+    let report = ActivityReportService::build("org-1", 10 * DAY, 15 * DAY, 7, &members, &sessions).unwrap();
+    let csv = report.to_csv().unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "member_id,division_id,sessions,playtime_hours,longest_streak_days,current_streak_days,last_active,days_inactive,inactive"
+    );
+    assert!(lines.next().unwrap().starts_with("alice,div-ops,4,27.00,"));
+    assert_eq!(csv.lines().count(), 4);
+
+    let (roles, perms) = (InMemoryRoleRepo::new(), InMemoryPermissionRepo::new());
+    let query = GetActivityReportQuery::new("org-1", 10 * DAY, 15 * DAY);
+    let denied = QueryHandler::new(&members, &roles, &perms).activity_report("bob", query, &sessions);
+    assert_eq!(denied.unwrap_err(), RepositoryError::Unauthorized);
+}
+
+#[test]
+fn report_query_is_authorized_on_the_org() {
+    let (mut members, sessions) = setup();
+    let mut roles = InMemoryRoleRepo::new();
+    let mut r = Role::new("officer", "Officer");
+    r.add_permission("member.read");
+    roles.create(r).unwrap();
+    let mut officer = Member::new("officer");
+    officer.add_role_assignment(RoleAssignment::new("officer", Some("org-1".to_string())));
+    members.add(officer).unwrap();
+    let perms = InMemoryPermissionRepo::new();
+    let q = QueryHandler::new(&members, &roles, &perms);
+
+    let mut query = GetActivityReportQuery::new("org-1", 10 * DAY, 15 * DAY);
+    query.inactive_after_days = 7;
+    let report = q.activity_report("officer", query, &sessions).unwrap();
+    assert_eq!(report, ActivityReportService::build("org-1", 10 * DAY, 15 * DAY, 7, &members, &sessions).unwrap());
+
+    let other = q.activity_report("officer", GetActivityReportQuery::new("org-2", 10 * DAY, 15 * DAY), &sessions);
+    assert_eq!(other.unwrap_err(), RepositoryError::Unauthorized);
+}
@@ -0,0 +1,101 @@
+use sc_manager_app::command_bus::{Caller, CommandBus, Dispatched, IdempotencyMiddleware, PolicyRepos, SigningMiddleware};
+use sc_manager_app::commands::{CreateFleetCommand, RegisterShipCommand};
+use sc_manager_app::handlers::fleet_handler::FleetHandler;
+use sc_manager_app::in_memory_fleet_repo::InMemoryFleetRepo;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_ship_repo::InMemoryShipRepo;
+use sc_manager_core::domain::{Member, Role, RoleAssignment};
+use sc_manager_core::events::{generate_test_keypair, verify_signature, SignedEvent};
+use sc_manager_core::repositories::{FleetRepository, MemberRepository, RepositoryError, RoleRepository};
+
+fn setup() -> (InMemoryMemberRepo, InMemoryRoleRepo, InMemoryPermissionRepo) {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut r = Role::new("quartermaster", "Quartermaster");
+    r.add_permission("fleet.create");
+    r.add_permission("ship.register");
+    role_repo.create(r).unwrap();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let mut m = Member::new("alice");
+    m.add_role_assignment(RoleAssignment::new("quartermaster", None));
+    member_repo.add(m).unwrap();
+    member_repo.add(Member::new("bob")).unwrap();
+    (member_repo, role_repo, InMemoryPermissionRepo::new())
+}
+
+#[test]
+fn dispatch_authorizes_and_dedupes() {
+    let (mut members, mut roles, mut perms) = setup();
+    let mut repos = PolicyRepos::new(&mut members, &mut roles, &mut perms);
+    let mut fleet_repo = InMemoryFleetRepo::new();
+    let mut ship_repo = InMemoryShipRepo::new();
+    let mut bus = CommandBus::with_defaults();
+
+    let mut create = |bus: &mut CommandBus<_, _, _>, repos: &mut PolicyRepos<_, _, _>, actor: &str| {
+        let caller = Caller::new(actor).with_idempotency_key("req-1");
+        bus.dispatch(caller, CreateFleetCommand::new("fleet-1", "Alpha"), repos, |cmd, _| {
+            FleetHandler::new(&mut fleet_repo, &mut ship_repo).create(cmd.clone())
+        })
+    };
+    assert_eq!(create(&mut bus, &mut repos, "bob"), Err(RepositoryError::Unauthorized));
+    assert_eq!(create(&mut bus, &mut repos, "alice"), Ok(Dispatched::Executed(())));
+    assert_eq!(create(&mut bus, &mut repos, "alice"), Ok(Dispatched::Duplicate));
+    assert!(fleet_repo.get("fleet-1").is_ok());
+}
+
+#[test]
+fn idempotency_is_keyed_on_the_caller_and_bounded() {
+    let (mut members, mut roles, mut perms) = setup();
+    let mut repos = PolicyRepos::new(&mut members, &mut roles, &mut perms);
+    let mut bus = CommandBus::new().with(IdempotencyMiddleware::with_capacity(2));
+    let mut run = |bus: &mut CommandBus<_, _, _>, caller: Caller<'_>| {
+        bus.dispatch(caller, RegisterShipCommand::new("ship-1", "Cutlass", None), &mut repos, |_, _| Ok(()))
+    };
+
+    // without a key the same command is not treated as a retry
+    assert_eq!(run(&mut bus, "alice".into()), Ok(Dispatched::Executed(())));
+    assert_eq!(run(&mut bus, "alice".into()), Ok(Dispatched::Executed(())));
+
+    let retry = Caller::new("alice").with_idempotency_key("a");
+    assert_eq!(run(&mut bus, retry), Ok(Dispatched::Executed(())));
+    assert_eq!(run(&mut bus, retry), Ok(Dispatched::Duplicate));
+    // keys are per actor
+    assert_eq!(run(&mut bus, Caller::new("bob").with_idempotency_key("a")), Ok(Dispatched::Executed(())));
+    // the oldest key is forgotten once the window is full
+    assert_eq!(run(&mut bus, Caller::new("alice").with_idempotency_key("b")), Ok(Dispatched::Executed(())));
+    assert_eq!(run(&mut bus, retry), Ok(Dispatched::Executed(())));
+}
+
+#[test]
+fn invalid_command_is_rejected_before_handler() {
+    let (mut members, mut roles, mut perms) = setup();
+    let mut repos = PolicyRepos::new(&mut members, &mut roles, &mut perms);
+    let mut bus = CommandBus::with_defaults();
+    let res = bus.dispatch("alice", CreateFleetCommand::new("fleet-1", " "), &mut repos, |_, _| -> Result<(), RepositoryError> {
+        panic!("handler must not run")
+    });
+    assert_eq!(res, Err(RepositoryError::InvalidInput));
+}
+
+#[test]
+fn successful_commands_emit_signed_events() {
+    let (mut members, mut roles, mut perms) = setup();
+    let mut repos = PolicyRepos::new(&mut members, &mut roles, &mut perms);
+    let kp = generate_test_keypair().unwrap();
+    let mut events: Vec<SignedEvent> = vec![];
+    {
+        let mut bus = CommandBus::with_defaults().with(SigningMiddleware::new(&kp, &mut events));
+        let cmd = RegisterShipCommand::new("ship-1", "Cutlass", None);
+        let caller = Caller::new("alice").with_idempotency_key("req-7");
+        bus.dispatch(caller, cmd, &mut repos, |_, _| Ok(())).unwrap();
+        bus.dispatch("bob", RegisterShipCommand::new("ship-2", "Aurora", None), &mut repos, |_, _| Ok(()))
+            .unwrap_err();
+    }
+    assert_eq!(events.len(), 1);
+    assert!(verify_signature(&events[0]));
+    assert_eq!(events[0].event.kind, "ship.register");
+    assert_eq!(events[0].event.id, "ship.register:req-7");
+    assert_eq!(events[0].event.payload["actor"], "alice");
+    assert_eq!(events[0].event.payload["command"]["model"], "Cutlass");
+}
@@ -0,0 +1,133 @@
+use sc_manager_app::command_bus::{AuthorizationMiddleware, CommandBus, Dispatched, PolicyRepos};
+use sc_manager_app::commands::{AssignPermissionToRoleCommand, AssignRoleCommand, StartSessionCommand};
+use sc_manager_app::handlers::{MemberHandler, RoleHandler};
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::services::{PermissionCache, PolicyContext};
+use sc_manager_core::domain::member::AssignmentCondition;
+use sc_manager_core::domain::{Member, Role, RoleAssignment};
+use sc_manager_core::events::EventEnvelope;
+use sc_manager_core::repositories::{MemberRepository, PermissionRepository, RepositoryError, RoleRepository};
+
+fn setup() -> (InMemoryMemberRepo, InMemoryRoleRepo, InMemoryPermissionRepo) {
+    let mut role_repo = InMemoryRoleRepo::new();
+    role_repo.create(Role::new("pilot", "Pilot")).unwrap();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let mut m = Member::new("alice");
+    m.add_role_assignment(RoleAssignment::new("pilot", None));
+    member_repo.add(m).unwrap();
+    (member_repo, role_repo, InMemoryPermissionRepo::new())
+}
+
+#[test]
+fn cache_serves_stale_until_invalidated_by_events() {
+    let (mut member_repo, mut role_repo, perm_repo) = setup();
+    let registry = prometheus::Registry::new();
+    let mut cache = PermissionCache::new().with_metrics(&registry).unwrap();
+    let ctx = PolicyContext::at(0);
+
+    assert!(!cache.check_permission_in(&ctx, "alice", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    // the role gains the permission; cached entry is still used
+    let mut r = role_repo.get("pilot").unwrap();
+    r.add_permission("session.start");
+    role_repo.update(r).unwrap();
+    assert!(!cache.check_permission_in(&ctx, "alice", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    cache.handle_event(&EventEnvelope::RolePermissionChanged { role_id: "pilot".into(), timestamp: 1 });
+    assert!(cache.is_empty());
+    assert!(cache.check_permission_in(&ctx, "alice", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    // revoking the assignment
+    let mut m = member_repo.get("alice").unwrap();
+    m.roles.clear();
+    member_repo.update(m).unwrap();
+    cache.handle_event(&EventEnvelope::RoleRevoked {
+        member_id: "alice".into(),
+        role_id: "pilot".into(),
+        resource_id: None,
+        reason: "manual".into(),
+        timestamp: 2,
+    });
+    assert!(!cache.check_permission_in(&ctx, "alice", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    let mut buf = Vec::new();
+    prometheus::Encoder::encode(&prometheus::TextEncoder::new(), &registry.gather(), &mut buf).unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.contains("policy_cache_hits_total 1"));
+    assert!(text.contains("policy_cache_misses_total 3"));
+}
+
+#[test]
+fn member_online_condition_reads_current_presence() {
+    let (mut member_repo, mut role_repo, perm_repo) = setup();
+    let mut r = Role::new("on-duty", "On Duty");
+    r.add_permission("event.create");
+    role_repo.create(r).unwrap();
+    let mut m = member_repo.get("alice").unwrap();
+    m.add_role_assignment(RoleAssignment::new("on-duty", None).with_condition(AssignmentCondition::MemberOnline));
+    member_repo.update(m).unwrap();
+
+    let mut cache = PermissionCache::new();
+    let ctx = PolicyContext::at(0);
+    assert!(!cache.check_permission_in(&ctx, "alice", "event.create", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    let mut m = member_repo.get("alice").unwrap();
+    m.online = true;
+    member_repo.update(m).unwrap();
+    assert!(cache.check_permission_in(&ctx, "alice", "event.create", None, &member_repo, &role_repo, &perm_repo).unwrap());
+}
+
+#[test]
+fn cache_on_the_bus_is_invalidated_by_handler_events() {
+    let (mut member_repo, mut role_repo, mut perm_repo) = setup();
+    perm_repo.register_catalog().unwrap();
+    role_repo.create(Role::new("quartermaster", "Quartermaster")).unwrap();
+    let mut officer = Member::new("officer");
+    officer.add_role_assignment(RoleAssignment::new("quartermaster", None));
+    member_repo.add(officer).unwrap();
+    let mut r = role_repo.get("quartermaster").unwrap();
+    r.add_permission("member.assign_role");
+    r.add_permission("role.update");
+    role_repo.update(r).unwrap();
+    role_repo.create(Role::new("starter", "Starter")).unwrap();
+
+    let auth = AuthorizationMiddleware::new().with_cache(PermissionCache::new());
+    let mut bus = CommandBus::new().with(auth);
+    let mut repos = PolicyRepos::new(&mut member_repo, &mut role_repo, &mut perm_repo);
+    let start = |bus: &mut CommandBus<_, _, _>, repos: &mut PolicyRepos<_, _, _>| {
+        bus.dispatch("alice", StartSessionCommand::new("s1", 0, None), repos, |_, _| Ok(()))
+    };
+
+    // alice is cached without session.start
+    assert_eq!(start(&mut bus, &mut repos), Err(RepositoryError::Unauthorized));
+
+    // RoleAssigned from the member handler drops alice's entry
+    let assign = AssignRoleCommand::new("alice", "starter", None);
+    bus.dispatch("officer", assign, &mut repos, |cmd, repos| MemberHandler::new(repos.members).assign_role(cmd.clone()))
+        .unwrap();
+    assert_eq!(start(&mut bus, &mut repos), Err(RepositoryError::Unauthorized));
+
+    // RolePermissionChanged from the role handler drops every holder of the role
+    let grant = AssignPermissionToRoleCommand::new("starter", "session.start");
+    bus.dispatch("officer", grant, &mut repos, |cmd, repos| {
+        RoleHandler::new(repos.roles, repos.permissions).assign_permission(cmd.clone())
+    })
+    .unwrap();
+    assert_eq!(start(&mut bus, &mut repos), Ok(Dispatched::Executed(())));
+
+    // events raised outside a dispatch, e.g. by the sweeper, are published on the bus
+    let mut m = repos.members.get("alice").unwrap();
+    m.roles.retain(|ra| ra.role_id != "starter");
+    repos.members.update(m).unwrap();
+    assert_eq!(start(&mut bus, &mut repos), Ok(Dispatched::Executed(())));
+    bus.publish(&EventEnvelope::RoleRevoked {
+        member_id: "alice".into(),
+        role_id: "starter".into(),
+        resource_id: None,
+        reason: "expired".into(),
+        timestamp: 3,
+    });
+    assert_eq!(start(&mut bus, &mut repos), Err(RepositoryError::Unauthorized));
+}
@@ -0,0 +1,88 @@
+use sc_manager_app::commands::{AssignPermissionToRoleCommand, CreatePermissionCommand, CreateRoleCommand};
+use sc_manager_app::handlers::role_handler::RoleHandler;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::services::policy_service::PolicyService;
+use sc_manager_core::repositories::{MemberRepository, PermissionRepository, RepositoryError, RoleRepository};
+
+#[test]
+fn wildcard_role_grants_namespaced_permissions() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let perm_repo = InMemoryPermissionRepo::with_catalog();
+
+    let mut r = sc_manager_core::domain::Role::new("fleet-admin", "Fleet Admin");
+    r.add_permission("fleet.*");
+    role_repo.create(r).unwrap();
+
+    let mut m = sc_manager_core::domain::Member::new("alice");
+    m.assign_role("fleet-admin", None);
+    member_repo.add(m).unwrap();
+
+    for perm in ["fleet.create", "fleet.update", "fleet.delete"] {
+        assert!(PolicyService::check_permission("alice", perm, None, &member_repo, &role_repo, &perm_repo).unwrap());
+    }
+    assert!(!PolicyService::check_permission("alice", "org.create", None, &member_repo, &role_repo, &perm_repo).unwrap());
+}
+
+#[test]
+fn global_wildcard_grants_everything() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let perm_repo = InMemoryPermissionRepo::new();
+
+    let mut r = sc_manager_core::domain::Role::new("owner", "Owner");
+    r.add_permission("*");
+    role_repo.create(r).unwrap();
+
+    let mut m = sc_manager_core::domain::Member::new("root");
+    m.assign_role("owner", None);
+    member_repo.add(m).unwrap();
+
+    assert!(PolicyService::check_permission("root", "org.create", None, &member_repo, &role_repo, &perm_repo).unwrap());
+    assert!(PolicyService::check_permission("root", "session.end", Some("orgA"), &member_repo, &role_repo, &perm_repo).unwrap());
+}
+
+#[test]
+fn assign_permission_accepts_catalog_ids_and_valid_wildcards() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut perm_repo = InMemoryPermissionRepo::with_catalog();
+    assert!(perm_repo.contains("fleet.create"));
+
+    let mut handler = RoleHandler::new(&mut role_repo, &mut perm_repo);
+    handler.create(CreateRoleCommand::new("r1", "Admin")).unwrap();
+
+    handler.assign_permission(AssignPermissionToRoleCommand::new("r1", "fleet.create")).unwrap();
+    handler.assign_permission(AssignPermissionToRoleCommand::new("r1", "ship.*")).unwrap();
+    handler.assign_permission(AssignPermissionToRoleCommand::new("r1", "*")).unwrap();
+
+    assert_eq!(
+        handler.assign_permission(AssignPermissionToRoleCommand::new("r1", "fleet.fly")).unwrap_err(),
+        RepositoryError::NotFound
+    );
+    assert_eq!(
+        handler.assign_permission(AssignPermissionToRoleCommand::new("r1", "fleet..*")).unwrap_err(),
+        RepositoryError::InvalidInput
+    );
+
+    let r = role_repo.get("r1").unwrap();
+    assert_eq!(r.permissions, vec!["fleet.create", "ship.*", "*"]);
+}
+
+#[test]
+fn create_permission_rejects_wildcards_and_malformed_ids() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut perm_repo = InMemoryPermissionRepo::new();
+    let mut handler = RoleHandler::new(&mut role_repo, &mut perm_repo);
+
+    assert_eq!(
+        handler.create_permission(CreatePermissionCommand::new("fleet.*", "All fleet")).unwrap_err(),
+        RepositoryError::InvalidInput
+    );
+    assert_eq!(
+        handler.create_permission(CreatePermissionCommand::new("Fleet Create", "Bad")).unwrap_err(),
+        RepositoryError::InvalidInput
+    );
+    handler.create_permission(CreatePermissionCommand::new("fleet.archive", "Archive fleets")).unwrap();
+}
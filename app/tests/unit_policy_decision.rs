@@ -0,0 +1,62 @@
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::services::policy_service::{AssignmentOutcome, DenyRule};
+use sc_manager_app::services::{PolicyContext, PolicyService};
+use sc_manager_core::domain::{Member, Role, RoleAssignment};
+use sc_manager_core::repositories::{MemberRepository, RepositoryError, RoleRepository};
+
+fn setup() -> (InMemoryMemberRepo, InMemoryRoleRepo, InMemoryPermissionRepo) {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut officer = Role::new("fleet-officer", "Fleet Officer");
+    officer.add_permission("fleet.*");
+    role_repo.create(officer).unwrap();
+    let mut pilot = Role::new("pilot", "Pilot");
+    pilot.add_permission("session.start");
+    role_repo.create(pilot).unwrap();
+    (InMemoryMemberRepo::new(), role_repo, InMemoryPermissionRepo::new())
+}
+
+#[test]
+fn allowed_decision_names_assignment_and_grant() {
+    let (mut member_repo, role_repo, perm_repo) = setup();
+    let mut m = Member::new("alice");
+    m.add_role_assignment(RoleAssignment::new("pilot", None));
+    m.add_role_assignment(RoleAssignment::new("fleet-officer", Some("fleet-1".to_string())));
+    member_repo.add(m).unwrap();
+
+    let d = PolicyService::evaluate(&PolicyContext::at(0), "alice", "fleet.update", Some("fleet-1"), &member_repo, &role_repo, &perm_repo).unwrap();
+    assert!(d.allowed);
+    assert_eq!(d.matched_assignment.unwrap().role_id, "fleet-officer");
+    assert_eq!(d.matched_grant.as_deref(), Some("fleet.*"));
+    assert!(d.deny_rule.is_none());
+    assert_eq!(d.evaluated.len(), 2);
+    assert_eq!(d.evaluated[0].outcome, AssignmentOutcome::PermissionNotGranted);
+    assert!(d.reason.contains("fleet-officer"));
+}
+
+#[test]
+fn denied_decision_reports_rule() {
+    let (mut member_repo, role_repo, perm_repo) = setup();
+    let mut m = Member::new("alice");
+    m.add_role_assignment(RoleAssignment::new("fleet-officer", None).with_validity(None, Some(100)));
+    member_repo.add(m).unwrap();
+
+    let d = PolicyService::evaluate(&PolicyContext::at(200), "alice", "fleet.create", None, &member_repo, &role_repo, &perm_repo).unwrap();
+    assert!(!d.allowed);
+    assert_eq!(d.deny_rule, Some(DenyRule::Expired { valid_until: 100 }));
+    assert!(d.reason.contains("expired"));
+}
+
+#[test]
+fn denied_without_matching_role() {
+    let (mut member_repo, role_repo, perm_repo) = setup();
+    member_repo.add(Member::new("bob")).unwrap();
+    let d = PolicyService::evaluate(&PolicyContext::at(0), "bob", "fleet.create", None, &member_repo, &role_repo, &perm_repo).unwrap();
+    assert!(!d.allowed);
+    assert!(d.deny_rule.is_none());
+    assert!(d.reason.contains("no role assignments"));
+
+    let err = PolicyService::evaluate(&PolicyContext::at(0), "nobody", "fleet.create", None, &member_repo, &role_repo, &perm_repo);
+    assert!(matches!(err, Err(RepositoryError::NotFound)));
+}
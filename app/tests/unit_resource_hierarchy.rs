@@ -0,0 +1,127 @@
+use sc_manager_app::command_bus::{CommandBus, Dispatched, PolicyRepos};
+use sc_manager_app::commands::AddShipToFleetCommand;
+use sc_manager_app::handlers::FleetHandler;
+use sc_manager_app::in_memory_fleet_repo::InMemoryFleetRepo;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_resource_hierarchy::InMemoryResourceHierarchy;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_ship_repo::InMemoryShipRepo;
+use sc_manager_app::repository_resource_hierarchy::RepositoryResourceHierarchy;
+use sc_manager_app::services::{PolicyContext, PolicyService};
+use sc_manager_core::domain::{Division, Fleet, Member, Organization, ResourceHierarchy, ResourceKind, Role, RoleAssignment, Ship};
+use sc_manager_core::repositories::{FleetRepository, MemberRepository, RepositoryError, RoleRepository, ShipRepository};
+
+fn hierarchy() -> InMemoryResourceHierarchy {
+    let mut h = InMemoryResourceHierarchy::new();
+    let mut org = Organization::new("org-1", "Org");
+    org.add_division(Division::new("div-ops", "Operations", None));
+    org.add_division(Division::new("div-mining", "Mining", Some("div-ops".to_string())));
+    h.add_organization(&org).unwrap();
+    let mut fleet = Fleet::new("fleet-1", "Prospectors");
+    fleet.add_ship(Ship::new("ship-1", "Prospector"));
+    h.add_fleet(&fleet, "div-mining").unwrap();
+    h.add_fleet(&Fleet::new("fleet-2", "Escort"), "org-1").unwrap();
+    h
+}
+
+fn setup(scope: &str) -> (InMemoryMemberRepo, InMemoryRoleRepo, InMemoryPermissionRepo) {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut r = Role::new("officer", "Officer");
+    r.add_permission("fleet.update");
+    role_repo.create(r).unwrap();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let mut m = Member::new("alice");
+    m.add_role_assignment(RoleAssignment::new("officer", Some(scope.to_string())));
+    member_repo.add(m).unwrap();
+    (member_repo, role_repo, InMemoryPermissionRepo::new())
+}
+
+#[test]
+fn ancestors_follow_org_division_fleet_ship() {
+    let h = hierarchy();
+    assert_eq!(h.ancestors("ship-1"), vec!["fleet-1", "div-mining", "div-ops", "org-1"]);
+    assert_eq!(h.kind("div-mining"), Some(ResourceKind::Division));
+    assert!(h.covers("org-1", "fleet-2"));
+    assert!(!h.covers("div-ops", "fleet-2"));
+}
+
+#[test]
+fn invalid_nesting_is_rejected() {
+    let mut h = hierarchy();
+    assert_eq!(h.insert("org-2", ResourceKind::Organization, Some("fleet-1")), Err(RepositoryError::InvalidInput));
+    assert_eq!(h.insert("div-ops", ResourceKind::Division, Some("div-mining")), Err(RepositoryError::InvalidInput));
+    assert_eq!(h.insert("ship-2", ResourceKind::Ship, Some("missing")), Err(RepositoryError::NotFound));
+}
+
+#[test]
+fn grant_on_parent_covers_children() {
+    let h = hierarchy();
+    let (member_repo, role_repo, perm_repo) = setup("div-ops");
+    let ctx = PolicyContext::at(0).with_hierarchy(&h);
+    let check = |resource: Option<&str>| {
+        PolicyService::check_permission_in(&ctx, "alice", "fleet.update", resource, &member_repo, &role_repo, &perm_repo).unwrap()
+    };
+    assert!(check(Some("fleet-1")));
+    assert!(check(Some("ship-1")));
+    assert!(!check(Some("fleet-2")));
+    assert!(!check(Some("org-1")));
+
+    // without a hierarchy scoped grants only match literally
+    assert!(!PolicyService::check_permission_in(&PolicyContext::at(0), "alice", "fleet.update", Some("fleet-1"), &member_repo, &role_repo, &perm_repo).unwrap());
+}
+
+#[test]
+fn unscoped_request_does_not_match_scoped_grant() {
+    let (member_repo, role_repo, perm_repo) = setup("org-1");
+    let allowed = PolicyService::check_permission("alice", "fleet.update", None, &member_repo, &role_repo, &perm_repo).unwrap();
+    assert!(!allowed);
+}
+
+#[test]
+fn repository_hierarchy_follows_fleet_and_ship_ownership() {
+    let mut fleet_repo = InMemoryFleetRepo::new();
+    let mut ship_repo = InMemoryShipRepo::new();
+    let mut ship = Ship::new("ship-1", "Prospector");
+    ship.owner_org = Some("org-1".to_string());
+    ship_repo.register(ship.clone()).unwrap();
+    let mut loose = Ship::new("ship-2", "Cutlass");
+    loose.owner_org = Some("org-1".to_string());
+    ship_repo.register(loose).unwrap();
+    let mut fleet = Fleet::new("fleet-1", "Prospectors");
+    fleet.org_id = Some("org-1".to_string());
+    fleet.add_ship(ship);
+    fleet_repo.create(fleet).unwrap();
+
+    let h = RepositoryResourceHierarchy::new(&fleet_repo, &ship_repo);
+    assert_eq!(h.ancestors("ship-1"), vec!["fleet-1", "org-1"]);
+    assert_eq!(h.ancestors("ship-2"), vec!["org-1"]);
+    assert_eq!(h.kind("fleet-1"), Some(ResourceKind::Fleet));
+    assert_eq!(h.resolve("ship-1").ancestors("ship-1"), vec!["fleet-1", "org-1"]);
+}
+
+#[test]
+fn org_grant_covers_fleet_commands_dispatched_with_repository_hierarchy() {
+    let (mut member_repo, mut role_repo, mut perm_repo) = setup("org-1");
+    let mut fleet_repo = InMemoryFleetRepo::new();
+    let mut ship_repo = InMemoryShipRepo::new();
+    let mut fleet = Fleet::new("fleet-1", "Prospectors");
+    fleet.org_id = Some("org-1".to_string());
+    fleet_repo.create(fleet).unwrap();
+    ship_repo.register(Ship::new("ship-1", "Prospector")).unwrap();
+
+    let scope = RepositoryResourceHierarchy::new(&fleet_repo, &ship_repo).resolve("fleet-1");
+    let mut bus = CommandBus::with_defaults();
+    let mut handler = FleetHandler::new(&mut fleet_repo, &mut ship_repo);
+    let add = |bus: &mut CommandBus<_, _, _>, repos: &mut PolicyRepos<_, _, _>, handler: &mut FleetHandler<_, _>| {
+        bus.dispatch("alice", AddShipToFleetCommand::new("fleet-1", "ship-1"), repos, |cmd, _| {
+            handler.add_ship_to_fleet(&cmd.fleet_id, &cmd.ship_id)
+        })
+    };
+
+    let mut repos = PolicyRepos::new(&mut member_repo, &mut role_repo, &mut perm_repo);
+    assert_eq!(add(&mut bus, &mut repos, &mut handler), Err(RepositoryError::Unauthorized));
+    let mut repos = repos.with_hierarchy(&scope);
+    assert_eq!(add(&mut bus, &mut repos, &mut handler), Ok(Dispatched::Executed(())));
+    assert_eq!(fleet_repo.get("fleet-1").unwrap().ships.len(), 1);
+}
@@ -0,0 +1,116 @@
+use sc_manager_app::command_bus::{CommandBus, PolicyRepos};
+use sc_manager_app::commands::GrantRoleCommand;
+use sc_manager_app::handlers::member_handler::MemberHandler;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
+use sc_manager_app::services::{PolicyContext, PolicyService, RoleAssignmentSweeper};
+use sc_manager_core::domain::{AssignmentCondition, Member, Role, RoleAssignment, Session};
+use sc_manager_core::events::EventEnvelope;
+use sc_manager_core::repositories::{MemberRepository, RepositoryError, RoleRepository, SessionRepository};
+
+fn setup() -> (InMemoryMemberRepo, InMemoryRoleRepo, InMemoryPermissionRepo) {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut r = Role::new("guest-pilot", "Guest Pilot");
+    r.add_permission("session.start");
+    role_repo.create(r).unwrap();
+    (InMemoryMemberRepo::new(), role_repo, InMemoryPermissionRepo::new())
+}
+
+#[test]
+fn expired_and_future_assignments_are_ignored() {
+    let (mut member_repo, role_repo, perm_repo) = setup();
+    let mut m = Member::new("guest");
+    m.add_role_assignment(RoleAssignment::new("guest-pilot", None).with_validity(Some(1000), Some(2000)));
+    member_repo.add(m).unwrap();
+
+    let check = |now: i64| {
+        PolicyService::check_permission_in(&PolicyContext::at(now), "guest", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap()
+    };
+    assert!(!check(999));
+    assert!(check(1500));
+    assert!(!check(2000));
+}
+
+#[test]
+fn session_condition_requires_active_session() {
+    let (mut member_repo, role_repo, perm_repo) = setup();
+    let mut session_repo = InMemorySessionRepo::new();
+    session_repo.create(Session::new("op-1", 100, None, None)).unwrap();
+
+    let mut m = Member::new("guest");
+    m.add_role_assignment(
+        RoleAssignment::new("guest-pilot", None).with_condition(AssignmentCondition::SessionActive("op-1".to_string())),
+    );
+    member_repo.add(m).unwrap();
+
+    // without a session lookup the condition cannot be met
+    assert!(!PolicyService::check_permission_in(&PolicyContext::at(150), "guest", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    let ctx = PolicyContext::at(150).with_sessions(&session_repo);
+    assert!(PolicyService::check_permission_in(&ctx, "guest", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+
+    let mut s = session_repo.get("op-1").unwrap();
+    s.end(200);
+    session_repo.update(s).unwrap();
+    let ctx = PolicyContext::at(250).with_sessions(&session_repo);
+    assert!(!PolicyService::check_permission_in(&ctx, "guest", "session.start", None, &member_repo, &role_repo, &perm_repo).unwrap());
+}
+
+#[test]
+fn sweeper_revokes_expired_assignments_and_emits_events() {
+    let (mut member_repo, _role_repo, _perm_repo) = setup();
+    let mut m = Member::new("guest");
+    m.assign_role("member", None);
+    m.add_role_assignment(RoleAssignment::new("guest-pilot", Some("op-1".to_string())).with_validity(None, Some(2000)));
+    member_repo.add(m).unwrap();
+
+    assert!(RoleAssignmentSweeper::sweep(1999, &mut member_repo).unwrap().is_empty());
+
+    let events = RoleAssignmentSweeper::sweep(2000, &mut member_repo).unwrap();
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        EventEnvelope::RoleRevoked { member_id, role_id, resource_id, reason, .. } => {
+            assert_eq!(member_id, "guest");
+            assert_eq!(role_id, "guest-pilot");
+            assert_eq!(resource_id.as_deref(), Some("op-1"));
+            assert_eq!(reason, "expired");
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+    let roles = member_repo.get("guest").unwrap().roles;
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].role_id, "member");
+}
+
+#[test]
+fn grant_role_rejects_inverted_validity_window() {
+    let (mut policy_repo, mut role_repo, mut perm_repo) = setup();
+    let mut r = Role::new("officer", "Officer");
+    r.add_permission("member.assign_role");
+    role_repo.create(r).unwrap();
+    let mut actor = Member::new("officer");
+    actor.assign_role("officer", None);
+    policy_repo.add(actor).unwrap();
+
+    let mut handler_repo = InMemoryMemberRepo::new();
+    handler_repo.add(Member::new("guest")).unwrap();
+    let mut handler = MemberHandler::new(&mut handler_repo);
+    let mut bus = CommandBus::with_defaults();
+    let mut repos = PolicyRepos::new(&mut policy_repo, &mut role_repo, &mut perm_repo);
+
+    let bad = RoleAssignment::new("guest-pilot", None).with_validity(Some(200), Some(100));
+    assert_eq!(
+        bus.dispatch("officer", GrantRoleCommand::new("guest", bad), &mut repos, |_, _| -> Result<(), RepositoryError> {
+            panic!("handler must not run")
+        })
+        .unwrap_err(),
+        RepositoryError::InvalidInput
+    );
+
+    let ok = RoleAssignment::new("guest-pilot", None).with_validity(Some(100), Some(200));
+    bus.dispatch("officer", GrantRoleCommand::new("guest", ok), &mut repos, |cmd, _| handler.grant_role(cmd.clone()))
+        .unwrap();
+    assert_eq!(handler_repo.get("guest").unwrap().roles[0].valid_until, Some(200));
+}
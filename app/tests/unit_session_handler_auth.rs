@@ -1,74 +1,99 @@
-use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
-use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
-use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
-use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
-use sc_manager_core::repositories::{MemberRepository, RoleRepository};
-
-#[test]
-fn start_session_requires_permission() {
-    let mut role_repo = InMemoryRoleRepo::new();
-    let mut member_repo = InMemoryMemberRepo::new();
-    let perm_repo = InMemoryPermissionRepo::new();
-
-    // role without session.start
-    let r = sc_manager_core::domain::Role::new("r-no", "NoStart");
-    role_repo.create(r).unwrap();
-
-    member_repo
-        .add(sc_manager_core::domain::Member::new("nope"))
-        .unwrap();
-    let mut m = member_repo.get("nope").unwrap();
-    m.assign_role("r-no", None);
-    member_repo.update(m).unwrap();
-
-    let mut repo = InMemorySessionRepo::new();
-    let mut handler = sc_manager_app::handlers::session_handler::SessionHandler::new(&mut repo);
-
-    let res = handler.start_with_auth(
-        "nope",
-        "s1",
-        1610000000,
-        None,
-        None,
-        &member_repo,
-        &role_repo,
-        &perm_repo,
-    );
-    assert_eq!(
-        res.unwrap_err(),
-        sc_manager_core::repositories::RepositoryError::Unauthorized
-    );
-}
-
-#[test]
-fn start_session_allowed_when_scoped() {
-    let mut role_repo = InMemoryRoleRepo::new();
-    let mut member_repo = InMemoryMemberRepo::new();
-    let perm_repo = InMemoryPermissionRepo::new();
-
-    let mut r = sc_manager_core::domain::Role::new("r-ok", "Start");
-    r.add_permission("session.start");
-    role_repo.create(r).unwrap();
-
-    member_repo
-        .add(sc_manager_core::domain::Member::new("sam"))
-        .unwrap();
-    let mut m = member_repo.get("sam").unwrap();
-    m.assign_role("r-ok", Some("orgA".to_string()));
-    member_repo.update(m).unwrap();
-
-    let mut repo = InMemorySessionRepo::new();
-    let mut handler = sc_manager_app::handlers::session_handler::SessionHandler::new(&mut repo);
-
-    let res = handler.start_with_auth(
-        "sam",
-        "s2",
-        1610000000,
-        Some("orgA".to_string()),
-        Some("sam".to_string()),
-        &member_repo,
-        &role_repo,
-        &perm_repo,
-    );
-    assert!(res.is_ok());
-}
+use sc_manager_app::command_bus::{CommandBus, PolicyRepos};
+use sc_manager_app::commands::{EndSessionCommand, StartSessionCommand};
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_session_repo::InMemorySessionRepo;
+use sc_manager_core::repositories::{MemberRepository, RoleRepository, SessionRepository};
+
+#[test]
+fn start_session_requires_permission() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let mut perm_repo = InMemoryPermissionRepo::new();
+
+    // role without session.start
+    let r = sc_manager_core::domain::Role::new("r-no", "NoStart");
+    role_repo.create(r).unwrap();
+
+    member_repo
+        .add(sc_manager_core::domain::Member::new("nope"))
+        .unwrap();
+    let mut m = member_repo.get("nope").unwrap();
+    m.assign_role("r-no", None);
+    member_repo.update(m).unwrap();
+
+    let mut repo = InMemorySessionRepo::new();
+    let mut handler = sc_manager_app::handlers::session_handler::SessionHandler::new(&mut repo);
+
+    let mut repos = PolicyRepos::new(&mut member_repo, &mut role_repo, &mut perm_repo);
+    let cmd = StartSessionCommand::new("s1", 1610000000, None);
+    let res = CommandBus::with_defaults().dispatch("nope", cmd, &mut repos, |cmd, _| {
+        handler.start(cmd.id.clone(), cmd.start_ts, cmd.org_id.clone(), cmd.participant.clone())
+    });
+    assert_eq!(
+        res.unwrap_err(),
+        sc_manager_core::repositories::RepositoryError::Unauthorized
+    );
+}
+
+#[test]
+fn start_session_allowed_when_scoped() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let mut perm_repo = InMemoryPermissionRepo::new();
+
+    let mut r = sc_manager_core::domain::Role::new("r-ok", "Start");
+    r.add_permission("session.start");
+    role_repo.create(r).unwrap();
+
+    member_repo
+        .add(sc_manager_core::domain::Member::new("sam"))
+        .unwrap();
+    let mut m = member_repo.get("sam").unwrap();
+    m.assign_role("r-ok", Some("orgA".to_string()));
+    member_repo.update(m).unwrap();
+
+    let mut repo = InMemorySessionRepo::new();
+    let mut handler = sc_manager_app::handlers::session_handler::SessionHandler::new(&mut repo);
+
+    let mut repos = PolicyRepos::new(&mut member_repo, &mut role_repo, &mut perm_repo);
+    let cmd = StartSessionCommand::new("s2", 1610000000, Some("orgA".to_string())).with_participant("sam");
+    let res = CommandBus::with_defaults().dispatch("sam", cmd, &mut repos, |cmd, _| {
+        handler.start(cmd.id.clone(), cmd.start_ts, cmd.org_id.clone(), cmd.participant.clone())
+    });
+    assert!(res.is_ok());
+}
+
+#[test]
+fn end_session_is_checked_on_the_session_org() {
+    let mut role_repo = InMemoryRoleRepo::new();
+    let mut member_repo = InMemoryMemberRepo::new();
+    let mut perm_repo = InMemoryPermissionRepo::new();
+
+    let mut r = sc_manager_core::domain::Role::new("r-end", "End");
+    r.add_permission("session.end");
+    role_repo.create(r).unwrap();
+
+    member_repo
+        .add(sc_manager_core::domain::Member::new("sam"))
+        .unwrap();
+    let mut m = member_repo.get("sam").unwrap();
+    m.assign_role("r-end", Some("orgA".to_string()));
+    member_repo.update(m).unwrap();
+
+    let mut repo = InMemorySessionRepo::new();
+    let mut handler = sc_manager_app::handlers::session_handler::SessionHandler::new(&mut repo);
+    handler.start("s1", 1610000000, Some("orgA".to_string()), None).unwrap();
+    handler.start("s2", 1610000000, Some("orgB".to_string()), None).unwrap();
+
+    let mut bus = CommandBus::with_defaults();
+    for (id, allowed) in [("s1", true), ("s2", false)] {
+        let session = handler.repo.get(id).unwrap();
+        let mut repos = PolicyRepos::new(&mut member_repo, &mut role_repo, &mut perm_repo);
+        let res = bus.dispatch("sam", EndSessionCommand::for_session(&session, 1610003600), &mut repos, |cmd, _| {
+            handler.end(&cmd.id, cmd.end_ts)
+        });
+        assert_eq!(res.is_ok(), allowed, "{}", id);
+    }
+}
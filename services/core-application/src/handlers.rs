@@ -1,3 +1,3 @@
-// Placeholder for handlers
-
+// Placeholder for handlers
+
 pub fn placeholder_handler() -> &'static str { "handler" }
\ No newline at end of file
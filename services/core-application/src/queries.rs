@@ -1,3 +1,3 @@
-// Placeholder for queries
-
+// Placeholder for queries
+
 pub fn placeholder_query() -> &'static str { "query" }
\ No newline at end of file
@@ -1,5 +1,5 @@
-// Application services placeholder
-
-pub mod publisher;
-
-pub fn placeholder() -> &'static str { "services" }
+// Application services placeholder
+
+pub mod publisher;
+
+pub fn placeholder() -> &'static str { "services" }
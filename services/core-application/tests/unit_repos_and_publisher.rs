@@ -5,7 +5,7 @@ use sc_manager_core::repositories::{RepositoryError, MemberRepository};
 #[test]
 fn member_repo_crud_and_list_by_org() {
     let mut repo = InMemoryMemberRepo::new();
-    let m = Member { id: "m1".into(), rsi_handle: None, online: false, org_id: Some("org1".into()), last_seen: None, last_session_id: None, roles: vec![] };
+    let m = Member { id: "m1".into(), rsi_handle: None, online: false, org_id: Some("org1".into()), division_id: None, rank: None, last_seen: None, last_session_id: None, roles: vec![] };
     assert!(repo.add(m.clone()).is_ok());
 
     let got = repo.get("m1").expect("get");
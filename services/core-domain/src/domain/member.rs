@@ -1,68 +1,132 @@
-use serde::{Deserialize, Serialize};
-
-/// Representation of a Member in the Core Domain
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RoleAssignment {
-    pub role_id: String,
-    pub resource_id: Option<String>,
-}
-
-impl RoleAssignment {
-    pub fn new(role_id: impl Into<String>, resource_id: Option<String>) -> Self {
-        Self {
-            role_id: role_id.into(),
-            resource_id,
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Member {
-    pub id: String,
-    pub rsi_handle: Option<String>,
-    pub online: bool,
-    pub org_id: Option<String>, // optional association to Organization
-    pub last_seen: Option<i64>,
-    pub last_session_id: Option<String>,
-    pub roles: Vec<RoleAssignment>,
-}
-
-impl Member {
-    pub fn new(id: impl Into<String>) -> Self {
-        Self {
-            id: id.into(),
-            rsi_handle: None,
-            online: false,
-            org_id: None,
-            last_seen: None,
-            last_session_id: None,
-            roles: vec![],
-        }
-    }
-
-    pub fn assign_role(&mut self, role_id: impl Into<String>, resource_id: Option<String>) {
-        let r = RoleAssignment::new(role_id, resource_id);
-        if !self
-            .roles
-            .iter()
-            .any(|ra| ra.role_id == r.role_id && ra.resource_id == r.resource_id)
-        {
-            self.roles.push(r);
-        }
-    }
-
-    pub fn revoke_role(&mut self, role_id: &str, resource_id: Option<&str>) {
-        self.roles
-            .retain(|ra| !(ra.role_id == role_id && ra.resource_id.as_deref() == resource_id));
-    }
-
-    pub fn assign_to_org(&mut self, org_id: impl Into<String>) {
-        self.org_id = Some(org_id.into());
-    }
-
-    pub fn unassign_org(&mut self) {
-        self.org_id = None;
-    }
-
-    // TODO: Add verification logic for RSI handle [TRACKED-004]
-}
+use serde::{Deserialize, Serialize};
+
+/// Extra condition a role assignment must satisfy to be in effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AssignmentCondition {
+    /// Only while the member is online.
+    MemberOnline,
+    /// Only while the given session has not ended.
+    SessionActive(String),
+}
+
+/// Representation of a Member in the Core Domain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoleAssignment {
+    pub role_id: String,
+    pub resource_id: Option<String>,
+    /// Unix timestamp (seconds) from which the assignment applies; `None` = immediately.
+    #[serde(default)]
+    pub valid_from: Option<i64>,
+    /// Unix timestamp (seconds) at which the assignment expires (exclusive); `None` = never.
+    #[serde(default)]
+    pub valid_until: Option<i64>,
+    #[serde(default)]
+    pub condition: Option<AssignmentCondition>,
+}
+
+impl RoleAssignment {
+    pub fn new(role_id: impl Into<String>, resource_id: Option<String>) -> Self {
+        Self {
+            role_id: role_id.into(),
+            resource_id,
+            valid_from: None,
+            valid_until: None,
+            condition: None,
+        }
+    }
+
+    pub fn with_validity(mut self, valid_from: Option<i64>, valid_until: Option<i64>) -> Self {
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        self
+    }
+
+    pub fn with_condition(mut self, condition: AssignmentCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+
+    /// Whether `ts` falls inside the validity window (conditions are not evaluated here).
+    pub fn is_valid_at(&self, ts: i64) -> bool {
+        self.valid_from.is_none_or(|from| ts >= from) && !self.is_expired_at(ts)
+    }
+
+    pub fn is_expired_at(&self, ts: i64) -> bool {
+        self.valid_until.is_some_and(|until| ts >= until)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    pub id: String,
+    pub rsi_handle: Option<String>,
+    pub online: bool,
+    pub org_id: Option<String>, // optional association to Organization
+    /// Division inside the organization, if assigned
+    #[serde(default)]
+    pub division_id: Option<String>,
+    /// Rank in the organization as shown on the RSI roster
+    #[serde(default)]
+    pub rank: Option<String>,
+    pub last_seen: Option<i64>,
+    pub last_session_id: Option<String>,
+    pub roles: Vec<RoleAssignment>,
+}
+
+impl Member {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            rsi_handle: None,
+            online: false,
+            org_id: None,
+            division_id: None,
+            rank: None,
+            last_seen: None,
+            last_session_id: None,
+            roles: vec![],
+        }
+    }
+
+    pub fn assign_role(&mut self, role_id: impl Into<String>, resource_id: Option<String>) {
+        let r = RoleAssignment::new(role_id, resource_id);
+        if !self
+            .roles
+            .iter()
+            .any(|ra| ra.role_id == r.role_id && ra.resource_id == r.resource_id)
+        {
+            self.roles.push(r);
+        }
+    }
+
+    /// Add a (possibly time-bounded or conditional) assignment, replacing any existing
+    /// assignment of the same role on the same resource.
+    pub fn add_role_assignment(&mut self, assignment: RoleAssignment) {
+        self.roles.retain(|ra| {
+            !(ra.role_id == assignment.role_id && ra.resource_id == assignment.resource_id)
+        });
+        self.roles.push(assignment);
+    }
+
+    /// Remove assignments expired at `ts` and return them.
+    pub fn remove_expired_roles(&mut self, ts: i64) -> Vec<RoleAssignment> {
+        let (expired, kept) = self.roles.drain(..).partition(|ra| ra.is_expired_at(ts));
+        self.roles = kept;
+        expired
+    }
+
+    pub fn revoke_role(&mut self, role_id: &str, resource_id: Option<&str>) {
+        self.roles
+            .retain(|ra| !(ra.role_id == role_id && ra.resource_id.as_deref() == resource_id));
+    }
+
+    pub fn assign_to_org(&mut self, org_id: impl Into<String>) {
+        self.org_id = Some(org_id.into());
+    }
+
+    pub fn unassign_org(&mut self) {
+        self.org_id = None;
+    }
+
+    // TODO: Add verification logic for RSI handle [TRACKED-004]
+}
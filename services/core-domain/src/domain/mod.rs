@@ -1,25 +1,27 @@
-//! Core domain module
-
-pub mod equipment;
-pub mod event;
-pub mod fleet;
-pub mod game_event;
-pub mod member;
-pub mod organization;
-pub mod permission;
-pub mod role;
-pub mod session;
-pub mod ship;
-pub mod division;
-
-pub use self::equipment::Equipment;
-pub use self::event::Event;
-pub use self::fleet::Fleet;
-pub use self::game_event::GameEvent;
-pub use self::member::Member;
-pub use self::organization::Organization;
-pub use self::permission::Permission;
-pub use self::role::Role;
-pub use self::session::Session;
-pub use self::ship::Ship;
-pub use self::division::Division;
+//! Core domain module
+
+pub mod equipment;
+pub mod event;
+pub mod fleet;
+pub mod game_event;
+pub mod member;
+pub mod organization;
+pub mod permission;
+pub mod resource;
+pub mod role;
+pub mod session;
+pub mod ship;
+pub mod division;
+
+pub use self::equipment::Equipment;
+pub use self::event::Event;
+pub use self::fleet::Fleet;
+pub use self::game_event::GameEvent;
+pub use self::member::{AssignmentCondition, Member, RoleAssignment};
+pub use self::organization::Organization;
+pub use self::permission::Permission;
+pub use self::resource::{ResourceHierarchy, ResourceKind};
+pub use self::role::Role;
+pub use self::session::Session;
+pub use self::ship::Ship;
+pub use self::division::Division;
@@ -1,16 +1,75 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Permission {
-    pub id: String,
-    pub name: String,
-}
-
-impl Permission {
-    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
-        Self {
-            id: id.into(),
-            name: name.into(),
-        }
-    }
-}
+use serde::{Deserialize, Serialize};
+
+/// Segment that grants every permission below its prefix (`fleet.*`) or everything (`*`).
+pub const WILDCARD: &str = "*";
+
+/// Built-in permission catalog: every permission id checked by the application layer.
+pub const CATALOG: &[(&str, &str)] = &[
+    ("org.create", "Create organizations"),
+    ("org.update", "Update organizations"),
+    ("org.read", "View organization overviews"),
+    ("member.read", "View member profiles"),
+    ("member.assign_role", "Assign roles to members"),
+    ("member.sync_roster", "Apply RSI roster synchronization"),
+    ("role.update", "Change role permissions"),
+    ("fleet.create", "Create fleets"),
+    ("fleet.update", "Update fleets"),
+    ("fleet.read", "View fleets"),
+    ("ship.register", "Register ships"),
+    ("ship.remove", "Remove ships"),
+    ("equipment.register", "Register equipment"),
+    ("event.create", "Create events"),
+    ("session.start", "Start sessions"),
+    ("session.end", "End sessions"),
+    ("session.read", "View session history"),
+    ("adapters.admin", "Manage data adapters"),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Permission {
+    pub id: String,
+    pub name: String,
+}
+
+impl Permission {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Hierarchical ids are dot-separated segments of `[a-z0-9_-]`.
+    /// Only the last segment may be the wildcard (`fleet.*`, `*`).
+    pub fn is_valid_id(id: &str) -> bool {
+        let segments: Vec<&str> = id.split('.').collect();
+        let last = segments.len() - 1;
+        segments.iter().enumerate().all(|(i, seg)| {
+            (i == last && *seg == WILDCARD)
+                || (!seg.is_empty()
+                    && seg
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-'))
+        })
+    }
+
+    /// Whether `id` is a wildcard grant rather than a concrete permission.
+    pub fn is_wildcard(id: &str) -> bool {
+        id == WILDCARD || id.ends_with(".*")
+    }
+
+    /// Whether a `granted` permission (possibly a wildcard) covers the `requested` one.
+    /// `fleet.*` covers `fleet.create` and `fleet.ship.add`, but not `fleet` itself.
+    pub fn grants(granted: &str, requested: &str) -> bool {
+        if granted == requested || granted == WILDCARD {
+            return true;
+        }
+        match granted.strip_suffix(".*") {
+            Some(prefix) => requested
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(|rest| !rest.is_empty()),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Kinds of resources a role assignment can be scoped to, outermost first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceKind {
+    Organization,
+    Division,
+    Fleet,
+    Ship,
+}
+
+impl ResourceKind {
+    /// Organization > Division > Fleet > Ship; divisions may nest.
+    pub fn can_contain(self, child: ResourceKind) -> bool {
+        match self {
+            ResourceKind::Organization => child != ResourceKind::Organization,
+            ResourceKind::Division => matches!(child, ResourceKind::Division | ResourceKind::Fleet | ResourceKind::Ship),
+            ResourceKind::Fleet => child == ResourceKind::Ship,
+            ResourceKind::Ship => false,
+        }
+    }
+}
+
+/// Resolves the parent chain of a resource id so grants on a parent cover its children.
+pub trait ResourceHierarchy {
+    fn kind(&self, resource_id: &str) -> Option<ResourceKind>;
+    fn parent(&self, resource_id: &str) -> Option<String>;
+
+    /// Parents of `resource_id`, nearest first. Stops on cycles.
+    fn ancestors(&self, resource_id: &str) -> Vec<String> {
+        let mut seen: HashSet<String> = HashSet::new();
+        seen.insert(resource_id.to_string());
+        let mut out = vec![];
+        let mut current = self.parent(resource_id);
+        while let Some(p) = current {
+            if !seen.insert(p.clone()) {
+                break;
+            }
+            current = self.parent(&p);
+            out.push(p);
+        }
+        out
+    }
+
+    /// Whether a grant scoped to `scope` applies to `resource_id`.
+    fn covers(&self, scope: &str, resource_id: &str) -> bool {
+        scope == resource_id || self.ancestors(resource_id).iter().any(|a| a == scope)
+    }
+}
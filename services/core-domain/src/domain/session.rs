@@ -1,41 +1,59 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Session {
-    pub id: String,
-    pub start_ts: i64,
-    pub end_ts: Option<i64>,
-    pub events: Vec<String>,
-    pub org_id: Option<String>,
-    pub participant: Option<String>,
-}
-
-impl Session {
-    pub fn new(
-        id: impl Into<String>,
-        start_ts: i64,
-        org_id: Option<String>,
-        participant: Option<String>,
-    ) -> Self {
-        Self {
-            id: id.into(),
-            start_ts,
-            end_ts: None,
-            events: vec![],
-            org_id,
-            participant,
-        }
-    }
-
-    pub fn end(&mut self, ts: i64) {
-        self.end_ts = Some(ts);
-    }
-
-    pub fn add_event(&mut self, event_id: impl Into<String>) {
-        self.events.push(event_id.into());
-    }
-
-    pub fn is_active(&self) -> bool {
-        self.end_ts.is_none()
-    }
-}
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub id: String,
+    pub start_ts: i64,
+    pub end_ts: Option<i64>,
+    pub events: Vec<String>,
+    pub org_id: Option<String>,
+    pub participant: Option<String>,
+    /// Timestamp of the last event attached to the session
+    #[serde(default)]
+    pub last_event_ts: Option<i64>,
+}
+
+impl Session {
+    pub fn new(
+        id: impl Into<String>,
+        start_ts: i64,
+        org_id: Option<String>,
+        participant: Option<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            start_ts,
+            end_ts: None,
+            events: vec![],
+            org_id,
+            participant,
+            last_event_ts: None,
+        }
+    }
+
+    pub fn end(&mut self, ts: i64) {
+        self.end_ts = Some(ts);
+    }
+
+    /// Attaches an event id; ids already attached are ignored.
+    pub fn add_event(&mut self, event_id: impl Into<String>) {
+        let event_id = event_id.into();
+        if !self.events.contains(&event_id) {
+            self.events.push(event_id);
+        }
+    }
+
+    /// Records activity at `ts`.
+    pub fn touch(&mut self, ts: i64) {
+        self.last_event_ts = Some(self.last_event_ts.map_or(ts, |t| t.max(ts)));
+    }
+
+    /// Last event timestamp, or the start when nothing happened yet.
+    pub fn last_activity(&self) -> i64 {
+        self.last_event_ts.unwrap_or(self.start_ts).max(self.start_ts)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.end_ts.is_none()
+    }
+}
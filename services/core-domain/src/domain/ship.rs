@@ -1,18 +1,22 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Ship {
-    pub id: String,
-    pub model: String,
-    pub owner_org: Option<String>,
-}
-
-impl Ship {
-    pub fn new(id: impl Into<String>, model: impl Into<String>) -> Self {
-        Self {
-            id: id.into(),
-            model: model.into(),
-            owner_org: None,
-        }
-    }
-}
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ship {
+    pub id: String,
+    pub model: String,
+    pub owner_org: Option<String>,
+    /// Name given to this ship by its owner
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl Ship {
+    pub fn new(id: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            model: model.into(),
+            owner_org: None,
+            name: None,
+        }
+    }
+}
@@ -1,26 +1,45 @@
-//! Domain events module
-
-pub trait DomainEvent: Send + Sync {
-    fn event_name(&self) -> &'static str;
-}
-
-// Example generic event envelope
-#[derive(Debug, Clone)]
-pub enum EventEnvelope {
-    OrgCreated {
-        id: String,
-    },
-    MemberAdded {
-        member_id: String,
-    },
-    // Game related events coming from adapters like game.log
-    GameEvent {
-        id: String,
-        event_type: crate::domain::game_event::GameEventType,
-        timestamp: i64,
-        details: Option<String>,
-    },
-}
-
-pub mod signing;
-pub use signing::{DomainEventPayload, SignedEvent, sign_event, verify_signature, generate_test_keypair, KeyPair};
+//! Domain events module
+
+pub trait DomainEvent: Send + Sync {
+    fn event_name(&self) -> &'static str;
+}
+
+// Example generic event envelope
+#[derive(Debug, Clone)]
+pub enum EventEnvelope {
+    OrgCreated {
+        id: String,
+    },
+    MemberAdded {
+        member_id: String,
+    },
+    RoleAssigned {
+        member_id: String,
+        role_id: String,
+        resource_id: Option<String>,
+        timestamp: i64,
+    },
+    RoleRevoked {
+        member_id: String,
+        role_id: String,
+        resource_id: Option<String>,
+        /// Why the assignment was revoked (e.g. "expired")
+        reason: String,
+        timestamp: i64,
+    },
+    /// A role's permission set changed; affects every member holding the role.
+    RolePermissionChanged {
+        role_id: String,
+        timestamp: i64,
+    },
+    // Game related events coming from adapters like game.log
+    GameEvent {
+        id: String,
+        event_type: crate::domain::game_event::GameEventType,
+        timestamp: i64,
+        details: Option<String>,
+    },
+}
+
+pub mod signing;
+pub use signing::{DomainEventPayload, SignedEvent, sign_event, verify_signature, generate_test_keypair, KeyPair};
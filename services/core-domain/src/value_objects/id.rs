@@ -1,8 +1,8 @@
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(pub String);
-
-impl From<&str> for Id {
-    fn from(s: &str) -> Self {
-        Id(s.to_string())
-    }
-}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(pub String);
+
+impl From<&str> for Id {
+    fn from(s: &str) -> Self {
+        Id(s.to_string())
+    }
+}
@@ -1,7 +1,7 @@
-pub mod handle;
-pub mod id;
-pub mod status;
-
-pub use self::handle::Handle;
-pub use self::id::Id;
-pub use self::status::Status;
+pub mod handle;
+pub mod id;
+pub mod status;
+
+pub use self::handle::Handle;
+pub use self::id::Id;
+pub use self::status::Status;
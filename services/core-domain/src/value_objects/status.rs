@@ -1,6 +1,6 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Status {
-    Online,
-    Offline,
-    Unknown,
-}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Online,
+    Offline,
+    Unknown,
+}
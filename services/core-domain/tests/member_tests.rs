@@ -1,31 +1,67 @@
-use sc_manager_core::domain::Member;
-
-#[test]
-fn assign_role_adds_and_does_not_duplicate() {
-    let mut m = Member::new("m1");
-    m.assign_role("admin", None);
-    assert_eq!(m.roles.len(), 1);
-    m.assign_role("admin", None);
-    assert_eq!(m.roles.len(), 1, "assigning the same role twice must not duplicate");
-}
-
-#[test]
-fn revoke_role_removes_matching_assignment() {
-    let mut m = Member::new("m2");
-    m.assign_role("admin", None);
-    m.assign_role("admin", Some("res1".to_string()));
-    assert_eq!(m.roles.len(), 2);
-
-    m.revoke_role("admin", None);
-    assert_eq!(m.roles.len(), 1);
-    assert_eq!(m.roles[0].resource_id.as_deref(), Some("res1"));
-}
-
-#[test]
-fn assign_and_unassign_org_sets_org_id() {
-    let mut m = Member::new("m3");
-    m.assign_to_org("orgX");
-    assert_eq!(m.org_id.as_deref(), Some("orgX"));
-    m.unassign_org();
-    assert!(m.org_id.is_none());
-}
+use sc_manager_core::domain::Member;
+
+#[test]
+fn assign_role_adds_and_does_not_duplicate() {
+    let mut m = Member::new("m1");
+    m.assign_role("admin", None);
+    assert_eq!(m.roles.len(), 1);
+    m.assign_role("admin", None);
+    assert_eq!(m.roles.len(), 1, "assigning the same role twice must not duplicate");
+}
+
+#[test]
+fn revoke_role_removes_matching_assignment() {
+    let mut m = Member::new("m2");
+    m.assign_role("admin", None);
+    m.assign_role("admin", Some("res1".to_string()));
+    assert_eq!(m.roles.len(), 2);
+
+    m.revoke_role("admin", None);
+    assert_eq!(m.roles.len(), 1);
+    assert_eq!(m.roles[0].resource_id.as_deref(), Some("res1"));
+}
+
+#[test]
+fn assign_and_unassign_org_sets_org_id() {
+    let mut m = Member::new("m3");
+    m.assign_to_org("orgX");
+    assert_eq!(m.org_id.as_deref(), Some("orgX"));
+    m.unassign_org();
+    assert!(m.org_id.is_none());
+}
+
+#[test]
+fn role_assignment_validity_window() {
+    use sc_manager_core::domain::RoleAssignment;
+    let ra = RoleAssignment::new("guest", None).with_validity(Some(100), Some(200));
+    assert!(!ra.is_valid_at(99));
+    assert!(ra.is_valid_at(100));
+    assert!(ra.is_valid_at(199));
+    assert!(!ra.is_valid_at(200));
+    assert!(ra.is_expired_at(200));
+    assert!(RoleAssignment::new("admin", None).is_valid_at(i64::MAX));
+}
+
+#[test]
+fn remove_expired_roles_returns_only_expired() {
+    use sc_manager_core::domain::RoleAssignment;
+    let mut m = Member::new("m4");
+    m.assign_role("admin", None);
+    m.add_role_assignment(RoleAssignment::new("guest", Some("op-1".to_string())).with_validity(None, Some(50)));
+    m.add_role_assignment(RoleAssignment::new("guest", Some("op-2".to_string())).with_validity(None, Some(500)));
+
+    let expired = m.remove_expired_roles(100);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].resource_id.as_deref(), Some("op-1"));
+    assert_eq!(m.roles.len(), 2);
+}
+
+#[test]
+fn add_role_assignment_replaces_same_role_and_resource() {
+    use sc_manager_core::domain::RoleAssignment;
+    let mut m = Member::new("m5");
+    m.add_role_assignment(RoleAssignment::new("guest", None).with_validity(None, Some(10)));
+    m.add_role_assignment(RoleAssignment::new("guest", None).with_validity(None, Some(20)));
+    assert_eq!(m.roles.len(), 1);
+    assert_eq!(m.roles[0].valid_until, Some(20));
+}
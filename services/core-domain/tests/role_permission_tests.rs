@@ -1,45 +1,69 @@
-use sc_manager_core::domain::{Permission, Role};
-
-#[test]
-fn add_permission_adds_once() {
-    let mut r = Role::new("role1", "TestRole");
-    r.add_permission("perm.read");
-    r.add_permission("perm.read");
-
-    let perms = r.list_permissions();
-    assert_eq!(perms.len(), 1);
-    assert_eq!(perms[0], "perm.read");
-}
-
-#[test]
-fn remove_permission_removes_matching_entry() {
-    let mut r = Role::new("role2", "RemovableRole");
-    r.add_permission("perm.a");
-    r.add_permission("perm.b");
-
-    r.remove_permission("perm.a");
-    let perms = r.list_permissions();
-    assert_eq!(perms.len(), 1);
-    assert_eq!(perms[0], "perm.b");
-}
-
-#[test]
-fn list_permissions_returns_cloned_list() {
-    let mut r = Role::new("role3", "CloneRole");
-    r.add_permission("perm.one");
-
-    let mut listed = r.list_permissions();
-    listed.push("perm.modified".to_string());
-
-    // original shouldn't be affected
-    let orig = r.list_permissions();
-    assert_eq!(orig.len(), 1);
-    assert_eq!(orig[0], "perm.one");
-}
-
-#[test]
-fn permission_new_creates_expected_struct() {
-    let p = Permission::new("perm.x", "Permission X");
-    assert_eq!(p.id, "perm.x");
-    assert_eq!(p.name, "Permission X");
-}
+use sc_manager_core::domain::{Permission, Role};
+
+#[test]
+fn add_permission_adds_once() {
+    let mut r = Role::new("role1", "TestRole");
+    r.add_permission("perm.read");
+    r.add_permission("perm.read");
+
+    let perms = r.list_permissions();
+    assert_eq!(perms.len(), 1);
+    assert_eq!(perms[0], "perm.read");
+}
+
+#[test]
+fn remove_permission_removes_matching_entry() {
+    let mut r = Role::new("role2", "RemovableRole");
+    r.add_permission("perm.a");
+    r.add_permission("perm.b");
+
+    r.remove_permission("perm.a");
+    let perms = r.list_permissions();
+    assert_eq!(perms.len(), 1);
+    assert_eq!(perms[0], "perm.b");
+}
+
+#[test]
+fn list_permissions_returns_cloned_list() {
+    let mut r = Role::new("role3", "CloneRole");
+    r.add_permission("perm.one");
+
+    let mut listed = r.list_permissions();
+    listed.push("perm.modified".to_string());
+
+    // original shouldn't be affected
+    let orig = r.list_permissions();
+    assert_eq!(orig.len(), 1);
+    assert_eq!(orig[0], "perm.one");
+}
+
+#[test]
+fn permission_new_creates_expected_struct() {
+    let p = Permission::new("perm.x", "Permission X");
+    assert_eq!(p.id, "perm.x");
+    assert_eq!(p.name, "Permission X");
+}
+
+#[test]
+fn wildcard_grants_cover_namespace() {
+    assert!(Permission::grants("fleet.create", "fleet.create"));
+    assert!(Permission::grants("fleet.*", "fleet.create"));
+    assert!(Permission::grants("fleet.*", "fleet.ship.add"));
+    assert!(Permission::grants("*", "org.update"));
+    assert!(!Permission::grants("fleet.*", "fleet"));
+    assert!(!Permission::grants("fleet.*", "fleetwide.create"));
+    assert!(!Permission::grants("fleet.create", "fleet.update"));
+}
+
+#[test]
+fn permission_id_validation() {
+    assert!(Permission::is_valid_id("fleet.create"));
+    assert!(Permission::is_valid_id("fleet.*"));
+    assert!(Permission::is_valid_id("*"));
+    assert!(!Permission::is_valid_id(""));
+    assert!(!Permission::is_valid_id("fleet..create"));
+    assert!(!Permission::is_valid_id("fleet.*.create"));
+    assert!(!Permission::is_valid_id("Fleet.Create"));
+    assert!(Permission::is_wildcard("fleet.*"));
+    assert!(!Permission::is_wildcard("fleet.create"));
+}
@@ -0,0 +1,246 @@
+//! Caller authentication and the repositories backing authorization decisions.
+//!
+//! Callers authenticate with `Authorization: Bearer <token>`, where the token is
+//! `v1.<claims>.<mac>`: base64url JSON claims (`sub`, `exp`) and their HMAC-SHA256 under the
+//! token secret (see `TokenSigner`). The secret is separate from the gateway's event signing
+//! key, so nothing signed as a domain event can pass as a token.
+//!
+//! The repositories start from the `GATEWAY_AUTHZ_FILE` seed and are kept current by the
+//! role events the application's `SigningMiddleware` publishes on `domain.events` (see
+//! `AuthzState::apply_role_event`), so role changes apply without a restart.
+
+use axum::http::{HeaderMap, StatusCode};
+use base64::engine::general_purpose::{STANDARD as base64_std, URL_SAFE_NO_PAD as base64_url};
+use base64::Engine as _;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sc_manager_app::in_memory_fleet_repo::InMemoryFleetRepo;
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_app::in_memory_ship_repo::InMemoryShipRepo;
+use sc_manager_app::repository_resource_hierarchy::RepositoryResourceHierarchy;
+use sc_manager_app::services::{PolicyContext, PolicyDecision, PolicyService};
+use sc_manager_core::domain::{Fleet, Member, Role, RoleAssignment, Ship};
+use sc_manager_core::events::{verify_signature, SignedEvent};
+use sc_manager_core::repositories::{FleetRepository, MemberRepository, RepositoryError, RoleRepository, ShipRepository};
+use std::sync::RwLock;
+
+/// Prefix of the current token format.
+pub const TOKEN_VERSION: &str = "v1";
+
+/// Kinds of the signed command events that change members' roles or roles' permissions.
+pub const ROLE_EVENT_KINDS: &[&str] = &["member.assign_role", "member.grant_role", "role.assign_permission"];
+
+/// Minimum token secret length in bytes.
+pub const MIN_SECRET_LEN: usize = 32;
+
+pub type AuthError = (StatusCode, String);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Claims {
+    sub: String,
+    exp: i64,
+}
+
+/// Issues and verifies caller tokens with an HMAC-SHA256 secret of at least `MIN_SECRET_LEN` bytes.
+pub struct TokenSigner {
+    secret: Vec<u8>,
+}
+
+impl TokenSigner {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Result<Self, String> {
+        let secret = secret.into();
+        if secret.len() < MIN_SECRET_LEN {
+            return Err(format!("token secret must be at least {} bytes, got {}", MIN_SECRET_LEN, secret.len()));
+        }
+        Ok(Self { secret })
+    }
+
+    /// Secret from `GATEWAY_TOKEN_SECRET_FILE` (raw bytes) or `GATEWAY_TOKEN_SECRET` (base64); `None` if neither is set.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        if let Ok(path) = std::env::var("GATEWAY_TOKEN_SECRET_FILE") {
+            let bytes = std::fs::read(&path).map_err(|e| format!("read {}: {}", path, e))?;
+            return Self::new(bytes).map(Some);
+        }
+        match std::env::var("GATEWAY_TOKEN_SECRET") {
+            Ok(b64) => {
+                let bytes = base64_std.decode(b64.trim()).map_err(|e| format!("GATEWAY_TOKEN_SECRET: base64 decode: {}", e))?;
+                Self::new(bytes).map(Some)
+            }
+            Err(_) => Ok(None),
+        }
+    }
+
+    fn mac(&self, claims: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).expect("hmac accepts any key length");
+        mac.update(TOKEN_VERSION.as_bytes());
+        mac.update(b".");
+        mac.update(claims.as_bytes());
+        mac
+    }
+
+    /// Token for `member_id`, valid until `expires_at` (unix seconds).
+    pub fn issue(&self, member_id: &str, expires_at: i64) -> String {
+        let claims = serde_json::to_vec(&Claims { sub: member_id.to_string(), exp: expires_at }).unwrap_or_default();
+        let claims = base64_url.encode(claims);
+        let mac = base64_url.encode(self.mac(&claims).finalize().into_bytes());
+        format!("{}.{}.{}", TOKEN_VERSION, claims, mac)
+    }
+
+    /// Member id carried by `token` if its MAC checks out and it has not expired at `now`.
+    pub fn verify(&self, token: &str, now: i64) -> Result<String, &'static str> {
+        let mut parts = token.splitn(3, '.');
+        let (Some(TOKEN_VERSION), Some(claims), Some(mac)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err("malformed token");
+        };
+        let mac = base64_url.decode(mac).map_err(|_| "malformed token")?;
+        self.mac(claims).verify_slice(&mac).map_err(|_| "invalid token")?;
+        let claims: Claims = base64_url
+            .decode(claims)
+            .ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .ok_or("malformed token")?;
+        if claims.exp <= now {
+            return Err("token expired");
+        }
+        Ok(claims.sub)
+    }
+}
+
+/// Members, roles and the resource tree loaded at startup (`GATEWAY_AUTHZ_FILE`).
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct AuthzSeed {
+    #[serde(default)]
+    pub members: Vec<Member>,
+    #[serde(default)]
+    pub roles: Vec<Role>,
+    #[serde(default)]
+    pub fleets: Vec<Fleet>,
+    #[serde(default)]
+    pub ships: Vec<Ship>,
+}
+
+/// Repositories backing authorization queries, the signer caller tokens must verify against and the
+/// public key role events must be signed with.
+pub struct AuthzState {
+    pub members: RwLock<InMemoryMemberRepo>,
+    pub roles: RwLock<InMemoryRoleRepo>,
+    pub permissions: RwLock<InMemoryPermissionRepo>,
+    pub fleets: RwLock<InMemoryFleetRepo>,
+    pub ships: RwLock<InMemoryShipRepo>,
+    tokens: TokenSigner,
+    role_events_key: Option<Vec<u8>>,
+}
+
+impl AuthzState {
+    pub fn new(
+        members: InMemoryMemberRepo,
+        roles: InMemoryRoleRepo,
+        permissions: InMemoryPermissionRepo,
+        fleets: InMemoryFleetRepo,
+        ships: InMemoryShipRepo,
+        tokens: TokenSigner,
+    ) -> Self {
+        Self {
+            members: RwLock::new(members),
+            roles: RwLock::new(roles),
+            permissions: RwLock::new(permissions),
+            fleets: RwLock::new(fleets),
+            ships: RwLock::new(ships),
+            tokens,
+            role_events_key: None,
+        }
+    }
+
+    /// Accept role events signed with `public_key` (the application's command signing key).
+    pub fn with_role_events_key(mut self, public_key: Vec<u8>) -> Self {
+        self.role_events_key = Some(public_key);
+        self
+    }
+
+    /// Apply a role event from `domain.events` to the repositories. Returns `Ok(false)` for events of other
+    /// kinds or not signed with the role events key; a role event that cannot be applied is an error.
+    pub fn apply_role_event(&self, signed: &SignedEvent) -> Result<bool, String> {
+        let trusted = self.role_events_key.as_ref().is_some_and(|k| *k == signed.public_key);
+        if !trusted || !ROLE_EVENT_KINDS.contains(&signed.event.kind.as_str()) || !verify_signature(signed) {
+            return Ok(false);
+        }
+        let cmd = &signed.event.payload["command"];
+        let field = |name: &str| cmd[name].as_str().map(str::to_string).ok_or_else(|| format!("{}: missing {}", signed.event.kind, name));
+        let fail = |e: String| format!("{}: {}", signed.event.kind, e);
+        match signed.event.kind.as_str() {
+            "member.assign_role" | "member.grant_role" => {
+                let mut members = self.members.write().map_err(|e| fail(e.to_string()))?;
+                let mut member = members.get(&field("member_id")?).map_err(|e| fail(format!("{:?}", e)))?;
+                if signed.event.kind == "member.assign_role" {
+                    member.assign_role(field("role_id")?, cmd["scope"].as_str().map(str::to_string));
+                } else {
+                    let assignment: RoleAssignment = serde_json::from_value(cmd["assignment"].clone()).map_err(|e| fail(e.to_string()))?;
+                    member.add_role_assignment(assignment);
+                }
+                members.update(member).map_err(|e| fail(format!("{:?}", e)))?;
+            }
+            _ => {
+                let mut roles = self.roles.write().map_err(|e| fail(e.to_string()))?;
+                let mut role = roles.get(&field("role_id")?).map_err(|e| fail(format!("{:?}", e)))?;
+                role.add_permission(field("permission_id")?);
+                roles.update(role).map_err(|e| fail(format!("{:?}", e)))?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// State holding the seeded members, roles, fleets and ships and the built-in permission catalog.
+    pub fn from_seed(seed: AuthzSeed, tokens: TokenSigner) -> Result<Self, RepositoryError> {
+        let mut members = InMemoryMemberRepo::new();
+        for m in seed.members {
+            members.add(m)?;
+        }
+        let mut roles = InMemoryRoleRepo::new();
+        for r in seed.roles {
+            roles.create(r)?;
+        }
+        let mut fleets = InMemoryFleetRepo::new();
+        for f in seed.fleets {
+            fleets.create(f)?;
+        }
+        let mut ships = InMemoryShipRepo::new();
+        for s in seed.ships {
+            ships.register(s)?;
+        }
+        Ok(Self::new(members, roles, InMemoryPermissionRepo::with_catalog(), fleets, ships, tokens))
+    }
+
+    /// Member id asserted by the caller's bearer token.
+    pub fn authenticate(&self, headers: &HeaderMap) -> Result<String, AuthError> {
+        let unauthorized = |msg: &str| (StatusCode::UNAUTHORIZED, msg.to_string());
+        let token = headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .ok_or_else(|| unauthorized("missing bearer token"))?;
+        self.tokens.verify(token, PolicyContext::current().now).map_err(unauthorized)
+    }
+
+    /// Policy decision for `member_id`, with scoped grants resolved through the fleet and ship repositories.
+    pub fn decide(&self, member_id: &str, permission: &str, resource: Option<&str>) -> Result<PolicyDecision, AuthError> {
+        let internal = |e: String| (StatusCode::INTERNAL_SERVER_ERROR, e);
+        let members = self.members.read().map_err(|e| internal(e.to_string()))?;
+        let roles = self.roles.read().map_err(|e| internal(e.to_string()))?;
+        let permissions = self.permissions.read().map_err(|e| internal(e.to_string()))?;
+        let fleets = self.fleets.read().map_err(|e| internal(e.to_string()))?;
+        let ships = self.ships.read().map_err(|e| internal(e.to_string()))?;
+        let hierarchy = RepositoryResourceHierarchy::new(&*fleets, &*ships);
+        PolicyService::evaluate(
+            &PolicyContext::current().with_hierarchy(&hierarchy),
+            member_id,
+            permission,
+            resource,
+            &*members,
+            &*roles,
+            &*permissions,
+        )
+        .map_err(|e| (StatusCode::NOT_FOUND, format!("member '{}': {:?}", member_id, e)))
+    }
+}
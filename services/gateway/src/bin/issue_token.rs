@@ -0,0 +1,32 @@
+//! Issue a caller token: `gateway_issue_token <member-id> [ttl-seconds]` (default 12 hours).
+//!
+//! Reads the token secret from `GATEWAY_TOKEN_SECRET_FILE` or `GATEWAY_TOKEN_SECRET`, like the
+//! gateway, and prints the token for use as `Authorization: Bearer <token>`.
+
+use sc_manager_app::services::PolicyContext;
+use sc_manager_gateway::authz::TokenSigner;
+
+const DEFAULT_TTL_SECS: i64 = 12 * 60 * 60;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (member, ttl) = match args.as_slice() {
+        [member] => (member, Ok(DEFAULT_TTL_SECS)),
+        [member, ttl] => (member, ttl.parse::<i64>().map_err(|e| format!("invalid ttl '{}': {}", ttl, e))),
+        _ => {
+            eprintln!("usage: gateway_issue_token <member-id> [ttl-seconds]");
+            std::process::exit(2);
+        }
+    };
+    let ttl = match ttl {
+        Ok(t) if t > 0 => t,
+        Ok(t) => { eprintln!("ttl must be positive, got {}", t); std::process::exit(2); }
+        Err(e) => { eprintln!("{}", e); std::process::exit(2); }
+    };
+    let signer = match TokenSigner::from_env() {
+        Ok(Some(s)) => s,
+        Ok(None) => { eprintln!("set GATEWAY_TOKEN_SECRET or GATEWAY_TOKEN_SECRET_FILE to the gateway's token secret"); std::process::exit(1); }
+        Err(e) => { eprintln!("failed to load token secret: {}", e); std::process::exit(1); }
+    };
+    println!("{}", signer.issue(member, PolicyContext::current().now + ttl));
+}
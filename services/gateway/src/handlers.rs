@@ -1,25 +1,175 @@
-use axum::{extract::Extension, Json};
-use serde_json::Value;
-
-pub async fn health_handler(Extension(registry): Extension<std::sync::Arc<sc_manager_adapters::AdapterRegistry>>) -> Json<Value> {
-    let h = registry.get_health().await;
-    let mut obj = serde_json::Map::new();
-    for (name, info) in h {
-        obj.insert(name, serde_json::to_value(info).unwrap_or_else(|_| serde_json::json!({}))); 
-    }
-    Json(Value::Object(obj))
-}
-
-use axum::response::IntoResponse;
-use prometheus::{Encoder, TextEncoder};
-
-pub async fn metrics_handler(Extension(registry): Extension<std::sync::Arc<prometheus::Registry>>) -> impl IntoResponse {
-    let encoder = TextEncoder::new();
-    let mf = registry.gather();
-    let mut buffer = Vec::new();
-    if let Err(e) = encoder.encode(&mf, &mut buffer) {
-        tracing::error!("failed to encode metrics: {}", e);
-        return (axum::http::StatusCode::INTERNAL_SERVER_ERROR, [("content-type", encoder.format_type().to_string())], Vec::new());
-    }
-    (axum::http::StatusCode::OK, [("content-type", encoder.format_type().to_string())], buffer)
-}
+use axum::response::IntoResponse;
+use axum::{extract::Extension, Json};
+use prometheus::{Encoder, TextEncoder};
+use serde_json::Value;
+use sc_manager_core::repositories::MemberRepository;
+
+pub use crate::authz::AuthzState;
+
+pub async fn health_handler(Extension(registry): Extension<std::sync::Arc<sc_manager_adapters::AdapterRegistry>>) -> Json<Value> {
+    let h = registry.get_health().await;
+    let mut obj = serde_json::Map::new();
+    for (name, info) in h {
+        obj.insert(name, serde_json::to_value(info).unwrap_or_else(|_| serde_json::json!({}))); 
+    }
+    Json(Value::Object(obj))
+}
+
+pub async fn metrics_handler(Extension(registry): Extension<std::sync::Arc<prometheus::Registry>>) -> impl IntoResponse {
+    let encoder = TextEncoder::new();
+    let mf = registry.gather();
+    let mut buffer = Vec::new();
+    if let Err(e) = encoder.encode(&mf, &mut buffer) {
+        tracing::error!("failed to encode metrics: {}", e);
+        return (axum::http::StatusCode::INTERNAL_SERVER_ERROR, [("content-type", encoder.format_type().to_string())], Vec::new());
+    }
+    (axum::http::StatusCode::OK, [("content-type", encoder.format_type().to_string())], buffer)
+}
+
+/// Event kinds under this prefix are minted by the gateway itself and refused from callers.
+pub const RESERVED_KIND_PREFIX: &str = "gateway.";
+
+/// Kind of a caller-submitted event (`POST /events`): required, and outside the reserved `gateway.` namespace.
+pub fn submitted_event_kind(body: &Value) -> Result<String, (axum::http::StatusCode, String)> {
+    use axum::http::StatusCode;
+    let kind = body
+        .get("kind")
+        .and_then(|v| v.as_str())
+        .filter(|k| !k.trim().is_empty())
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, "event kind is required".to_string()))?;
+    if kind.starts_with(RESERVED_KIND_PREFIX) {
+        return Err((StatusCode::FORBIDDEN, format!("event kind '{}' is reserved for the gateway", kind)));
+    }
+    Ok(kind.to_string())
+}
+
+/// Permission that lets a member explain decisions for other members of its organization.
+pub const EXPLAIN_ADMIN_PERMISSION: &str = "member.assign_role";
+
+#[derive(serde::Deserialize)]
+pub struct ExplainQuery {
+    pub member: String,
+    pub permission: String,
+    pub resource: Option<String>,
+}
+
+/// `GET /authz/explain?member=..&permission=..[&resource=..]`: answers "why can't I do X"
+/// with the full policy decision. Callers may explain their own decisions; explaining another
+/// member's requires `member.assign_role` on that member's organization.
+pub async fn authz_explain_handler(
+    Extension(state): Extension<std::sync::Arc<AuthzState>>,
+    headers: axum::http::HeaderMap,
+    axum::extract::Query(q): axum::extract::Query<ExplainQuery>,
+) -> Result<Json<Value>, (axum::http::StatusCode, String)> {
+    let caller = state.authenticate(&headers)?;
+    if caller != q.member {
+        let forbidden = || (axum::http::StatusCode::FORBIDDEN, format!("member '{}' may not explain decisions for '{}'", caller, q.member));
+        let org = state
+            .members
+            .read()
+            .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+            .get(&q.member)
+            .ok()
+            .and_then(|m| m.org_id);
+        let allowed = state
+            .decide(&caller, EXPLAIN_ADMIN_PERMISSION, org.as_deref())
+            .map_err(|_| forbidden())?
+            .allowed;
+        if !allowed {
+            return Err(forbidden());
+        }
+    }
+
+    let decision = state.decide(&q.member, &q.permission, q.resource.as_deref())?;
+    serde_json::to_value(decision)
+        .map(Json)
+        .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Permission required by the `/admin/adapters` endpoints.
+pub const ADAPTERS_ADMIN_PERMISSION: &str = "adapters.admin";
+
+type AdminError = (axum::http::StatusCode, String);
+
+/// Authenticate the caller and require `adapters.admin`.
+fn require_adapters_admin(state: &AuthzState, headers: &axum::http::HeaderMap) -> Result<(), AdminError> {
+    use axum::http::StatusCode;
+    let member = state.authenticate(headers)?;
+    let decision = state
+        .decide(&member, ADAPTERS_ADMIN_PERMISSION, None)
+        .map_err(|_| (StatusCode::FORBIDDEN, format!("member '{}' is not authorized", member)))?;
+    if !decision.allowed {
+        return Err((StatusCode::FORBIDDEN, format!("member '{}' lacks {}", member, ADAPTERS_ADMIN_PERMISSION)));
+    }
+    Ok(())
+}
+
+type Registry = std::sync::Arc<sc_manager_adapters::AdapterRegistry>;
+
+/// `GET /admin/adapters`: registered adapters with attribution, license, schedule and last data.
+pub async fn adapters_list_handler(
+    Extension(state): Extension<std::sync::Arc<AuthzState>>,
+    Extension(registry): Extension<Registry>,
+    headers: axum::http::HeaderMap,
+) -> Result<Json<Value>, AdminError> {
+    require_adapters_admin(&state, &headers)?;
+    Ok(Json(serde_json::json!(registry.list())))
+}
+
+/// `GET /admin/adapters/:name`: one adapter plus its cached data.
+pub async fn adapter_detail_handler(
+    Extension(state): Extension<std::sync::Arc<AuthzState>>,
+    Extension(registry): Extension<Registry>,
+    headers: axum::http::HeaderMap,
+    axum::extract::Path(name): axum::extract::Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    require_adapters_admin(&state, &headers)?;
+    let info = registry.info(&name).ok_or_else(|| (axum::http::StatusCode::NOT_FOUND, format!("adapter '{}' not found", name)))?;
+    let data = registry.cache().entry(&name).map(|e| e.data);
+    Ok(Json(serde_json::json!({ "adapter": info, "data": data })))
+}
+
+/// `POST /admin/adapters/:name/fetch`: run `fetch_and_update` now.
+pub async fn adapter_trigger_handler(
+    Extension(state): Extension<std::sync::Arc<AuthzState>>,
+    Extension(registry): Extension<Registry>,
+    headers: axum::http::HeaderMap,
+    axum::extract::Path(name): axum::extract::Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    require_adapters_admin(&state, &headers)?;
+    if registry.get(&name).is_none() {
+        return Err((axum::http::StatusCode::NOT_FOUND, format!("adapter '{}' not found", name)));
+    }
+    registry.trigger(&name).await.map_err(|e| (axum::http::StatusCode::BAD_GATEWAY, e))?;
+    Ok(Json(serde_json::json!({ "status": "ok", "adapter": registry.info(&name) })))
+}
+
+/// `POST /admin/adapters/:name/pause`: stop scheduled fetches.
+pub async fn adapter_pause_handler(
+    Extension(state): Extension<std::sync::Arc<AuthzState>>,
+    Extension(registry): Extension<Registry>,
+    headers: axum::http::HeaderMap,
+    axum::extract::Path(name): axum::extract::Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    set_paused(&state, &registry, &headers, &name, true)
+}
+
+/// `POST /admin/adapters/:name/resume`: resume scheduled fetches.
+pub async fn adapter_resume_handler(
+    Extension(state): Extension<std::sync::Arc<AuthzState>>,
+    Extension(registry): Extension<Registry>,
+    headers: axum::http::HeaderMap,
+    axum::extract::Path(name): axum::extract::Path<String>,
+) -> Result<Json<Value>, AdminError> {
+    set_paused(&state, &registry, &headers, &name, false)
+}
+
+fn set_paused(state: &AuthzState, registry: &Registry, headers: &axum::http::HeaderMap, name: &str, paused: bool) -> Result<Json<Value>, AdminError> {
+    require_adapters_admin(state, headers)?;
+    if registry.get(name).is_none() {
+        return Err((axum::http::StatusCode::NOT_FOUND, format!("adapter '{}' not found", name)));
+    }
+    let res = if paused { registry.pause(name) } else { registry.resume(name) };
+    res.map_err(|e| (axum::http::StatusCode::CONFLICT, e))?;
+    Ok(Json(serde_json::json!({ "status": "ok", "adapter": registry.info(name) })))
+}
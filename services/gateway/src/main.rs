@@ -1,143 +1,219 @@
-use axum::{routing::post, Json, Router};
-use serde_json::{Value, json};
-use std::net::SocketAddr;
-use std::env;
-use log::info;
-mod key_loader;
-mod nats;
-
-
-
-use axum::extract::Extension;
-use std::sync::Arc;
-use sc_manager_gateway::handlers;
-use base64::Engine;
-
-async fn events_handler(
-    Extension(nats_client): Extension<Arc<nats::NatsClient>>,
-    Json(body): Json<Value>,
-) -> Result<Json<Value>, (axum::http::StatusCode, String)> {
-    use sc_manager_core::events::{DomainEventPayload, sign_event};
-    use uuid::Uuid;
-
-    let subj = "domain.events";
-
-    // Load keypair (from env/file or deterministic test pair)
-    let kp = match key_loader::load_gateway_keypair() {
-        Ok(k) => k,
-        Err(e) => return Err((axum::http::StatusCode::INTERNAL_SERVER_ERROR, e)),
-    };
-
-    // Build DomainEventPayload
-    let kind = body.get("kind").and_then(|v| v.as_str()).unwrap_or("gateway.event").to_string();
-    let ev = DomainEventPayload { id: Uuid::new_v4().to_string(), kind, payload: body };
-
-    // Sign and serialize
-    let signed = sign_event(&kp, &ev).map_err(|e| (axum::http::StatusCode::BAD_REQUEST, format!("sign: {}", e)))?;
-    let payload = serde_json::to_vec(&signed).map_err(|e| (axum::http::StatusCode::BAD_REQUEST, format!("serialize signed: {}", e)))?;
-
-    nats_client.publish(subj, &payload).await.map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e))?;
-    Ok(Json(json!({"status":"ok"})))
-}
-
-// Key loading implementation moved to `key_loader.rs` (supports env var and file-based keys)
-
-
-
-#[tokio::main]
-async fn main() {
-    // Initialize logging from RUST_LOG env var (default: info)
-    env_logger::Builder::from_env(env_logger::Env::new().default_filter_or("info")).init();
-
-    // Simple HTTP server with one endpoint
-    let app = Router::new()
-        .route("/events", post(events_handler))
-        .route("/health", axum::routing::get(crate::handlers::health_handler));
-    let bind = std::env::var("GATEWAY_BIND").unwrap_or_else(|_| "0.0.0.0:8080".into());
-    let addr: SocketAddr = match bind.parse() {
-        Ok(a) => a,
-        Err(e) => {
-            tracing::error!("Invalid GATEWAY_BIND '{}': {}. Falling back to 0.0.0.0:8080", bind, e);
-            match "0.0.0.0:8080".parse() {
-                Ok(a) => a,
-                Err(e) => { eprintln!("Critical: default bind failed to parse: {}", e); std::process::exit(1); }
-            }
-        }
-    };
-    info!("Gateway running on {}", addr);
-
-    // Connect to NATS once and share the client with handlers
-    let nats_url = env::var("NATS_URL").unwrap_or_else(|_| "nats://127.0.0.1:4222".into());
-    let nats_client = match nats::NatsClient::connect(&nats_url).await {
-        Ok(c) => std::sync::Arc::new(c),
-        Err(e) => {
-            eprintln!("failed to connect to nats at {}: {}", nats_url, e);
-            std::process::exit(1);
-        }
-    };
-
-    // Initialize adapters and register in registry (config-driven)
-    let mut registry = sc_manager_adapters::AdapterRegistry::new();
-    let enabled_adapters = env::var("ADAPTERS").unwrap_or_else(|_| "discord".into());
-
-    if enabled_adapters.split(',').any(|s| s.trim().eq_ignore_ascii_case("discord")) {
-        let secs: u64 = env::var("DISCORD_SCHEDULE_SECONDS").ok().and_then(|v| v.parse().ok()).unwrap_or(60 * 60);
-        let discord_adapter = std::sync::Arc::new(sc_manager_adapters::discord::InMemoryDiscordAdapter::new()) as std::sync::Arc<dyn sc_manager_adapters::adapter_api::DataAdapter>;
-        registry.register(discord_adapter, sc_manager_adapters::Schedule::Fixed(std::time::Duration::from_secs(secs)));
-    }
-
-    // Load gateway signing key for publisher
-    let kp = match key_loader::load_gateway_keypair() {
-        Ok(k) => k,
-        Err(e) => { eprintln!("failed to load gateway keypair for publisher: {}", e); std::process::exit(1); }
-    };
-    let kp = std::sync::Arc::new(kp);
-
-    // NATS publisher that signs adapter payloads as domain events
-    struct NatsPublisher { client: std::sync::Arc<nats::NatsClient>, kp: std::sync::Arc<sc_manager_core::events::KeyPair> }
-    impl sc_manager_adapters::EventPublisher for NatsPublisher {
-        fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = sc_manager_adapters::Result<()>> + Send + 'a>> {
-            let client = self.client.clone();
-            let kp = self.kp.clone();
-            let subj = subject.to_string();
-            Box::pin(async move {
-                // Try to parse payload as JSON, fallback to base64 raw
-                let val: serde_json::Value = match serde_json::from_slice(&payload) {
-                    Ok(v) => v,
-                    Err(_) => serde_json::json!({"raw_b64": base64::engine::general_purpose::STANDARD.encode(payload)})
-                };
-                use sc_manager_core::events::{DomainEventPayload, sign_event};
-                let ev = DomainEventPayload { id: uuid::Uuid::new_v4().to_string(), kind: subj, payload: val };
-                let signed = sign_event(&kp, &ev).map_err(|e| e.to_string())?;
-                let b = serde_json::to_vec(&signed).map_err(|e| e.to_string())?;
-                client.publish("domain.events", &b).await.map_err(|e| e.to_string())
-            })
-        }
-    }
-
-    // Setup metrics registry and register adapter metrics
-    let metrics_registry = prometheus::Registry::new();
-
-    let publisher = std::sync::Arc::new(NatsPublisher { client: nats_client.clone(), kp });
-    if let Err(e) = registry.start_all(Some(publisher), Some(std::sync::Arc::new(metrics_registry.clone()))).await {
-        eprintln!("failed to start adapters: {}", e);
-        std::process::exit(1);
-    }
-    let registry = std::sync::Arc::new(registry);
-
-    // Attach the client, registry and metrics as axum Extensions
-    let app = app
-        .route("/metrics", axum::routing::get(crate::handlers::metrics_handler))
-        .layer(Extension(nats_client))
-        .layer(Extension(registry))
-        .layer(Extension(std::sync::Arc::new(metrics_registry.clone())));
-
-    // Use axum-server for binding which provides a cross-platform helper
-    if let Err(e) = axum_server::bind(addr)
-        .serve(app.into_make_service())
-        .await
-    {
-        eprintln!("server error: {}", e);
-        std::process::exit(1);
-    }
-}
+use axum::{routing::post, Json, Router};
+use serde_json::{Value, json};
+use std::net::SocketAddr;
+use std::env;
+use log::info;
+mod key_loader;
+mod nats;
+
+
+
+use axum::extract::Extension;
+use std::sync::Arc;
+use sc_manager_gateway::handlers;
+use base64::Engine;
+
+async fn events_handler(
+    Extension(nats_client): Extension<Arc<nats::NatsClient>>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, (axum::http::StatusCode, String)> {
+    use sc_manager_core::events::{DomainEventPayload, sign_event};
+    use uuid::Uuid;
+
+    let subj = "domain.events";
+
+    // Load keypair (from env/file or deterministic test pair)
+    let kp = match key_loader::load_gateway_keypair() {
+        Ok(k) => k,
+        Err(e) => return Err((axum::http::StatusCode::INTERNAL_SERVER_ERROR, e)),
+    };
+
+    // Build DomainEventPayload; `gateway.*` kinds are reserved for events the gateway mints itself
+    let kind = handlers::submitted_event_kind(&body)?;
+    let ev = DomainEventPayload { id: Uuid::new_v4().to_string(), kind, payload: body };
+
+    // Sign and serialize
+    let signed = sign_event(&kp, &ev).map_err(|e| (axum::http::StatusCode::BAD_REQUEST, format!("sign: {}", e)))?;
+    let payload = serde_json::to_vec(&signed).map_err(|e| (axum::http::StatusCode::BAD_REQUEST, format!("serialize signed: {}", e)))?;
+
+    nats_client.publish(subj, &payload).await.map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    Ok(Json(json!({"status":"ok"})))
+}
+
+// Key loading implementation moved to `key_loader.rs` (supports env var and file-based keys)
+
+
+
+#[tokio::main]
+async fn main() {
+    // Initialize logging from RUST_LOG env var (default: info)
+    env_logger::Builder::from_env(env_logger::Env::new().default_filter_or("info")).init();
+
+    // Simple HTTP server with one endpoint
+    let app = Router::new()
+        .route("/events", post(events_handler))
+        .route("/health", axum::routing::get(crate::handlers::health_handler));
+    let bind = std::env::var("GATEWAY_BIND").unwrap_or_else(|_| "0.0.0.0:8080".into());
+    let addr: SocketAddr = match bind.parse() {
+        Ok(a) => a,
+        Err(e) => {
+            tracing::error!("Invalid GATEWAY_BIND '{}': {}. Falling back to 0.0.0.0:8080", bind, e);
+            match "0.0.0.0:8080".parse() {
+                Ok(a) => a,
+                Err(e) => { eprintln!("Critical: default bind failed to parse: {}", e); std::process::exit(1); }
+            }
+        }
+    };
+    info!("Gateway running on {}", addr);
+
+    // Connect to NATS once and share the client with handlers
+    let nats_url = env::var("NATS_URL").unwrap_or_else(|_| "nats://127.0.0.1:4222".into());
+    let nats_client = match nats::NatsClient::connect(&nats_url).await {
+        Ok(c) => std::sync::Arc::new(c),
+        Err(e) => {
+            eprintln!("failed to connect to nats at {}: {}", nats_url, e);
+            std::process::exit(1);
+        }
+    };
+
+    // Initialize adapters and register in registry (config-driven)
+    // Optional hubs must be opted in via ADAPTER_OPTIONAL_HUBS; the in-memory discord adapter reads no hub
+    let mut registry = sc_manager_adapters::AdapterRegistry::new()
+        .with_hub_policy(sc_manager_adapters::registry::HubPolicy::from_env().exempt("inmemory-discord"));
+    // Adapters publish deltas on change; ADAPTER_SNAPSHOT_SECONDS adds periodic full snapshots
+    if let Some(secs) = env::var("ADAPTER_SNAPSHOT_SECONDS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        registry = registry.with_snapshot_interval(std::time::Duration::from_secs(secs));
+    }
+    let enabled_adapters = env::var("ADAPTERS").unwrap_or_else(|_| "discord".into());
+
+    if enabled_adapters.split(',').any(|s| s.trim().eq_ignore_ascii_case("discord")) {
+        let secs: u64 = env::var("DISCORD_SCHEDULE_SECONDS").ok().and_then(|v| v.parse().ok()).unwrap_or(60 * 60);
+        let discord_adapter = std::sync::Arc::new(sc_manager_adapters::discord::InMemoryDiscordAdapter::new()) as std::sync::Arc<dyn sc_manager_adapters::adapter_api::DataAdapter>;
+        if let Err(e) = registry.register(discord_adapter, sc_manager_adapters::Schedule::Fixed(std::time::Duration::from_secs(secs))) {
+            eprintln!("failed to register discord adapter: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // Load gateway signing key for publisher
+    let kp = match key_loader::load_gateway_keypair() {
+        Ok(k) => k,
+        Err(e) => { eprintln!("failed to load gateway keypair for publisher: {}", e); std::process::exit(1); }
+    };
+
+    // Members, roles and resource tree for authorization
+    let seed = match env::var("GATEWAY_AUTHZ_FILE") {
+        Ok(path) => match std::fs::read(&path).map_err(|e| e.to_string()).and_then(|b| serde_json::from_slice(&b).map_err(|e| e.to_string())) {
+            Ok(seed) => seed,
+            Err(e) => { eprintln!("failed to load GATEWAY_AUTHZ_FILE {}: {}", path, e); std::process::exit(1); }
+        },
+        Err(_) => {
+            tracing::warn!("GATEWAY_AUTHZ_FILE not set; authorization has no members and refuses all callers");
+            sc_manager_gateway::authz::AuthzSeed::default()
+        }
+    };
+    // Caller tokens are MACed with their own secret, never with the event signing key
+    let tokens = match sc_manager_gateway::authz::TokenSigner::from_env() {
+        Ok(Some(t)) => t,
+        Ok(None) => {
+            tracing::warn!("GATEWAY_TOKEN_SECRET not set; using a per-process secret, issued tokens stop working on restart");
+            let secret: Vec<u8> = (0..2).flat_map(|_| *uuid::Uuid::new_v4().as_bytes()).collect();
+            match sc_manager_gateway::authz::TokenSigner::new(secret) {
+                Ok(t) => t,
+                Err(e) => { eprintln!("failed to create token secret: {}", e); std::process::exit(1); }
+            }
+        }
+        Err(e) => { eprintln!("failed to load token secret: {}", e); std::process::exit(1); }
+    };
+    let mut authz = match sc_manager_gateway::authz::AuthzState::from_seed(seed, tokens) {
+        Ok(a) => a,
+        Err(e) => { eprintln!("invalid GATEWAY_AUTHZ_FILE: {}", e); std::process::exit(1); }
+    };
+    // Role changes arrive as command events signed by the application; events signed with the gateway's own key
+    // (including everything submitted to POST /events) never qualify
+    match env::var("GATEWAY_ROLE_EVENTS_PUBLIC_KEY") {
+        Ok(b64) => match base64::engine::general_purpose::STANDARD.decode(b64.trim()) {
+            Ok(key) if kp.public_bytes().is_ok_and(|own| own == key) => {
+                eprintln!("GATEWAY_ROLE_EVENTS_PUBLIC_KEY must not be the gateway's own key");
+                std::process::exit(1);
+            }
+            Ok(key) => authz = authz.with_role_events_key(key),
+            Err(e) => { eprintln!("invalid GATEWAY_ROLE_EVENTS_PUBLIC_KEY: {}", e); std::process::exit(1); }
+        },
+        Err(_) => tracing::warn!("GATEWAY_ROLE_EVENTS_PUBLIC_KEY not set; role changes take effect only after a restart"),
+    }
+    let authz = std::sync::Arc::new(authz);
+    match nats_client.subscribe("domain.events").await {
+        Ok(mut events) => {
+            let authz = authz.clone();
+            tokio::spawn(async move {
+                use futures::StreamExt;
+                while let Some(msg) = events.next().await {
+                    let Ok(signed) = serde_json::from_slice::<sc_manager_core::events::SignedEvent>(&msg.payload) else { continue };
+                    match authz.apply_role_event(&signed) {
+                        Ok(true) => info!("applied role event {} ({})", signed.event.id, signed.event.kind),
+                        Ok(false) => {}
+                        Err(e) => tracing::warn!("failed to apply role event {}: {}", signed.event.id, e),
+                    }
+                }
+            });
+        }
+        Err(e) => { eprintln!("failed to subscribe to domain.events: {}", e); std::process::exit(1); }
+    }
+    let kp = std::sync::Arc::new(kp);
+
+    // NATS publisher that signs adapter payloads as domain events
+    struct NatsPublisher { client: std::sync::Arc<nats::NatsClient>, kp: std::sync::Arc<sc_manager_core::events::KeyPair> }
+    impl sc_manager_adapters::EventPublisher for NatsPublisher {
+        fn publish<'a>(&'a self, subject: &'a str, payload: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = sc_manager_adapters::Result<()>> + Send + 'a>> {
+            let client = self.client.clone();
+            let kp = self.kp.clone();
+            let subj = subject.to_string();
+            Box::pin(async move {
+                // Try to parse payload as JSON, fallback to base64 raw
+                let val: serde_json::Value = match serde_json::from_slice(&payload) {
+                    Ok(v) => v,
+                    Err(_) => serde_json::json!({"raw_b64": base64::engine::general_purpose::STANDARD.encode(payload)})
+                };
+                use sc_manager_core::events::{DomainEventPayload, sign_event};
+                let ev = DomainEventPayload { id: uuid::Uuid::new_v4().to_string(), kind: subj, payload: val };
+                let signed = sign_event(&kp, &ev).map_err(|e| e.to_string())?;
+                let b = serde_json::to_vec(&signed).map_err(|e| e.to_string())?;
+                client.publish("domain.events", &b).await.map_err(|e| e.to_string())
+            })
+        }
+    }
+
+    // Setup metrics registry and register adapter metrics
+    let metrics_registry = prometheus::Registry::new();
+
+    let publisher = std::sync::Arc::new(NatsPublisher { client: nats_client.clone(), kp });
+    if let Err(e) = registry.start_all(Some(publisher), Some(std::sync::Arc::new(metrics_registry.clone()))).await {
+        eprintln!("failed to start adapters: {}", e);
+        std::process::exit(1);
+    }
+    let registry = std::sync::Arc::new(registry);
+
+    // Attach the client, registry and metrics as axum Extensions
+    let app = app
+        .route("/metrics", axum::routing::get(crate::handlers::metrics_handler))
+        .route("/authz/explain", axum::routing::get(crate::handlers::authz_explain_handler))
+        .route("/admin/adapters", axum::routing::get(crate::handlers::adapters_list_handler))
+        .route("/admin/adapters/:name", axum::routing::get(crate::handlers::adapter_detail_handler))
+        .route("/admin/adapters/:name/fetch", axum::routing::post(crate::handlers::adapter_trigger_handler))
+        .route("/admin/adapters/:name/pause", axum::routing::post(crate::handlers::adapter_pause_handler))
+        .route("/admin/adapters/:name/resume", axum::routing::post(crate::handlers::adapter_resume_handler))
+        .layer(Extension(authz))
+        .layer(Extension(nats_client))
+        .layer(Extension(registry))
+        .layer(Extension(std::sync::Arc::new(metrics_registry.clone())));
+
+    // Use axum-server for binding which provides a cross-platform helper
+    if let Err(e) = axum_server::bind(addr)
+        .serve(app.into_make_service())
+        .await
+    {
+        eprintln!("server error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,88 @@
+use axum::{routing::{get, post}, Router, extract::Extension, body::to_bytes};
+use serde_json::Value;
+use std::sync::Arc;
+use tower::util::ServiceExt; // for oneshot
+use sc_manager_core::domain::{Member, Role, RoleAssignment};
+use sc_manager_gateway::authz::{AuthzSeed, AuthzState, TokenSigner};
+use sc_manager_gateway::handlers;
+
+const SECRET: [u8; 32] = [7u8; 32];
+
+async fn app() -> Router {
+    let mut r = Role::new("ops", "Operations");
+    r.add_permission("adapters.*");
+    let mut admin = Member::new("alice");
+    admin.add_role_assignment(RoleAssignment::new("ops", None));
+    let seed = AuthzSeed {
+        members: vec![admin, Member::new("bob")],
+        roles: vec![r],
+        ..AuthzSeed::default()
+    };
+    let state = AuthzState::from_seed(seed, TokenSigner::new(SECRET).unwrap()).expect("seed");
+
+    let mut registry = sc_manager_adapters::AdapterRegistry::new()
+        .with_hub_policy(sc_manager_adapters::registry::HubPolicy::new().exempt("inmemory-discord"));
+    registry.register(
+        Arc::new(sc_manager_adapters::discord::InMemoryDiscordAdapter::new()) as Arc<dyn sc_manager_adapters::adapter_api::DataAdapter>,
+        sc_manager_adapters::Schedule::Fixed(std::time::Duration::from_secs(3600)),
+    ).expect("register adapter");
+    registry.start_all(None, None).await.expect("start adapters");
+
+    Router::new()
+        .route("/admin/adapters", get(handlers::adapters_list_handler))
+        .route("/admin/adapters/:name", get(handlers::adapter_detail_handler))
+        .route("/admin/adapters/:name/fetch", post(handlers::adapter_trigger_handler))
+        .route("/admin/adapters/:name/pause", post(handlers::adapter_pause_handler))
+        .route("/admin/adapters/:name/resume", post(handlers::adapter_resume_handler))
+        .layer(Extension(Arc::new(registry)))
+        .layer(Extension(Arc::new(state)))
+}
+
+async fn call(app: &Router, method: &str, uri: &str, member: Option<&str>) -> (u16, Value) {
+    let mut req = axum::http::Request::builder().method(method).uri(uri);
+    if let Some(m) = member {
+        let token = TokenSigner::new(SECRET).unwrap().issue(m, i64::MAX);
+        req = req.header("authorization", format!("Bearer {}", token));
+    }
+    let response = app.clone().oneshot(req.body(axum::body::Body::empty()).unwrap()).await.expect("request failed");
+    let status = response.status().as_u16();
+    let body = to_bytes(response.into_body(), 64 * 1024).await.expect("body");
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn admin_endpoints_require_permission() {
+    let app = app().await;
+    assert_eq!(call(&app, "GET", "/admin/adapters", None).await.0, 401);
+    assert_eq!(call(&app, "GET", "/admin/adapters", Some("bob")).await.0, 403);
+    assert_eq!(call(&app, "POST", "/admin/adapters/inmemory-discord/pause", Some("bob")).await.0, 403);
+
+    // the old identity header is not trusted
+    let req = axum::http::Request::builder().uri("/admin/adapters").header("x-member-id", "alice");
+    let response = app.clone().oneshot(req.body(axum::body::Body::empty()).unwrap()).await.expect("request failed");
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn admin_lists_triggers_and_pauses_adapters() {
+    let app = app().await;
+    let (status, list) = call(&app, "GET", "/admin/adapters", Some("alice")).await;
+    assert_eq!(status, 200);
+    assert_eq!(list[0]["name"], "inmemory-discord");
+    assert_eq!(list[0]["license"], "MIT");
+    assert_eq!(list[0]["schedule"]["kind"], "fixed");
+
+    let (status, paused) = call(&app, "POST", "/admin/adapters/inmemory-discord/pause", Some("alice")).await;
+    assert_eq!(status, 200);
+    assert_eq!(paused["adapter"]["paused"], true);
+
+    let (status, _) = call(&app, "POST", "/admin/adapters/inmemory-discord/fetch", Some("alice")).await;
+    assert_eq!(status, 200);
+    let (_, detail) = call(&app, "GET", "/admin/adapters/inmemory-discord", Some("alice")).await;
+    assert!(detail["data"]["News"].is_array());
+
+    let (status, resumed) = call(&app, "POST", "/admin/adapters/inmemory-discord/resume", Some("alice")).await;
+    assert_eq!(status, 200);
+    assert_eq!(resumed["adapter"]["paused"], false);
+    assert_eq!(call(&app, "POST", "/admin/adapters/missing/fetch", Some("alice")).await.0, 404);
+}
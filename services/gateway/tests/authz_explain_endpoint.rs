@@ -0,0 +1,93 @@
+use axum::{routing::get, Router, extract::Extension, body::to_bytes};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as base64_url;
+use base64::Engine as _;
+use serde_json::Value;
+use std::sync::Arc;
+use tower::util::ServiceExt; // for oneshot
+use sc_manager_core::domain::{Member, Role, RoleAssignment};
+use sc_manager_core::events::{generate_test_keypair, sign_event, DomainEventPayload};
+use sc_manager_gateway::authz::{AuthzSeed, AuthzState, TokenSigner};
+use sc_manager_gateway::handlers::authz_explain_handler;
+
+const SECRET: [u8; 32] = [7u8; 32];
+
+fn signer() -> TokenSigner {
+    TokenSigner::new(SECRET).expect("signer")
+}
+
+fn app() -> Router {
+    let mut officer = Role::new("fleet-officer", "Fleet Officer");
+    officer.add_permission("fleet.*");
+    let mut admin = Role::new("personnel", "Personnel");
+    admin.add_permission("member.assign_role");
+    let mut alice = Member::new("alice");
+    alice.add_role_assignment(RoleAssignment::new("fleet-officer", Some("fleet-1".to_string())));
+    let mut carol = Member::new("carol");
+    carol.add_role_assignment(RoleAssignment::new("personnel", None));
+    let seed = AuthzSeed {
+        members: vec![alice, Member::new("bob"), carol],
+        roles: vec![officer, admin],
+        ..AuthzSeed::default()
+    };
+    let state = AuthzState::from_seed(seed, signer()).expect("seed");
+
+    Router::new()
+        .route("/authz/explain", get(authz_explain_handler))
+        .layer(Extension(Arc::new(state)))
+}
+
+async fn explain(app: &Router, caller: Option<&str>) -> (u16, Value) {
+    let mut req = axum::http::Request::builder().uri("/authz/explain?member=alice&permission=fleet.update&resource=fleet-2");
+    if let Some(c) = caller {
+        let token = signer().issue(c, i64::MAX);
+        req = req.header("authorization", format!("Bearer {}", token));
+    }
+    let response = app.clone().oneshot(req.body(axum::body::Body::empty()).unwrap()).await.expect("request failed");
+    let status = response.status().as_u16();
+    let body = to_bytes(response.into_body(), 64 * 1024).await.expect("body");
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn explain_reports_scope_mismatch() {
+    let (status, v) = explain(&app(), Some("alice")).await;
+    assert_eq!(status, 200);
+    assert_eq!(v["allowed"], false);
+    assert_eq!(v["deny_rule"]["rule"], "scope_mismatch");
+    assert_eq!(v["deny_rule"]["assignment_scope"], "fleet-1");
+}
+
+#[tokio::test]
+async fn explain_is_limited_to_self_and_admins() {
+    let app = app();
+    assert_eq!(explain(&app, None).await.0, 401);
+    assert_eq!(explain(&app, Some("bob")).await.0, 403);
+    assert_eq!(explain(&app, Some("carol")).await.0, 200);
+}
+
+#[tokio::test]
+async fn tokens_must_carry_the_token_secret_and_be_unexpired() {
+    let app = app();
+    let foreign = TokenSigner::new([8u8; 32]).unwrap().issue("alice", i64::MAX);
+    let expired = signer().issue("alice", 1);
+    // claims swapped for another member's, keeping bob's MAC
+    let bob = signer().issue("bob", i64::MAX);
+    let mac = bob.rsplit('.').next().unwrap();
+    let tampered = format!("v1.{}.{}", base64_url.encode(br#"{"sub":"alice","exp":9999999999}"#), mac);
+    // an event signed with the gateway's event key, as `POST /events` used to mint, is not a token
+    let event = DomainEventPayload {
+        id: "e-1".into(),
+        kind: "gateway.auth".into(),
+        payload: serde_json::json!({ "member": "alice", "expires_at": i64::MAX }),
+    };
+    let signed = sign_event(&generate_test_keypair().unwrap(), &event).unwrap();
+    let signed_event = base64_url.encode(serde_json::to_vec(&signed).unwrap());
+    for token in [foreign, expired, tampered, signed_event] {
+        let req = axum::http::Request::builder()
+            .uri("/authz/explain?member=alice&permission=fleet.update")
+            .header("authorization", format!("Bearer {}", token))
+            .body(axum::body::Body::empty())
+            .unwrap();
+        assert_eq!(app.clone().oneshot(req).await.unwrap().status(), 401);
+    }
+}
@@ -0,0 +1,67 @@
+use sc_manager_app::command_bus::{CommandBus, PolicyRepos, SigningMiddleware};
+use sc_manager_app::commands::{AssignPermissionToRoleCommand, AssignRoleCommand, GrantRoleCommand};
+use sc_manager_app::in_memory_member_repo::InMemoryMemberRepo;
+use sc_manager_app::in_memory_permission_repo::InMemoryPermissionRepo;
+use sc_manager_app::in_memory_role_repo::InMemoryRoleRepo;
+use sc_manager_core::domain::{Member, Role, RoleAssignment};
+use sc_manager_core::events::{KeyPair, SignedEvent};
+use sc_manager_gateway::authz::{AuthzSeed, AuthzState, TokenSigner};
+
+fn app_key() -> KeyPair {
+    KeyPair::from_secret_bytes(&[3u8; 32]).expect("app key")
+}
+
+fn state() -> AuthzState {
+    let seed = AuthzSeed {
+        members: vec![Member::new("alice")],
+        roles: vec![Role::new("fleet-officer", "Fleet Officer")],
+        ..AuthzSeed::default()
+    };
+    AuthzState::from_seed(seed, TokenSigner::new([7u8; 32]).unwrap())
+        .expect("seed")
+        .with_role_events_key(app_key().public_bytes().unwrap())
+}
+
+/// Events as the application's command bus signs them.
+fn role_events(kp: &KeyPair) -> Vec<SignedEvent> {
+    let mut sink: Vec<SignedEvent> = vec![];
+    {
+        let mut bus = CommandBus::new().with(SigningMiddleware::new(kp, &mut sink));
+        let (mut members, mut roles, mut permissions) = (InMemoryMemberRepo::new(), InMemoryRoleRepo::new(), InMemoryPermissionRepo::new());
+        let mut repos = PolicyRepos::new(&mut members, &mut roles, &mut permissions);
+        let assign = AssignRoleCommand { member_id: "alice".into(), role_id: "fleet-officer".into(), scope: Some("fleet-1".into()) };
+        bus.dispatch("admin", assign, &mut repos, |_, _| Ok(())).expect("assign");
+        let scoped = GrantRoleCommand { member_id: "alice".into(), assignment: RoleAssignment::new("fleet-officer", Some("fleet-3".into())) };
+        bus.dispatch("admin", scoped, &mut repos, |_, _| Ok(())).expect("grant role");
+        let grant = AssignPermissionToRoleCommand { role_id: "fleet-officer".into(), permission_id: "fleet.update".into() };
+        bus.dispatch("admin", grant, &mut repos, |_, _| Ok(())).expect("grant");
+    }
+    sink
+}
+
+#[test]
+fn role_events_update_decisions_without_a_restart() {
+    let state = state();
+    assert!(!state.decide("alice", "fleet.update", Some("fleet-1")).unwrap().allowed);
+
+    for ev in role_events(&app_key()) {
+        assert!(state.apply_role_event(&ev).expect("apply"));
+    }
+    assert!(state.decide("alice", "fleet.update", Some("fleet-1")).unwrap().allowed);
+    assert!(state.decide("alice", "fleet.update", Some("fleet-3")).unwrap().allowed);
+    assert!(!state.decide("alice", "fleet.update", Some("fleet-2")).unwrap().allowed);
+}
+
+#[test]
+fn role_events_from_other_keys_are_ignored() {
+    let state = state();
+    // e.g. the gateway's own key, which signs whatever callers submit to POST /events
+    let other = KeyPair::from_secret_bytes(&[1u8; 32]).unwrap();
+    for ev in role_events(&other) {
+        assert!(!state.apply_role_event(&ev).expect("ignored"));
+    }
+    let mut forged = role_events(&app_key()).remove(0);
+    forged.event.payload["command"]["scope"] = serde_json::Value::Null;
+    assert!(!state.apply_role_event(&forged).expect("bad signature is ignored"));
+    assert!(!state.decide("alice", "fleet.update", Some("fleet-1")).unwrap().allowed);
+}
@@ -1,11 +1,20 @@
-use serde_json::json;
-use sc_manager_core::events::{DomainEventPayload, sign_event, verify_signature, generate_test_keypair};
-
-#[test]
-fn create_signed_payload_and_verify() {
-    let kp = generate_test_keypair().expect("generate test keypair");
-    let body = json!({"kind":"test","foo":"bar"});
-    let ev = DomainEventPayload { id: "e-test".into(), kind: "test".into(), payload: body.clone() };
-    let s = sign_event(&kp, &ev).expect("sign event");
-    assert!(verify_signature(&s));
-}
+use serde_json::json;
+use sc_manager_core::events::{DomainEventPayload, sign_event, verify_signature, generate_test_keypair};
+
+#[test]
+fn create_signed_payload_and_verify() {
+    let kp = generate_test_keypair().expect("generate test keypair");
+    let body = json!({"kind":"test","foo":"bar"});
+    let ev = DomainEventPayload { id: "e-test".into(), kind: "test".into(), payload: body.clone() };
+    let s = sign_event(&kp, &ev).expect("sign event");
+    assert!(verify_signature(&s));
+}
+
+#[test]
+fn callers_cannot_submit_reserved_kinds() {
+    use sc_manager_gateway::handlers::submitted_event_kind;
+    assert_eq!(submitted_event_kind(&json!({"kind": "org.note", "text": "hi"})).unwrap(), "org.note");
+    assert_eq!(submitted_event_kind(&json!({"kind": "gateway.auth", "member": "alice"})).unwrap_err().0, 403);
+    assert_eq!(submitted_event_kind(&json!({"kind": "gateway.event"})).unwrap_err().0, 403);
+    assert_eq!(submitted_event_kind(&json!({"text": "no kind"})).unwrap_err().0, 400);
+}